@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use deckgym::card_ids::CardId;
+use deckgym::database::{get_card, get_card_by_enum};
 use deckgym::types::{Attack, Card, EnergyType, PokemonCard};
 use lazy_static::lazy_static;
 
@@ -101,6 +102,9 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("lazy_static_map_approach", |b| {
         b.iter(|| lazy_static_map_approach(black_box("A1 001")))
     });
+    c.bench_function("CardId::from_card_id", |b| {
+        b.iter(|| CardId::from_card_id(black_box("A3a 062")))
+    });
 
     c.bench_function("get_card_create", |b| {
         b.iter(|| get_card_create(black_box(CardId::A1001Bulbasaur)))
@@ -108,6 +112,12 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("get_card_lazy_map", |b| {
         b.iter(|| get_card_lazy_map(black_box(CardId::A1001Bulbasaur)))
     });
+    c.bench_function("database::get_card_by_enum", |b| {
+        b.iter(|| get_card_by_enum(black_box(CardId::A1001Bulbasaur)))
+    });
+    c.bench_function("database::get_card", |b| {
+        b.iter(|| get_card(black_box(CardId::A1001Bulbasaur)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
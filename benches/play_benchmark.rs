@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use deckgym::{
-    players::{Player, RandomPlayer},
+    players::{ExpectiMiniMaxPlayer, MctsPlayer, Player, RandomPlayer},
     test_helpers::load_test_decks,
 };
 
@@ -13,10 +13,38 @@ fn play_random_game(seed: u64) {
     game.play();
 }
 
+// Search-heavy players clone the state for every node they explore.
+fn play_mcts_game(seed: u64) {
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(MctsPlayer::new(deck_a, 10));
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, seed);
+    game.play();
+}
+
+fn play_expectiminimax_game(seed: u64) {
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(ExpectiMiniMaxPlayer {
+        deck: deck_a,
+        max_depth: 2,
+    });
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, seed);
+    game.play();
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("play random game", |b| {
         b.iter(|| play_random_game(black_box(20)))
     });
+    c.bench_function("play mcts game", |b| {
+        b.iter(|| play_mcts_game(black_box(20)))
+    });
+    c.bench_function("play expectiminimax game", |b| {
+        b.iter(|| play_expectiminimax_game(black_box(20)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,31 +1,224 @@
-use std::collections::HashMap;
+// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.
 
-// TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Pokémon abilities. Reprints share the variant of the first card with the ability,
+/// named like its `CardId`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AbilityId {
-    A1177Weezing,
     A1007Butterfree,
+    A1020Victreebel,
+    A1061Poliwrath,
+    A1067Cloyster,
+    A1089Greninja,
+    A1098Magneton,
+    A1123GengarEx,
+    A1125Hypno,
     A1132Gardevoir,
-    A2a071Arceus,
-}
-
-// Create a static HashMap for fast (pokemon, index) lookup
-lazy_static::lazy_static! {
-    static ref ABILITY_ID_MAP: HashMap<&'static str, AbilityId> = {
-        let mut m = HashMap::new();
-        m.insert("A1 007", AbilityId::A1007Butterfree);
-        m.insert("A1 177", AbilityId::A1177Weezing);
-        m.insert("A1 132", AbilityId::A1132Gardevoir);
-        m.insert("A2a 071", AbilityId::A2a071Arceus);
-        m.insert("A2a 086", AbilityId::A2a071Arceus);
-        m.insert("A2a 095", AbilityId::A2a071Arceus);
-        m.insert("A2a 096", AbilityId::A2a071Arceus);
-        m
-    };
+    A1177Weezing,
+    A1182Melmetal,
+    A1188Pidgeot,
+    A1209Porygon,
+    A1a006Serperior,
+    A1a019Vaporeon,
+    A1a046AerodactylEx,
+    A1a056Druddigon,
+    A2022Shaymin,
+    A2032Piloswine,
+    A2033Mamoswine,
+    A2034Regice,
+    A2072Dusknoir,
+    A2078Giratina,
+    A2087Regirock,
+    A2092Lucario,
+    A2110DarkraiEx,
+    A2114Bastiodon,
+    A2123Garchomp,
+    A2a009Carnivine,
+    A2a010LeafeonEx,
+    A2a013Heatran,
+    A2a021Abomasnow,
+    A2a022GlaceonEx,
+    A2a026Raichu,
+    A2a034Unown,
+    A2a035Rotom,
+    A2a041Tyranitar,
+    A2a050Crobat,
+    A2a055Magnezone,
+    A2a069Shaymin,
+    A2a071ArceusEx,
+    A2b021Tatsugiri,
+    A2b028Pawmot,
+    A2b035GiratinaEx,
+    A2b051Grafaiai,
+    A2b061Wigglytuff,
+    A3021Wimpod,
+    A3048Primarina,
+    A3054Pyukumuku,
+    A3066Oricorio,
+    A3080Comfey,
+    A3087LunalaEx,
+    A3096Conkeldurr,
+    A3104PassimianEx,
+    A3109AlolanPersian,
+    A3122SolgaleoEx,
+    A3141Komala,
+    A3a015Luxray,
+    A3a021Zeraora,
+    A3a027Shiinotic,
+    A3a031Claydol,
+    A3a042Nihilego,
+    A3a052Ferrothorn,
+    A3a056Stoutland,
+    A3a062Celesteela,
+    A3b009FlareonEx,
+    A3b034SylveonEx,
+    A3b056EeveeEx,
+    A3b057SnorlaxEx,
+    A3b059Ambipom,
+    A3b099Gardevoir,
+    PA037CresseliaEx,
+    PA047Staraptor,
 }
 
 impl AbilityId {
+    /// None if the Pokémon has no ability or is not found.
     pub fn from_pokemon_id(pokemon_id: &str) -> Option<Self> {
-        ABILITY_ID_MAP.get(&pokemon_id).copied()
+        match pokemon_id {
+            "A1 007" => Some(AbilityId::A1007Butterfree),
+            "P-A 013" => Some(AbilityId::A1007Butterfree),
+            "A1 020" => Some(AbilityId::A1020Victreebel),
+            "A1 061" => Some(AbilityId::A1061Poliwrath),
+            "A1 067" => Some(AbilityId::A1067Cloyster),
+            "A1 089" => Some(AbilityId::A1089Greninja),
+            "A3a 093" => Some(AbilityId::A1089Greninja),
+            "P-A 019" => Some(AbilityId::A1089Greninja),
+            "A1 098" => Some(AbilityId::A1098Magneton),
+            "A1 123" => Some(AbilityId::A1123GengarEx),
+            "A1 261" => Some(AbilityId::A1123GengarEx),
+            "A1 277" => Some(AbilityId::A1123GengarEx),
+            "A3 234" => Some(AbilityId::A1123GengarEx),
+            "A1 125" => Some(AbilityId::A1125Hypno),
+            "A1 132" => Some(AbilityId::A1132Gardevoir),
+            "A1 177" => Some(AbilityId::A1177Weezing),
+            "A1 243" => Some(AbilityId::A1177Weezing),
+            "A1 182" => Some(AbilityId::A1182Melmetal),
+            "A1 188" => Some(AbilityId::A1188Pidgeot),
+            "A1 245" => Some(AbilityId::A1188Pidgeot),
+            "A3a 097" => Some(AbilityId::A1188Pidgeot),
+            "A1 209" => Some(AbilityId::A1209Porygon),
+            "A1 249" => Some(AbilityId::A1209Porygon),
+            "A1a 006" => Some(AbilityId::A1a006Serperior),
+            "A1a 070" => Some(AbilityId::A1a006Serperior),
+            "A1a 019" => Some(AbilityId::A1a019Vaporeon),
+            "A1a 072" => Some(AbilityId::A1a019Vaporeon),
+            "A1a 046" => Some(AbilityId::A1a046AerodactylEx),
+            "A1a 078" => Some(AbilityId::A1a046AerodactylEx),
+            "A1a 084" => Some(AbilityId::A1a046AerodactylEx),
+            "A3a 101" => Some(AbilityId::A1a046AerodactylEx),
+            "A1a 056" => Some(AbilityId::A1a056Druddigon),
+            "A2 022" => Some(AbilityId::A2022Shaymin),
+            "A2 159" => Some(AbilityId::A2022Shaymin),
+            "A2 032" => Some(AbilityId::A2032Piloswine),
+            "A2 033" => Some(AbilityId::A2033Mamoswine),
+            "A2 160" => Some(AbilityId::A2033Mamoswine),
+            "A2 034" => Some(AbilityId::A2034Regice),
+            "A2 072" => Some(AbilityId::A2072Dusknoir),
+            "A2 078" => Some(AbilityId::A2078Giratina),
+            "A2 167" => Some(AbilityId::A2078Giratina),
+            "A2 087" => Some(AbilityId::A2087Regirock),
+            "A2 092" => Some(AbilityId::A2092Lucario),
+            "A2 170" => Some(AbilityId::A2092Lucario),
+            "A2 110" => Some(AbilityId::A2110DarkraiEx),
+            "A2 187" => Some(AbilityId::A2110DarkraiEx),
+            "A2 202" => Some(AbilityId::A2110DarkraiEx),
+            "P-A 042" => Some(AbilityId::A2110DarkraiEx),
+            "A2 114" => Some(AbilityId::A2114Bastiodon),
+            "A2 123" => Some(AbilityId::A2123Garchomp),
+            "A2 175" => Some(AbilityId::A2123Garchomp),
+            "A2a 009" => Some(AbilityId::A2a009Carnivine),
+            "A2a 010" => Some(AbilityId::A2a010LeafeonEx),
+            "A2a 082" => Some(AbilityId::A2a010LeafeonEx),
+            "A2a 091" => Some(AbilityId::A2a010LeafeonEx),
+            "A2a 013" => Some(AbilityId::A2a013Heatran),
+            "A2a 021" => Some(AbilityId::A2a021Abomasnow),
+            "A2a 022" => Some(AbilityId::A2a022GlaceonEx),
+            "A2a 083" => Some(AbilityId::A2a022GlaceonEx),
+            "A2a 092" => Some(AbilityId::A2a022GlaceonEx),
+            "A2a 026" => Some(AbilityId::A2a026Raichu),
+            "P-A 044" => Some(AbilityId::A2a026Raichu),
+            "A2a 034" => Some(AbilityId::A2a034Unown),
+            "A2a 078" => Some(AbilityId::A2a034Unown),
+            "A2a 035" => Some(AbilityId::A2a035Rotom),
+            "A2a 041" => Some(AbilityId::A2a041Tyranitar),
+            "A2a 050" => Some(AbilityId::A2a050Crobat),
+            "A2a 055" => Some(AbilityId::A2a055Magnezone),
+            "A2a 069" => Some(AbilityId::A2a069Shaymin),
+            "A2a 081" => Some(AbilityId::A2a069Shaymin),
+            "A2a 071" => Some(AbilityId::A2a071ArceusEx),
+            "A2a 086" => Some(AbilityId::A2a071ArceusEx),
+            "A2a 095" => Some(AbilityId::A2a071ArceusEx),
+            "A2a 096" => Some(AbilityId::A2a071ArceusEx),
+            "A2b 021" => Some(AbilityId::A2b021Tatsugiri),
+            "A2b 075" => Some(AbilityId::A2b021Tatsugiri),
+            "A2b 028" => Some(AbilityId::A2b028Pawmot),
+            "P-A 054" => Some(AbilityId::A2b028Pawmot),
+            "A2b 035" => Some(AbilityId::A2b035GiratinaEx),
+            "A2b 083" => Some(AbilityId::A2b035GiratinaEx),
+            "A2b 096" => Some(AbilityId::A2b035GiratinaEx),
+            "A2b 051" => Some(AbilityId::A2b051Grafaiai),
+            "A2b 076" => Some(AbilityId::A2b051Grafaiai),
+            "A2b 061" => Some(AbilityId::A2b061Wigglytuff),
+            "A2b 078" => Some(AbilityId::A2b061Wigglytuff),
+            "A3 229" => Some(AbilityId::A2b061Wigglytuff),
+            "A3 021" => Some(AbilityId::A3021Wimpod),
+            "A3 048" => Some(AbilityId::A3048Primarina),
+            "A3 054" => Some(AbilityId::A3054Pyukumuku),
+            "A3 163" => Some(AbilityId::A3054Pyukumuku),
+            "A3 066" => Some(AbilityId::A3066Oricorio),
+            "A3 165" => Some(AbilityId::A3066Oricorio),
+            "A3 080" => Some(AbilityId::A3080Comfey),
+            "A3 168" => Some(AbilityId::A3080Comfey),
+            "A3 087" => Some(AbilityId::A3087LunalaEx),
+            "A3 186" => Some(AbilityId::A3087LunalaEx),
+            "A3 204" => Some(AbilityId::A3087LunalaEx),
+            "A3 238" => Some(AbilityId::A3087LunalaEx),
+            "A3 096" => Some(AbilityId::A3096Conkeldurr),
+            "A3 104" => Some(AbilityId::A3104PassimianEx),
+            "A3 187" => Some(AbilityId::A3104PassimianEx),
+            "A3 205" => Some(AbilityId::A3104PassimianEx),
+            "A3 109" => Some(AbilityId::A3109AlolanPersian),
+            "A3 122" => Some(AbilityId::A3122SolgaleoEx),
+            "A3 189" => Some(AbilityId::A3122SolgaleoEx),
+            "A3 207" => Some(AbilityId::A3122SolgaleoEx),
+            "A3 239" => Some(AbilityId::A3122SolgaleoEx),
+            "A3 141" => Some(AbilityId::A3141Komala),
+            "A3 179" => Some(AbilityId::A3141Komala),
+            "A3a 015" => Some(AbilityId::A3a015Luxray),
+            "A3a 021" => Some(AbilityId::A3a021Zeraora),
+            "A3a 027" => Some(AbilityId::A3a027Shiinotic),
+            "A3a 031" => Some(AbilityId::A3a031Claydol),
+            "A3a 042" => Some(AbilityId::A3a042Nihilego),
+            "A3a 103" => Some(AbilityId::A3a042Nihilego),
+            "A3a 052" => Some(AbilityId::A3a052Ferrothorn),
+            "A3a 056" => Some(AbilityId::A3a056Stoutland),
+            "A3a 062" => Some(AbilityId::A3a062Celesteela),
+            "A3a 075" => Some(AbilityId::A3a062Celesteela),
+            "A3b 009" => Some(AbilityId::A3b009FlareonEx),
+            "A3b 079" => Some(AbilityId::A3b009FlareonEx),
+            "A3b 087" => Some(AbilityId::A3b009FlareonEx),
+            "A3b 034" => Some(AbilityId::A3b034SylveonEx),
+            "A3b 081" => Some(AbilityId::A3b034SylveonEx),
+            "A3b 089" => Some(AbilityId::A3b034SylveonEx),
+            "A3b 056" => Some(AbilityId::A3b056EeveeEx),
+            "A3b 083" => Some(AbilityId::A3b056EeveeEx),
+            "A3b 092" => Some(AbilityId::A3b056EeveeEx),
+            "A3b 057" => Some(AbilityId::A3b057SnorlaxEx),
+            "A3b 084" => Some(AbilityId::A3b057SnorlaxEx),
+            "A3b 091" => Some(AbilityId::A3b057SnorlaxEx),
+            "A3b 059" => Some(AbilityId::A3b059Ambipom),
+            "A3b 099" => Some(AbilityId::A3b099Gardevoir),
+            "P-A 037" => Some(AbilityId::PA037CresseliaEx),
+            "P-A 047" => Some(AbilityId::PA047Staraptor),
+            _ => None,
+        }
     }
 }
@@ -1,21 +1,28 @@
 use log::debug;
 
-use crate::{ability_ids::AbilityId, types::EnergyType, State};
+use crate::{
+    ability_ids::AbilityId, actions::SimpleAction, hooks::can_be_affected_by_status,
+    types::EnergyType, State,
+};
 
-// This is a reducer of all actions relating to abilities.
+// This is a reducer of all actions relating to (activated) abilities, and to the optional
+// ones the player chooses to use when they trigger. Other abilities are applied by the engine
+// when they trigger (see `AbilityTrigger`).
 pub(crate) fn apply_abilities_action(acting_player: usize, state: &mut State, index: usize) {
     let pokemon = state.in_play_pokemon[acting_player][index]
-        .as_mut()
+        .as_ref()
         .expect("Pokemon should be there if using ability");
-    pokemon.ability_used = true;
-    let ability_id = AbilityId::from_pokemon_id(&pokemon.get_id()[..])
+    let ability_id = AbilityId::from_pokemon_id(pokemon.card.get_id_str())
         .expect("Pokemon should have ability implemented");
+    state.update_pokemon(acting_player, index, |pokemon| pokemon.ability_used = true);
     match ability_id {
         AbilityId::A1007Butterfree => {
             // Once during your turn, you may heal 20 damage from each of your Pokemon.
             debug!("Butterfree's ability: Healing 20 damage from each Pokemon");
-            for pokemon in state.in_play_pokemon[acting_player].iter_mut().flatten() {
-                pokemon.heal(20);
+            for in_play_idx in 0..state.in_play_pokemon[acting_player].len() {
+                if state.in_play_pokemon[acting_player][in_play_idx].is_some() {
+                    state.update_pokemon(acting_player, in_play_idx, |pokemon| pokemon.heal(20));
+                }
             }
         }
         AbilityId::A1177Weezing => {
@@ -23,18 +30,25 @@ pub(crate) fn apply_abilities_action(acting_player: usize, state: &mut State, in
             debug!("Weezing's ability: Poisoning opponent's active Pokemon");
             let opponent = (acting_player + 1) % 2;
             let opponent_active = state.in_play_pokemon[opponent][0]
-                .as_mut()
+                .as_ref()
                 .expect("Opponent should have active pokemon");
-            opponent_active.poisoned = true;
+            if can_be_affected_by_status(opponent_active) {
+                state.update_pokemon(opponent, 0, |x| x.poisoned = true);
+            }
         }
         AbilityId::A1132Gardevoir => {
             // Once during your turn, you may take 1 Psychic Energy from your Energy\n        Zone and attach it to the Psychic Pokemon in the Active Spot.
             debug!("Gardevoir's ability: Attaching 1 Psychic Energy to active Pokemon");
-            let active = state.get_active_mut(acting_player);
-            active.attach_energy(&EnergyType::Psychic, 1);
+            state.attach_energy(acting_player, 0, EnergyType::Psychic, 1);
         }
-        AbilityId::A2a071Arceus => {
-            panic!("Arceus's ability cant be used");
+        AbilityId::A3b034SylveonEx => {
+            // Once during your turn, when you play this Pokémon from your hand to evolve 1 of your Pokémon, you may draw 2 cards.
+            debug!("Sylveon ex's ability: Drawing 2 cards");
+            // Each card is drawn as its own chance node
+            for _ in 0..2 {
+                state.push_choices(acting_player, vec![SimpleAction::DrawCard]);
+            }
         }
+        _ => unreachable!("{:?} is not an activated ability", ability_id),
     }
 }
@@ -3,15 +3,21 @@ use std::panic;
 use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng};
 
 use crate::{
-    hooks::{get_retreat_cost, on_attach_tool, to_playable_card},
+    ability_ids::AbilityId,
+    error::DeckgymError,
+    hooks::{
+        ability_trigger, get_retreat_cost, on_attach_tool, on_evolve, to_playable_card,
+        tool_hp_bonus, try_to_playable_card, AbilityTrigger,
+    },
     state::State,
-    types::{Card, PlayedCard},
+    types::{Card, CardRef},
 };
 
 use super::{
     apply_abilities_action::apply_abilities_action,
     apply_action_helpers::{
-        apply_common_mutation, forecast_end_turn, handle_attack_damage, Mutations, Probabilities,
+        apply_common_mutation, discard_energy_cost, forecast_draw, forecast_end_turn,
+        handle_attack_damage, Mutations, Probabilities,
     },
     apply_attack_action::forecast_attack,
     apply_trainer_action::forecast_trainer_action,
@@ -22,7 +28,17 @@ use super::{
 /// and then chooses one of them to apply. This is so that bot implementations can re-use the
 /// `forecast_action` function.
 pub fn apply_action(rng: &mut StdRng, state: &mut State, action: &Action) {
-    let (probabilities, mut lazy_mutations) = forecast_action(state, action);
+    try_apply_action(rng, state, action).unwrap_or_else(|err| panic!("{err}"));
+}
+
+/// Like `apply_action`, but returns an error (leaving `state` untouched) if the action
+/// involves a card that is not implemented.
+pub fn try_apply_action(
+    rng: &mut StdRng,
+    state: &mut State,
+    action: &Action,
+) -> Result<(), DeckgymError> {
+    let (probabilities, mut lazy_mutations) = try_forecast_action(state, action)?;
     if probabilities.len() == 1 {
         lazy_mutations.remove(0)(rng, state, action);
     } else {
@@ -30,15 +46,44 @@ pub fn apply_action(rng: &mut StdRng, state: &mut State, action: &Action) {
         let chosen_index = dist.sample(rng);
         lazy_mutations.remove(chosen_index)(rng, state, action);
     }
+    Ok(())
+}
+
+pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutations) {
+    try_forecast_action(state, action).unwrap_or_else(|err| panic!("{err}"))
 }
 
 /// This should be mostly a "router" function that calls the appropriate forecast function
 /// based on the action type.
-pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutations) {
+pub(crate) fn try_forecast_action(
+    state: &State,
+    action: &Action,
+) -> Result<(Probabilities, Mutations), DeckgymError> {
+    // Check upfront what the (deterministic) mutations would otherwise panic on.
     match &action.action {
+        SimpleAction::Place(card, _) | SimpleAction::Evolve(card, _) => {
+            try_to_playable_card(*card, true)?;
+        }
+        SimpleAction::UseAbility(in_play_idx) => {
+            let id = state.in_play_pokemon[action.actor][*in_play_idx]
+                .as_ref()
+                .expect("Pokemon should be there if using ability")
+                .get_id();
+            match AbilityId::from_pokemon_id(&id).and_then(ability_trigger) {
+                None => return Err(DeckgymError::UnsupportedAbility(id)),
+                Some(AbilityTrigger::Activated) => {}
+                // Optional triggered abilities are chosen from the stack
+                Some(AbilityTrigger::OnEvolve) if action.is_stack => {}
+                Some(_) => return Err(DeckgymError::UnusableAbility(id)),
+            }
+        }
+        _ => {}
+    }
+
+    let forecast: (Probabilities, Mutations) = match &action.action {
+        SimpleAction::DrawCard => forecast_draw(state, action.actor),
         // Deterministic Actions
-        SimpleAction::DrawCard // TODO: DrawCard should return actual deck probabilities.
-        | SimpleAction::Place(_, _)
+        SimpleAction::Place(_, _)
         | SimpleAction::Attach { .. }
         | SimpleAction::AttachTool { .. }
         | SimpleAction::Evolve(_, _)
@@ -47,7 +92,9 @@ pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutati
         | SimpleAction::Retreat(_)
         | SimpleAction::ApplyDamage { .. }
         | SimpleAction::Heal { .. }
-        | SimpleAction::PreventDamage { .. } => (
+        | SimpleAction::PreventDamage { .. }
+        | SimpleAction::DiscardEnergy { .. }
+        | SimpleAction::Noop => (
             vec![1.0],
             vec![Box::new({
                 |_, mutable_state, action| {
@@ -55,32 +102,26 @@ pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutati
                 }
             })],
         ),
-        SimpleAction::Attack(index) => forecast_attack(action.actor, state, *index),
+        SimpleAction::Attack(index) => forecast_attack(action.actor, state, *index)?,
         SimpleAction::Play { trainer_card } => {
-            forecast_trainer_action(action.actor, state, trainer_card)
+            forecast_trainer_action(action.actor, state, trainer_card)?
         }
         // acting_player is not passed here, because there is only 1 turn to end. The current turn.
         SimpleAction::EndTurn => forecast_end_turn(state),
-    }
+    };
+    Ok(forecast)
 }
 
 fn apply_deterministic_action(state: &mut State, action: &Action) {
     apply_common_mutation(state, action);
 
     match &action.action {
-        SimpleAction::DrawCard => {
-            state.maybe_draw_card(action.actor);
-        }
         SimpleAction::Attach {
             attachments,
             is_turn_energy,
         } => {
             for (amount, energy, in_play_idx) in attachments {
-                state.in_play_pokemon[action.actor][*in_play_idx]
-                    .as_mut()
-                    .expect("Pokemon should be there if attaching energy to it")
-                    .attached_energy
-                    .extend(std::iter::repeat_n(*energy, *amount as usize));
+                state.attach_energy(action.actor, *in_play_idx, *energy, *amount);
             }
             if *is_turn_energy {
                 state.current_energy = None;
@@ -90,19 +131,18 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
             in_play_idx,
             tool_id,
         } => {
-            state.in_play_pokemon[action.actor][*in_play_idx]
-                .as_mut()
-                .expect("Pokemon should be there if attaching tool to it")
-                .attached_tool = Some(*tool_id);
+            state.update_pokemon(action.actor, *in_play_idx, |pokemon| {
+                pokemon.attached_tool = Some(*tool_id);
+            });
             on_attach_tool(state, action.actor, *in_play_idx, *tool_id);
         }
         SimpleAction::Place(card, index) => {
-            let played_card = to_playable_card(card, true);
-            state.in_play_pokemon[action.actor][*index] = Some(played_card);
-            state.remove_card_from_hand(action.actor, card);
+            let played_card = to_playable_card(*card, true);
+            state.set_in_play(action.actor, *index, Some(played_card));
+            state.remove_card_from_hand(action.actor, *card);
         }
         SimpleAction::Evolve(card, position) => {
-            apply_evolve(action.actor, state, card, *position);
+            apply_evolve(action.actor, state, *card, *position);
         }
         SimpleAction::UseAbility(position) => {
             apply_abilities_action(action.actor, state, *position);
@@ -129,50 +169,56 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
         } => {
             apply_prevent_damage(action.actor, state, *amount, *duration);
         }
+        SimpleAction::DiscardEnergy {
+            in_play_idx,
+            energies,
+        } => {
+            for energy in energies {
+                state.discard_energy(action.actor, *in_play_idx, *energy);
+            }
+        }
+        SimpleAction::Noop => {}
         _ => panic!("Deterministic Action expected"),
     }
 }
 
 fn apply_healing(acting_player: usize, state: &mut State, position: usize, amount: u32) {
-    let active = state.in_play_pokemon[acting_player][position]
-        .as_mut()
-        .expect("Pokemon should be there if applying potion to it");
-    active.heal(amount);
+    state.update_pokemon(acting_player, position, |pokemon| pokemon.heal(amount));
 }
 
 fn apply_prevent_damage(acting_player: usize, state: &mut State, amount: u32, duration: u32) {
-    let active = state.in_play_pokemon[acting_player][0]
-        .as_mut()
-        .expect("Active Pokemon should be there if applying damage prevention");
-    active.damage_prevention = Some((amount, state.turn_count as u32 + duration as u32));
+    let expires_turn = state.turn_count as u32 + duration;
+    state.update_pokemon(acting_player, 0, |active| {
+        active.damage_prevention = Some((amount, expires_turn));
+    });
 }
 
 fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_free: bool) {
-    if !is_free {
+    let retreat_cost = if is_free {
+        vec![]
+    } else {
         let active = state.in_play_pokemon[acting_player][0]
             .as_ref()
             .expect("Active Pokemon should be there if paid retreating");
-        let retreat_cost = get_retreat_cost(state, active);
-        let attached_energy: &mut Vec<_> = state.in_play_pokemon[acting_player][0]
-            .as_mut()
-            .expect("Active Pokemon should be there if paid retreating")
-            .attached_energy
-            .as_mut();
-
-        // TODO: Maybe give option to user to select which energy to discard
-        let count = retreat_cost.len();
-        attached_energy.truncate(attached_energy.len() - count);
-    }
+        get_retreat_cost(state, active)
+    };
+
+    state.swap_in_play(acting_player, 0, bench_idx);
+    state.remove_active_effects(acting_player);
 
-    state.in_play_pokemon[acting_player].swap(0, bench_idx);
+    // The player chooses which energy pays the cost (from the Pokemon now in the Bench)
+    if !retreat_cost.is_empty() {
+        discard_energy_cost(state, acting_player, bench_idx, &retreat_cost);
+    }
 
     // Cure any status conditions
-    if let Some(pokemon) = &state.in_play_pokemon[acting_player][bench_idx] {
-        state.in_play_pokemon[acting_player][bench_idx] = Some(PlayedCard {
-            poisoned: false,
-            paralyzed: false,
-            asleep: false,
-            ..pokemon.clone()
+    if state.in_play_pokemon[acting_player][bench_idx].is_some() {
+        state.update_pokemon(acting_player, bench_idx, |pokemon| {
+            pokemon.poisoned = false;
+            pokemon.paralyzed = false;
+            pokemon.asleep = false;
+            pokemon.burned = false;
+            pokemon.confused = false;
         });
     }
 
@@ -181,28 +227,39 @@ fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_f
 
 // We will replace the PlayedCard, but taking into account the attached energy
 //  and the remaining HP.
-fn apply_evolve(acting_player: usize, state: &mut State, card: &Card, position: usize) {
+fn apply_evolve(acting_player: usize, state: &mut State, card: CardRef, position: usize) {
     // This removes status conditions
     let mut played_card = to_playable_card(card, true);
 
     let old_pokemon = state.in_play_pokemon[acting_player][position]
         .as_ref()
         .expect("Pokemon should be there if evolving it");
-    if let Card::Pokemon(pokemon_card) = &played_card.card {
+    if let Card::Pokemon(pokemon_card) = &*played_card.card {
         if pokemon_card.stage == 0 {
             panic!("Only stage 1 or 2 pokemons can be evolved");
         }
 
+        // The tool stays attached (and its HP bonus may change, e.g. Leaf Cape)
+        if let Some(tool_id) = old_pokemon.attached_tool {
+            let hp_bonus = tool_hp_bonus(tool_id, &played_card.card);
+            played_card.attached_tool = Some(tool_id);
+            played_card.total_hp += hp_bonus;
+            played_card.remaining_hp += hp_bonus;
+        }
         let damage_taken = old_pokemon.total_hp - old_pokemon.remaining_hp;
         played_card.remaining_hp -= damage_taken;
         played_card.attached_energy = old_pokemon.attached_energy.clone();
         played_card.cards_behind = old_pokemon.cards_behind.clone();
-        played_card.cards_behind.push(old_pokemon.card.clone());
-        state.in_play_pokemon[acting_player][position] = Some(played_card);
+        played_card.cards_behind.push(old_pokemon.card);
+        state.set_in_play(acting_player, position, Some(played_card));
+        if position == 0 {
+            state.remove_active_effects(acting_player);
+        }
     } else {
         panic!("Only Pokemon cards can be evolved");
     }
     state.remove_card_from_hand(acting_player, card);
+    on_evolve(state, acting_player, position);
     // NOTE: Phantomly leave the Stage 0 card behind the newly evolved card
 }
 
@@ -214,16 +271,17 @@ mod tests {
     use super::*;
     use crate::card_ids::CardId;
     use crate::database::get_card_by_enum;
-    use crate::types::PlayedCard;
+    use crate::tool_ids::ToolId;
+    use crate::types::{CardRef, PlayedCard};
     use crate::{types::EnergyType, Deck};
 
     #[test]
     fn test_apply_evolve() {
         let mut state = State::new(&Deck::default(), &Deck::default());
         let energy = EnergyType::Colorless;
-        let mankey = get_card_by_enum(CardId::PA017Mankey);
-        let primeape = get_card_by_enum(CardId::A1142Primeape);
-        let mut base_played_card = to_playable_card(&mankey, false);
+        let mankey: CardRef = get_card_by_enum(CardId::PA017Mankey).into();
+        let primeape: CardRef = get_card_by_enum(CardId::A1142Primeape).into();
+        let mut base_played_card = to_playable_card(mankey, false);
         base_played_card.remaining_hp = 20; // 30 damage taken
         base_played_card.attached_energy = vec![energy];
         state.in_play_pokemon[0][0] = Some(base_played_card.clone());
@@ -231,14 +289,14 @@ mod tests {
         healthy_bench.remaining_hp = 50;
         healthy_bench.attached_energy = vec![energy, energy, energy];
         state.in_play_pokemon[0][2] = Some(healthy_bench);
-        state.hands[0] = vec![primeape.clone(), primeape.clone()];
+        state.hands[0] = vec![primeape, primeape];
 
         // Evolve Active
-        apply_evolve(0, &mut state, &primeape, 0);
+        apply_evolve(0, &mut state, primeape, 0);
         assert_eq!(
             state.in_play_pokemon[0][0],
             Some(PlayedCard {
-                card: primeape.clone(),
+                card: primeape,
                 remaining_hp: 60, // 90 - 30 = 60
                 total_hp: 90,
                 attached_energy: vec![energy],
@@ -248,17 +306,19 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()],
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey],
                 damage_prevention: None,
             })
         );
 
         // Evolve Bench
-        apply_evolve(0, &mut state, &primeape, 2);
+        apply_evolve(0, &mut state, primeape, 2);
         assert_eq!(
             state.in_play_pokemon[0][0],
             Some(PlayedCard {
-                card: primeape.clone(),
+                card: primeape,
                 remaining_hp: 60, // 90 - 30 = 60
                 total_hp: 90,
                 attached_energy: vec![energy],
@@ -268,14 +328,16 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()],
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey],
                 damage_prevention: None,
             })
         );
         assert_eq!(
             state.in_play_pokemon[0][2],
             Some(PlayedCard {
-                card: primeape.clone(),
+                card: primeape,
                 remaining_hp: 90, // 90 - 0 = 90
                 total_hp: 90,
                 attached_energy: vec![energy, energy, energy],
@@ -285,7 +347,9 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()],
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey],
                 damage_prevention: None,
             })
         );
@@ -296,9 +360,9 @@ mod tests {
         let mut state = State::new(&Deck::default(), &Deck::default());
         // PUT Mankey in Active and Primeape in Bench 2
         let mankey = get_card_by_enum(CardId::A1141Mankey);
-        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        let primeape: CardRef = get_card_by_enum(CardId::A1142Primeape).into();
         state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, false));
-        state.in_play_pokemon[0][2] = Some(to_playable_card(&primeape, false));
+        state.in_play_pokemon[0][2] = Some(to_playable_card(primeape, false));
 
         // Forcefully Activate Primeape
         let mut rng: StdRng = StdRng::seed_from_u64(rand::random());
@@ -311,11 +375,151 @@ mod tests {
 
         assert_eq!(
             state.in_play_pokemon[0][0],
-            Some(to_playable_card(&primeape, false))
+            Some(to_playable_card(primeape, false))
         );
         assert_eq!(
             state.in_play_pokemon[0][2],
             Some(to_playable_card(&mankey, false))
         );
     }
+
+    #[test]
+    fn test_evolving_keeps_tool() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur: CardRef = get_card_by_enum(CardId::A1002Ivysaur).into();
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        on_attach_tool(&mut state, 0, 0, ToolId::A2147GiantCape);
+        state.get_active_mut(0).attached_tool = Some(ToolId::A2147GiantCape);
+        state.get_active_mut(0).apply_damage(10);
+        state.hands[0] = vec![ivysaur];
+
+        apply_evolve(0, &mut state, ivysaur, 0);
+        let evolved = state.get_active(0);
+        assert_eq!(evolved.attached_tool, Some(ToolId::A2147GiantCape));
+        assert_eq!(evolved.total_hp, 110); // 90 + 20
+        assert_eq!(evolved.remaining_hp, 100);
+    }
+
+    #[test]
+    fn test_retreat_lets_player_choose_energy() {
+        // Blastoise retreats with 3 Colorless
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let blastoise = get_card_by_enum(CardId::A1055Blastoise);
+        let mut active = to_playable_card(&blastoise, false);
+        active.attached_energy = vec![
+            EnergyType::Water,
+            EnergyType::Water,
+            EnergyType::Water,
+            EnergyType::Fire,
+        ];
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&blastoise, false));
+
+        apply_retreat(0, &mut state, 1, false);
+        let (actor, choices) = state.move_generation_stack.last().unwrap().clone();
+        assert_eq!(actor, 0);
+        let keep_fire = SimpleAction::DiscardEnergy {
+            in_play_idx: 1,
+            energies: vec![EnergyType::Water; 3],
+        };
+        assert_eq!(choices.len(), 2);
+        assert!(choices.contains(&keep_fire));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = Action {
+            actor: 0,
+            action: keep_fire,
+            is_stack: true,
+        };
+        apply_action(&mut rng, &mut state, &action);
+        assert!(state.move_generation_stack.is_empty());
+        assert_eq!(
+            state.in_play_pokemon[0][1]
+                .as_ref()
+                .unwrap()
+                .attached_energy,
+            vec![EnergyType::Fire]
+        );
+
+        // With a single way of paying, energy is discarded right away
+        state.get_active_mut(0).attached_energy = vec![EnergyType::Water; 4];
+        apply_retreat(0, &mut state, 1, false);
+        assert!(state.move_generation_stack.is_empty());
+        assert_eq!(
+            state.in_play_pokemon[0][1]
+                .as_ref()
+                .unwrap()
+                .attached_energy,
+            vec![EnergyType::Water]
+        );
+    }
+
+    #[test]
+    fn test_sylveon_ex_ability_is_optional() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let eevee: CardRef = CardId::A1206Eevee.into();
+        let sylveon_ex: CardRef = CardId::A3b034SylveonEx.into();
+        state.in_play_pokemon[0][0] = Some(to_playable_card(eevee, false));
+        state.hands[0] = vec![sylveon_ex];
+        state.decks[0].cards = vec![eevee; 5];
+        let mut rng = StdRng::seed_from_u64(0);
+        let stack_action = |action| Action {
+            actor: 0,
+            action,
+            is_stack: true,
+        };
+
+        let evolve = Action {
+            actor: 0,
+            action: SimpleAction::Evolve(sylveon_ex, 0),
+            is_stack: false,
+        };
+        apply_action(&mut rng, &mut state, &evolve);
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::UseAbility(0), SimpleAction::Noop])]
+        );
+
+        // Declining draws nothing
+        let mut declined = state.clone();
+        apply_action(&mut rng, &mut declined, &stack_action(SimpleAction::Noop));
+        assert!(declined.move_generation_stack.is_empty());
+        assert!(declined.hands[0].is_empty());
+
+        // Using the ability draws 2 cards, one at a time
+        let use_ability = stack_action(SimpleAction::UseAbility(0));
+        apply_action(&mut rng, &mut state, &use_ability);
+        while !state.move_generation_stack.is_empty() {
+            apply_action(&mut rng, &mut state, &stack_action(SimpleAction::DrawCard));
+        }
+        assert_eq!(state.hands[0].len(), 2);
+    }
+
+    #[test]
+    fn test_evolving_and_retreating_cure_status() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let mankey: CardRef = get_card_by_enum(CardId::PA017Mankey).into();
+        let primeape: CardRef = get_card_by_enum(CardId::A1142Primeape).into();
+        let mut paralyzed_mankey = to_playable_card(mankey, false);
+        paralyzed_mankey.paralyzed = true;
+        paralyzed_mankey.poisoned = true;
+        paralyzed_mankey.burned = true;
+        state.in_play_pokemon[0][0] = Some(paralyzed_mankey);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(mankey, false));
+        state.hands[0] = vec![primeape];
+
+        // Evolving cures all status conditions
+        apply_evolve(0, &mut state, primeape, 0);
+        let active = state.get_active(0);
+        assert!(!active.paralyzed && !active.poisoned && !active.asleep && !active.burned);
+
+        // Retreating cures the Pokemon that goes to the Bench
+        state.get_active_mut(0).confused = true;
+        state.get_active_mut(0).burned = true;
+        apply_retreat(0, &mut state, 1, true);
+        let benched = state.in_play_pokemon[0][1].as_ref().unwrap();
+        assert_eq!(benched.card, primeape);
+        assert!(!benched.confused && !benched.burned);
+    }
 }
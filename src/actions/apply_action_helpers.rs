@@ -2,7 +2,14 @@ use log::debug;
 use rand::rngs::StdRng;
 
 use crate::{
-    actions::SimpleAction, hooks::get_counterattack_damage, state::GameOutcome, types::Card, State,
+    actions::SimpleAction,
+    hooks::{
+        ability_damage_reduction, energy_discard_options, get_between_turns_damage,
+        get_counterattack_damage, on_end_turn_tools, on_tool_damage_received, on_tool_knockout,
+    },
+    state::{GameOutcome, Zone},
+    types::{Card, CardRef, EnergyType, TrainerType},
+    State,
 };
 
 use super::Action;
@@ -45,46 +52,70 @@ pub(crate) fn forecast_end_turn(state: &State) -> (Probabilities, Mutations) {
 
 /// Handle Status Effects
 fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
+    // Tools act at the end of the turn, before the checkup (e.g. Lum Berry cures conditions)
+    let mut state = state.clone();
+    on_end_turn_tools(&mut state);
+    let state = &state;
+
     let mut sleeps_to_handle = vec![];
     let mut paralyzed_to_handle = vec![];
     let mut poisons_to_handle = vec![];
+    let mut burns_to_handle = vec![];
     for player in 0..2 {
         for (i, pokemon) in state.enumerate_in_play_pokemon(player) {
             if pokemon.asleep {
                 sleeps_to_handle.push((player, i));
             }
-            if pokemon.paralyzed {
+            // Paralysis wears off at the end of its owner's turn
+            if pokemon.paralyzed && player == state.current_player {
                 paralyzed_to_handle.push((player, i));
             }
             if pokemon.poisoned {
                 poisons_to_handle.push((player, i));
                 debug!("{}'s Pokemon {} is poisoned", player, i);
             }
+            if pokemon.burned {
+                burns_to_handle.push((player, i));
+                debug!("{}'s Pokemon {} is burned", player, i);
+            }
         }
     }
 
     // Get all binary vectors representing the possible outcomes.
     // These are the "outcome_ids" (e.g. outcome [true, false] might represent
-    // waking up one pokemon and not another).
-    let outcome_ids = generate_boolean_vectors(sleeps_to_handle.len());
-    let probabilities = vec![1.0 / outcome_ids.len() as f64; outcome_ids.len()];
+    // waking up one pokemon and not another). The first entries are the sleep flips,
+    // the rest are the burn recovery flips.
+    // Each is combined with the possible Energy Zone rolls of the incoming player.
+    let outcome_ids = generate_boolean_vectors(sleeps_to_handle.len() + burns_to_handle.len());
+    let sleep_probability = 1.0 / outcome_ids.len() as f64;
+    let next_player = (state.current_player + 1) % 2;
+    let energy_outcomes = state.energy_roll_outcomes(next_player);
+    let mut probabilities = vec![];
     let mut outcomes: Mutations = vec![];
     for outcome in outcome_ids {
-        let sleeps_to_handle = sleeps_to_handle.clone();
-        let paralyzed_to_handle = paralyzed_to_handle.clone();
-        let poisons_to_handle = poisons_to_handle.clone();
-        outcomes.push(Box::new({
-            |_, state, action| {
-                apply_common_mutation(state, action);
-                apply_pokemon_checkup(
-                    state,
-                    sleeps_to_handle,
-                    paralyzed_to_handle,
-                    poisons_to_handle,
-                    outcome,
-                );
-            }
-        }));
+        for (energy_probability, rolled_energy) in energy_outcomes.iter().copied() {
+            let outcome = outcome.clone();
+            let sleeps_to_handle = sleeps_to_handle.clone();
+            let paralyzed_to_handle = paralyzed_to_handle.clone();
+            let poisons_to_handle = poisons_to_handle.clone();
+            let burns_to_handle = burns_to_handle.clone();
+            probabilities.push(sleep_probability * energy_probability);
+            outcomes.push(Box::new({
+                move |_, state, action| {
+                    apply_common_mutation(state, action);
+                    on_end_turn_tools(state);
+                    apply_pokemon_checkup(
+                        state,
+                        sleeps_to_handle,
+                        paralyzed_to_handle,
+                        poisons_to_handle,
+                        burns_to_handle,
+                        outcome,
+                        rolled_energy,
+                    );
+                }
+            }));
+        }
     }
     (probabilities, outcomes)
 }
@@ -94,32 +125,58 @@ fn apply_pokemon_checkup(
     sleeps_to_handle: Vec<(usize, usize)>,
     paralyzed_to_handle: Vec<(usize, usize)>,
     poisons_to_handle: Vec<(usize, usize)>,
+    burns_to_handle: Vec<(usize, usize)>,
     outcome: Vec<bool>,
+    rolled_energy: Option<EnergyType>,
 ) {
-    for (i, is_awake) in sleeps_to_handle.iter().zip(outcome) {
+    let (sleep_outcome, burn_outcome) = outcome.split_at(sleeps_to_handle.len());
+    for (i, &is_awake) in sleeps_to_handle.iter().zip(sleep_outcome) {
         if is_awake {
             let (player, in_play_idx) = i;
-            let pokemon = mutated_state.in_play_pokemon[*player][*in_play_idx]
-                .as_mut()
-                .expect("Pokemon should be there...");
-            pokemon.asleep = false;
+            mutated_state.update_pokemon(*player, *in_play_idx, |x| x.asleep = false);
             debug!("{}'s Pokemon {} woke up", player, in_play_idx);
         }
     }
     // These always happen regardless of outcome_binary_vector
     for (player, in_play_idx) in paralyzed_to_handle {
-        let pokemon = mutated_state.in_play_pokemon[player][in_play_idx]
-            .as_mut()
-            .expect("Pokemon should be there...");
-        pokemon.paralyzed = false;
+        mutated_state.update_pokemon(player, in_play_idx, |x| x.paralyzed = false);
         debug!("{}'s Pokemon {} is un-paralyzed", player, in_play_idx);
     }
-    for (player, in_play_idx) in poisons_to_handle {
+    for (i, &is_recovered) in burns_to_handle.iter().zip(burn_outcome) {
+        if is_recovered {
+            let (player, in_play_idx) = i;
+            mutated_state.update_pokemon(*player, *in_play_idx, |x| x.burned = false);
+            debug!("{}'s Pokemon {} is no longer burned", player, in_play_idx);
+        }
+    }
+    // Poison, burn and ability (e.g. Glaceon ex) damage of the same Pokemon is dealt at
+    // once, so that a K.O. is only handled once.
+    let ability_damage = get_between_turns_damage(mutated_state);
+    let mut status_damage: Vec<(usize, usize, u32)> = vec![];
+    let all_damage = poisons_to_handle
+        .into_iter()
+        .map(|(player, in_play_idx)| (player, in_play_idx, 10))
+        .chain(
+            burns_to_handle
+                .into_iter()
+                .map(|(player, in_play_idx)| (player, in_play_idx, 20)),
+        )
+        .chain(ability_damage);
+    for (player, in_play_idx, amount) in all_damage {
+        match status_damage
+            .iter_mut()
+            .find(|(p, i, _)| *p == player && *i == in_play_idx)
+        {
+            Some((_, _, damage)) => *damage += amount,
+            None => status_damage.push((player, in_play_idx, amount)),
+        }
+    }
+    for (player, in_play_idx, damage) in status_damage {
         let opponent = (player + 1) % 2;
-        handle_attack_damage(mutated_state, opponent, &vec![(10, in_play_idx)]);
+        handle_damage(mutated_state, opponent, &vec![(damage, in_play_idx)], false);
     }
     // Advance turn
-    mutated_state.advance_turn();
+    mutated_state.advance_turn(rolled_energy);
 }
 
 fn generate_boolean_vectors(n: usize) -> Vec<Vec<bool>> {
@@ -139,6 +196,18 @@ pub(crate) fn handle_attack_damage(
     state: &mut State,
     attacking_player: usize,
     targets: &Vec<(u32, usize)>, // damage, in_play_idx
+) {
+    handle_damage(state, attacking_player, targets, true);
+}
+
+/// Deals damage to the opponent of `attacking_player` and handles the K.O.s. Counterattacks
+/// and tools that react to attacks are only triggered if `is_from_attack`
+/// (e.g. not for Poison damage in the Pokemon Checkup).
+pub(crate) fn handle_damage(
+    state: &mut State,
+    attacking_player: usize,
+    targets: &Vec<(u32, usize)>, // damage, in_play_idx
+    is_from_attack: bool,
 ) {
     let defending_player = (attacking_player + 1) % 2;
     let mut knockouts: Vec<(usize, usize)> = vec![];
@@ -148,46 +217,62 @@ pub(crate) fn handle_attack_damage(
         }
 
         // Create a closure for target_pokemon's mutations
-        let counter_damage = {
-            let target_pokemon = state.in_play_pokemon[defending_player][*target_pokemon_idx]
-                .as_mut()
-                .expect("Pokemon should be there if taking damage");
-            target_pokemon.apply_damage(*damage); // Applies without surpassing 0 HP
-            debug!(
-                "Dealt {} damage to opponent's {} Pokemon. Remaining HP: {}",
-                damage, target_pokemon_idx, target_pokemon.remaining_hp
-            );
-            if target_pokemon.remaining_hp == 0 {
-                knockouts.push((defending_player, *target_pokemon_idx));
-            }
+        let (mut counter_damage, defending_tool) =
+            state.update_pokemon(defending_player, *target_pokemon_idx, |target_pokemon| {
+                let damage = if is_from_attack {
+                    damage.saturating_sub(ability_damage_reduction(target_pokemon))
+                } else {
+                    *damage
+                };
+                target_pokemon.apply_damage(damage); // Applies without surpassing 0 HP
+                debug!(
+                    "Dealt {} damage to opponent's {} Pokemon. Remaining HP: {}",
+                    damage, target_pokemon_idx, target_pokemon.remaining_hp
+                );
+                if target_pokemon.remaining_hp == 0 {
+                    knockouts.push((defending_player, *target_pokemon_idx));
+                }
 
-            if *target_pokemon_idx == 0 {
-                get_counterattack_damage(target_pokemon)
-            } else {
-                0
-            }
-        };
+                if *target_pokemon_idx == 0 && is_from_attack {
+                    let is_knocked_out = target_pokemon.remaining_hp == 0;
+                    (
+                        get_counterattack_damage(target_pokemon, is_knocked_out),
+                        target_pokemon.attached_tool,
+                    )
+                } else {
+                    (0, None)
+                }
+            });
+        if let Some(tool_id) = defending_tool {
+            counter_damage += on_tool_damage_received(state, attacking_player, tool_id);
+        }
 
         // If pokemon not active, don't even look at counter-attack logic.
         if *target_pokemon_idx == 0 && counter_damage > 0 {
-            let attacking_pokemon = state.in_play_pokemon[attacking_player][0]
-                .as_mut()
-                .expect("Active Pokemon should be there");
-            attacking_pokemon.apply_damage(counter_damage);
+            let remaining_hp = state.update_pokemon(attacking_player, 0, |attacking_pokemon| {
+                attacking_pokemon.apply_damage(counter_damage);
+                attacking_pokemon.remaining_hp
+            });
             debug!(
                 "Dealt {} counterattack damage to active Pokemon. Remaining HP: {}",
-                counter_damage, attacking_pokemon.remaining_hp
+                counter_damage, remaining_hp
             );
-            if attacking_pokemon.remaining_hp == 0 {
+            if remaining_hp == 0 {
                 knockouts.push((attacking_player, 0));
             }
         }
     }
 
     // Handle knockouts: Discard cards and award points (to potentially short-circuit promotions)
+    let mut tool_choices: Vec<(usize, Vec<SimpleAction>)> = vec![];
     for (ko_receiver, ko_pokemon_idx) in knockouts.clone() {
+        if is_from_attack && ko_receiver == defending_player {
+            if let Some(choices) = on_tool_knockout(state, ko_receiver, ko_pokemon_idx) {
+                tool_choices.push((ko_receiver, choices));
+            }
+        }
         let ko_pokemon = state.in_play_pokemon[ko_receiver][ko_pokemon_idx]
-            .as_mut()
+            .as_ref()
             .expect("Pokemon should be there if knocked out");
 
         // Award points
@@ -201,21 +286,32 @@ pub(crate) fn handle_attack_damage(
 
         // Move card (and evolution chain) into discard pile
         let mut cards_to_discard = ko_pokemon.cards_behind.clone();
-        cards_to_discard.push(ko_pokemon.card.clone());
+        cards_to_discard.push(ko_pokemon.card);
+        if let Some(tool_id) = ko_pokemon.attached_tool {
+            cards_to_discard.push(tool_id.card_id().into());
+        }
         debug!("Discarding: {:?}", cards_to_discard);
-        state.discard_piles[ko_receiver].extend(cards_to_discard);
-        state.in_play_pokemon[ko_receiver][ko_pokemon_idx] = None;
+        for card in cards_to_discard {
+            state.push_card(Zone::DiscardPile, ko_receiver, card);
+        }
+        state.set_in_play(ko_receiver, ko_pokemon_idx, None);
+        if ko_pokemon_idx == 0 {
+            state.remove_active_effects(ko_receiver);
+        }
     }
 
     // If game ends because of knockouts, set winner and return so as to short-circuit promotion logic
-    if state.points[attacking_player] >= 3 && state.points[defending_player] >= 3 {
-        debug!("Both players have 3 points, it's a tie");
+    let points_to_win = state.rules.points_to_win;
+    if state.points[attacking_player] >= points_to_win
+        && state.points[defending_player] >= points_to_win
+    {
+        debug!("Both players have {} points, it's a tie", points_to_win);
         state.winner = Some(GameOutcome::Tie);
         return;
-    } else if state.points[attacking_player] >= 3 {
+    } else if state.points[attacking_player] >= points_to_win {
         state.winner = Some(GameOutcome::Win(attacking_player));
         return; // attacking player could lose by attacking into a RockyHelmet e.g.
-    } else if state.points[defending_player] >= 3 {
+    } else if state.points[defending_player] >= points_to_win {
         state.winner = Some(GameOutcome::Win(defending_player));
         return;
     }
@@ -244,9 +340,115 @@ pub(crate) fn handle_attack_damage(
                 "Triggering Activate moves: {:?} to player {}",
                 possible_moves, ko_receiver
             );
-            state
-                .move_generation_stack
-                .push((ko_receiver, possible_moves));
+            state.push_choices(ko_receiver, possible_moves);
+        }
+    }
+
+    // Tool choices (e.g. Electrical Cord moving energy to the Bench) go before the promotion
+    for (player, choices) in tool_choices {
+        state.push_choices(player, choices);
+    }
+}
+
+/// Chance node for drawing a card: one outcome per distinct card in the deck.
+pub(crate) fn forecast_draw(state: &State, player: usize) -> (Probabilities, Mutations) {
+    let mut probabilities = vec![];
+    let mut outcomes: Mutations = vec![];
+    for (probability, cards) in draw_outcomes(&state.decks[player].cards, 1) {
+        probabilities.push(probability);
+        outcomes.push(Box::new(move |_, state, action| {
+            apply_common_mutation(state, action);
+            for &card in &cards {
+                state.draw_card(player, card);
+            }
+        }));
+    }
+    (probabilities, outcomes)
+}
+
+/// Returns the possible outcomes of drawing `count` cards from `cards`: one per distinct set
+/// of cards (sorted by id), with its probability given the number of copies of each card.
+/// The order of `cards` is not used, so that forecasting a draw doesn't peek at the deck.
+pub(crate) fn draw_outcomes(cards: &[CardRef], count: usize) -> Vec<(f64, Vec<CardRef>)> {
+    let mut sorted = cards.to_vec();
+    sorted.sort_by_key(|x| x.get_id());
+    let mut copies: Vec<(CardRef, usize)> = vec![];
+    for card in sorted {
+        match copies.last_mut() {
+            Some((last, n)) if *last == card => *n += 1,
+            _ => copies.push((card, 1)),
+        }
+    }
+
+    let count = count.min(cards.len());
+    let mut outcomes = vec![];
+    collect_draws(&copies, count, 1.0, &mut vec![], &mut outcomes);
+    let total = binomial(cards.len(), count);
+    outcomes
+        .into_iter()
+        .map(|(ways, drawn)| (ways / total, drawn))
+        .collect()
+}
+
+// Enumerates how many copies of each card are drawn, accumulating the number of ways.
+fn collect_draws(
+    copies: &[(CardRef, usize)],
+    to_draw: usize,
+    ways: f64,
+    current: &mut Vec<CardRef>,
+    outcomes: &mut Vec<(f64, Vec<CardRef>)>,
+) {
+    if to_draw == 0 {
+        outcomes.push((ways, current.clone()));
+        return;
+    }
+    let Some(((card, n), rest)) = copies.split_first() else {
+        return;
+    };
+    for taken in 0..=(*n).min(to_draw) {
+        current.extend(std::iter::repeat_n(*card, taken));
+        let ways = ways * binomial(*n, taken);
+        collect_draws(rest, to_draw - taken, ways, current, outcomes);
+        current.truncate(current.len() - taken);
+    }
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Discards energy from `player`'s Pokemon at `in_play_idx` to pay `cost`. If there are
+/// different ways of paying it (e.g. Colorless with mixed energy), the player chooses one
+/// through the move generation stack.
+pub(crate) fn discard_energy_cost(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+    cost: &[EnergyType],
+) {
+    let pokemon = state.in_play_pokemon[player][in_play_idx]
+        .as_ref()
+        .expect("Pokemon should be there if discarding energy from it");
+    let mut options = energy_discard_options(&pokemon.attached_energy, cost);
+    match options.len() {
+        // Can't pay the full cost, so discard what matches
+        0 => cost
+            .iter()
+            .for_each(|energy| state.discard_energy(player, in_play_idx, *energy)),
+        1 => options
+            .pop()
+            .unwrap()
+            .iter()
+            .for_each(|energy| state.discard_energy(player, in_play_idx, *energy)),
+        _ => {
+            let choices = options
+                .into_iter()
+                .map(|energies| SimpleAction::DiscardEnergy {
+                    in_play_idx,
+                    energies,
+                })
+                .collect();
+            state.push_choices(player, choices);
         }
     }
 }
@@ -255,13 +457,274 @@ pub(crate) fn handle_attack_damage(
 // TODO: Is there a way outcome implementations don't have to remember to call this?
 pub(crate) fn apply_common_mutation(state: &mut State, action: &Action) {
     if action.is_stack {
-        state.move_generation_stack.pop();
+        state.pop_choices();
     }
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
-        state.discard_card_from_hand(action.actor, &card);
+        let card = *state.hands[action.actor]
+            .iter()
+            .find(|x| matches!(x.card(), Card::Trainer(x) if x == trainer_card))
+            .expect("Player hand should contain the played trainer");
+        if trainer_card.trainer_card_type == TrainerType::Tool {
+            // Tools stay in play (attached) until they are discarded
+            state.remove_card_from_hand(action.actor, card);
+        } else {
+            state.discard_card_from_hand(action.actor, card);
+        }
         if card.is_support() {
             state.has_played_support = true;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card, rules::RuleSet,
+        tool_ids::ToolId, Deck,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_end_turn_exposes_energy_roll_as_chance_outcomes() {
+        let deck = Deck::from_string(
+            "Energy: Grass\nEnergy: Fire\n2 Bulbasaur A1 001\n2 Charmander A1 033",
+        )
+        .unwrap();
+        let mut state = State::new(&deck, &deck);
+        state.turn_count = 1;
+        state.next_energy[1] = Some(EnergyType::Grass);
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+
+        let (probabilities, mutations) = forecast_end_turn(&state);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+
+        // Each outcome promotes the preview and sets the rolled energy as the new preview.
+        let mut rng = StdRng::seed_from_u64(0);
+        for (mutation, expected_next) in mutations
+            .into_iter()
+            .zip([EnergyType::Grass, EnergyType::Fire])
+        {
+            let mut outcome = state.clone();
+            mutation(&mut rng, &mut outcome, &action);
+            assert_eq!(outcome.get_current_energy(), Some(EnergyType::Grass));
+            assert_eq!(outcome.get_next_energy(1), Some(expected_next));
+        }
+    }
+
+    #[test]
+    fn test_draw_outcomes_follow_deck_composition() {
+        let bulbasaur: CardRef = get_card_by_enum(CardId::A1001Bulbasaur).into();
+        let charmander: CardRef = get_card_by_enum(CardId::A1033Charmander).into();
+        let cards = vec![bulbasaur, charmander, bulbasaur, bulbasaur];
+
+        // Drawing one card gives one outcome per distinct card, weighted by its copies
+        let outcomes = draw_outcomes(&cards, 1);
+        assert_eq!(
+            outcomes,
+            vec![(0.25, vec![charmander]), (0.75, vec![bulbasaur])]
+        );
+
+        // Drawing two: 3 ways of two Bulbasaur and 3 of one of each, out of 6
+        let outcomes = draw_outcomes(&cards, 2);
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes
+            .iter()
+            .all(|(p, drawn)| *p == 0.5 && drawn.len() == 2));
+
+        // Drawing more than there is takes the whole deck
+        let outcomes = draw_outcomes(&cards, 7);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].0, 1.0);
+        assert_eq!(outcomes[0].1.len(), 4);
+    }
+
+    #[test]
+    fn test_draw_forecast_ignores_deck_order() {
+        let deck =
+            Deck::from_string("Energy: Grass\n2 Bulbasaur A1 001\n1 Charmander A1 033").unwrap();
+        let mut state = State::new(&deck, &deck);
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::DrawCard,
+            is_stack: false,
+        };
+
+        let (probabilities, mutations) = forecast_draw(&state, 0);
+        state.decks[0].cards.reverse();
+        let (reversed_probabilities, _) = forecast_draw(&state, 0);
+        assert_eq!(probabilities, reversed_probabilities);
+        assert_eq!(probabilities.iter().sum::<f64>(), 1.0);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for mutation in mutations {
+            let mut outcome = state.clone();
+            mutation(&mut rng, &mut outcome, &action);
+            assert_eq!(outcome.hands[0].len(), 1);
+            assert_eq!(outcome.decks[0].cards.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_checkup_handles_sleep_and_paralysis() {
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut state = State {
+            turn_count: 3,
+            ..Default::default()
+        };
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur);
+        state.get_active_mut(0).asleep = true;
+        state.get_active_mut(1).paralyzed = true;
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+
+        // Player 0 ends its turn: the sleep flip is a chance outcome, and the opponent's
+        // Pokemon (paralyzed during this turn) stays paralyzed through its next turn.
+        let (probabilities, mutations) = forecast_pokemon_checkup(&state);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut asleep_states = vec![];
+        for mutation in mutations {
+            let mut outcome = state.clone();
+            mutation(&mut rng, &mut outcome, &action);
+            assert!(outcome.get_active(1).paralyzed);
+            asleep_states.push(outcome.get_active(0).asleep);
+        }
+        assert_eq!(asleep_states.len(), 2);
+        assert!(asleep_states.contains(&true) && asleep_states.contains(&false));
+
+        // Once the paralyzed Pokemon's owner ends its turn, the paralysis wears off
+        let mut state = state.clone();
+        state.current_player = 1;
+        state.get_active_mut(0).asleep = false;
+        let (_, mutations) = forecast_pokemon_checkup(&state);
+        let mut outcome = state.clone();
+        let action = Action { actor: 1, ..action };
+        mutations.into_iter().next().unwrap()(&mut rng, &mut outcome, &action);
+        assert!(!outcome.get_active(1).paralyzed);
+    }
+
+    #[test]
+    fn test_checkup_handles_burn() {
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut state = State {
+            turn_count: 3,
+            ..Default::default()
+        };
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur);
+        state.get_active_mut(1).burned = true;
+        state.get_active_mut(1).poisoned = true;
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+
+        // Burn (20) and poison (10) damage always happen, then a coin flip may cure the burn
+        let (probabilities, mutations) = forecast_pokemon_checkup(&state);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut burned_states = vec![];
+        for mutation in mutations {
+            let mut outcome = state.clone();
+            mutation(&mut rng, &mut outcome, &action);
+            assert_eq!(outcome.get_remaining_hp(1, 0), 40);
+            assert!(outcome.get_active(1).poisoned);
+            burned_states.push(outcome.get_active(1).burned);
+        }
+        assert!(burned_states.contains(&true) && burned_states.contains(&false));
+    }
+
+    #[test]
+    fn test_tools_react_to_attack_damage_only() {
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        state.get_active_mut(1).attached_tool = Some(ToolId::A2148RockyHelmet);
+
+        // Poison damage (Pokemon Checkup) doesn't trigger Rocky Helmet
+        handle_damage(&mut state, 0, &vec![(10, 0)], false);
+        assert_eq!(state.get_remaining_hp(0, 0), 70);
+
+        // Attacks do
+        handle_attack_damage(&mut state, 0, &vec![(10, 0)]);
+        assert_eq!(state.get_remaining_hp(0, 0), 50);
+        assert_eq!(state.get_remaining_hp(1, 0), 50);
+
+        // Poison Barb poisons the attacker
+        state.get_active_mut(1).attached_tool = Some(ToolId::A3146PoisonBarb);
+        handle_attack_damage(&mut state, 0, &vec![(10, 0)]);
+        assert!(state.get_active(0).poisoned);
+
+        // Knocked Out Pokemon discard their tool
+        handle_attack_damage(&mut state, 0, &vec![(40, 0)]);
+        assert_eq!(
+            state.discard_piles[1],
+            vec![
+                get_card_by_enum(CardId::A1001Bulbasaur),
+                get_card_by_enum(CardId::A3146PoisonBarb)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_abilities_react_to_attack_damage() {
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let cloyster = to_playable_card(get_card_by_enum(CardId::A1067Cloyster), false);
+        let pyukumuku = to_playable_card(get_card_by_enum(CardId::A3054Pyukumuku), false);
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(bulbasaur);
+        state.in_play_pokemon[1][0] = Some(cloyster);
+        state.in_play_pokemon[1][1] = Some(pyukumuku);
+
+        // Shell Armor reduces attack damage, but not Poison damage
+        handle_attack_damage(&mut state, 0, &vec![(30, 0)]);
+        assert_eq!(state.get_remaining_hp(1, 0), 100);
+        handle_damage(&mut state, 0, &vec![(10, 0)], false);
+        assert_eq!(state.get_remaining_hp(1, 0), 90);
+
+        // Innards Out damages the attacker when Knocked Out
+        state.in_play_pokemon[1].swap(0, 1);
+        handle_attack_damage(&mut state, 0, &vec![(60, 0)]);
+        assert_eq!(state.get_remaining_hp(0, 0), 20);
+        assert!(state.in_play_pokemon[1][0].is_none());
+    }
+
+    #[test]
+    fn test_points_to_win_follows_rules() {
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][1] = Some(bulbasaur);
+        state.points = [2, 0];
+
+        // With the standard rules, the third point wins the game
+        let mut standard = state.clone();
+        handle_attack_damage(&mut standard, 0, &vec![(70, 0)]);
+        assert_eq!(standard.winner, Some(GameOutcome::Win(0)));
+
+        // In a 4-point match, the game goes on and the opponent promotes a Bench Pokemon
+        let mut four_points = state.clone();
+        four_points.rules = RuleSet {
+            points_to_win: 4,
+            ..Default::default()
+        };
+        handle_attack_damage(&mut four_points, 0, &vec![(70, 0)]);
+        assert_eq!(four_points.points, [3, 0]);
+        assert_eq!(four_points.winner, None);
+        assert_eq!(four_points.move_generation_stack.len(), 1);
+    }
+}
@@ -3,16 +3,18 @@ use rand::Rng;
 
 use crate::{
     attack_ids::AttackId,
+    effects::{Effect, EffectKind, EffectTarget},
+    error::DeckgymError,
     hooks::get_damage_from_attack,
     types::{EnergyType, StatusCondition},
     State,
 };
 
 use super::{
-    apply_action_helpers::{Mutations, Probabilities},
+    apply_action_helpers::{discard_energy_cost, Mutations, Probabilities},
     mutations::{
         active_damage_doutcome, active_damage_effect_doutcome, active_damage_effect_mutation,
-        active_damage_mutation, build_status_effect, damage_effect_doutcome,
+        active_damage_mutation, build_status_effect, damage_effect_doutcome, damage_mutation,
         index_active_damage_doutcome,
     },
     SimpleAction,
@@ -23,16 +25,24 @@ pub(crate) fn forecast_attack(
     acting_player: usize,
     state: &State,
     index: usize,
-) -> (Probabilities, Mutations) {
+) -> Result<(Probabilities, Mutations), DeckgymError> {
     let active = state.get_active(acting_player);
     let attack = active.card.get_attacks()[index].clone();
     trace!("Forecasting attack: {:?} {:?}", active, attack);
-    if attack.effect.is_none() {
+    let (mut probabilities, mut mutations) = if attack.effect.is_none() {
         let damage = get_damage_from_attack(state, acting_player, index, 0);
         active_damage_doutcome(damage)
     } else {
-        forecast_effect_attack(acting_player, state, index)
+        forecast_effect_attack(acting_player, state, index)?
+    };
+
+    // A Confused Pokemon flips a coin before attacking. If tails, the attack does nothing.
+    if active.confused {
+        probabilities.iter_mut().for_each(|x| *x *= 0.5);
+        probabilities.push(0.5);
+        mutations.push(damage_mutation(vec![]));
     }
+    Ok((probabilities, mutations))
 }
 
 fn celebi_powerful_bloom(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
@@ -81,19 +91,12 @@ fn forecast_effect_attack(
     acting_player: usize,
     state: &State,
     index: usize,
-) -> (Probabilities, Mutations) {
-    let attack_id = {
-        let active = state.get_active(acting_player);
-        AttackId::from_pokemon_index(&active.get_id()[..], index).unwrap_or_else(|| {
-            panic!(
-                "Attack not found for Pokemon: {:?} {:?} {:?}",
-                active.card,
-                active.card.get_attacks(),
-                index
-            )
-        })
+) -> Result<(Probabilities, Mutations), DeckgymError> {
+    let active = state.get_active(acting_player);
+    let Some(attack_id) = AttackId::from_pokemon_index(active.card.get_id_str(), index) else {
+        return unimplemented_attack(acting_player, state, index);
     };
-    match attack_id {
+    let forecast = match attack_id {
         AttackId::A1003VenusaurMegaDrain => self_heal_attack(30, index),
         AttackId::A1004VenusaurExGiantBloom => self_heal_attack(30, index),
         AttackId::A1013VileplumeSoothingScent => damage_status_attack(80, StatusCondition::Asleep),
@@ -108,7 +111,9 @@ fn forecast_effect_attack(
         }
         AttackId::A1029PetililBlot => self_heal_attack(10, index),
         AttackId::A1030LilligantLeafSupply => energy_bench_attack(0, 1, EnergyType::Grass),
-        AttackId::A1031Skiddo => probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 40]),
+        AttackId::A1031SkiddoSurpriseAttack => {
+            probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 40])
+        }
         AttackId::A1033CharmanderEmber => self_energy_discard_attack(0, vec![EnergyType::Fire]),
         AttackId::A1035CharizardFireSpin => {
             self_energy_discard_attack(0, vec![EnergyType::Fire, EnergyType::Fire])
@@ -125,7 +130,7 @@ fn forecast_effect_attack(
         AttackId::A1046MoltresSkyAttack => {
             probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 130])
         }
-        AttackId::A1047MoltresExInfernoDance => moltres_inferno_dance(),
+        AttackId::A1047MoltresExInferno => moltres_inferno_dance(),
         AttackId::A1052CentiskorchFireBlast => {
             self_energy_discard_attack(0, vec![EnergyType::Fire])
         }
@@ -133,14 +138,18 @@ fn forecast_effect_attack(
         AttackId::A1056BlastoiseExHydroBazooka => {
             hydro_pump_attack(acting_player, state, 100, 5, 60)
         }
-        AttackId::A1057PsyduckHeadache => damage_and_turn_effect_attack(0, 1),
+        AttackId::A1057PsyduckHeadache => damage_and_opponent_effect_attack(
+            index,
+            EffectKind::CantPlaySupporter,
+            EffectTarget::Player,
+        ),
         AttackId::A1063TentacruelPoisonTentacles => {
             damage_status_attack(50, StatusCondition::Poisoned)
         }
         AttackId::A1069KinglerKOCrab => {
             probabilistic_damage_attack(vec![0.25, 0.75], vec![160, 80])
         }
-        AttackId::A1071SeadraWaterArrow => direct_damage(50, false),
+        AttackId::A1071SeadraWater => direct_damage(50, false),
         AttackId::A1073SeakingHornHazard => {
             probabilistic_damage_attack(vec![0.5, 0.5], vec![80, 0])
         }
@@ -167,7 +176,7 @@ fn forecast_effect_attack(
             vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
             vec![0, 50, 100, 150, 200],
         ),
-        AttackId::A1106ZebstrikaThunderSpear => direct_damage(30, false),
+        AttackId::A1106ZebstrikaThunder => direct_damage(30, false),
         AttackId::A1109EelektrossThunderFang => {
             damage_chance_status_attack(80, 0.5, StatusCondition::Paralyzed)
         }
@@ -180,12 +189,9 @@ fn forecast_effect_attack(
         AttackId::A1115AbraPsyshock => {
             damage_chance_status_attack(10, 0.5, StatusCondition::Paralyzed)
         }
-        AttackId::A1115AbraTeleport => teleport_attack(),
         AttackId::A1117AlakazamPsychic => {
             damage_based_on_opponent_energy(acting_player, state, 60, 30)
         }
-        AttackId::A1010MewtwoPsychic => damage_based_on_opponent_energy(acting_player, state, 10, 10),
-        AttackId::A1010MewtwoBarrier => self_energy_discard_and_prevent_damage_attack(vec![EnergyType::Psychic]),
         AttackId::A1127JynxPsychic => damage_based_on_opponent_energy(acting_player, state, 30, 20),
         AttackId::A1128MewtwoPowerBlast => {
             self_energy_discard_attack(index, vec![EnergyType::Psychic])
@@ -201,15 +207,15 @@ fn forecast_effect_attack(
         AttackId::A1153MarowakExBonemerang => {
             probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 80, 160])
         }
-        AttackId::A1154HitmonleeStretchKick => direct_damage(30, true),
-        AttackId::A107Jab => active_damage_doutcome(20),
-        AttackId::A107SpecialPunch => active_damage_doutcome(40),
+        AttackId::A1154HitmonleeStretch => direct_damage(30, true),
         AttackId::A1163GrapploctKnockBack => knock_back_attack(60),
-        AttackId::A1165ArbokCorner => damage_and_turn_effect_attack(index, 1),
+        AttackId::A1165ArbokCorner => {
+            damage_and_opponent_effect_attack(index, EffectKind::CantRetreat, EffectTarget::Active)
+        }
         AttackId::A1171NidokingPoisonHorn => damage_status_attack(90, StatusCondition::Poisoned),
         AttackId::A1174GrimerPoisonGas => damage_status_attack(10, StatusCondition::Poisoned),
         AttackId::A1178MawileCrunch => mawile_crunch(),
-        AttackId::A1195WigglytuffSleepySong => damage_status_attack(80, StatusCondition::Asleep),
+        AttackId::A1195WigglytuffExSleepySong => damage_status_attack(80, StatusCondition::Asleep),
         AttackId::A1196MeowthPayDay => draw_and_damage_outcome(10),
         AttackId::A1201LickitungContinuousLick => flip_until_tails_attack(60),
         AttackId::A1203KangaskhanDizzyPunch => {
@@ -233,24 +239,36 @@ fn forecast_effect_attack(
         AttackId::A1a030DedenneThunderShock => {
             damage_chance_status_attack(10, 0.5, StatusCondition::Paralyzed)
         }
-        AttackId::A1a041MankeyFocusFist => probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 50]),
-        AttackId::A1a061EeveeContinuousSteps => flip_until_tails_attack(20),
-        AttackId::A2049PalkiaDimensionalStorm => palkia_dimensional_storm(state),
+        AttackId::A1a041Mankeyocusist => probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 50]),
+        AttackId::A1a061EeveeontinuousSteps => flip_until_tails_attack(20),
+        AttackId::A2049PalkiaExDimensionalStorm => palkia_dimensional_storm(state),
         AttackId::A2119DialgaExMetallicTurbo => energy_bench_attack(index, 2, EnergyType::Metal),
         AttackId::A2a071ArceusExUltimateForce => {
             bench_count_attack(acting_player, state, 70, 20, None)
         }
-        AttackId::A3112AbsolBadNews => absol_bad_news_attack(acting_player, state),
-        AttackId::A3112AbsolPrizeCount => absol_prize_count_attack(acting_player, state),
-        AttackId::A2035PiplupHeal | AttackId::PA034PiplupHeal => self_heal_attack(20, index),
-        AttackId::A3a094JynxPsychic => {
-            damage_based_on_opponent_energy(acting_player, state, 30, 20)
-        }
+        AttackId::A2035PiplupUnknown => self_heal_attack(20, index),
         AttackId::PA072AlolanGrimerPoison => damage_status_attack(0, StatusCondition::Poisoned),
-        AttackId::A1213CinccinoDoTheWave | AttackId::PA031CinccinoDoTheWave => {
-            bench_count_attack(acting_player, state, 0, 30, None)
-        }
+        AttackId::A1213CinccinoDotheWave => bench_count_attack(acting_player, state, 0, 30, None),
+        _ => return unimplemented_attack(acting_player, state, index),
+    };
+    Ok(forecast)
+}
+
+/// Attacks whose effect has no behavior yet only do their fixed damage when partial
+/// implementations are allowed.
+fn unimplemented_attack(
+    acting_player: usize,
+    state: &State,
+    index: usize,
+) -> Result<(Probabilities, Mutations), DeckgymError> {
+    if state.allow_partial {
+        let damage = get_damage_from_attack(state, acting_player, index, 0);
+        return Ok(active_damage_doutcome(damage));
     }
+    Err(DeckgymError::UnsupportedAttack {
+        pokemon: state.get_active(acting_player).get_id(),
+        index,
+    })
 }
 
 fn palkia_dimensional_storm(state: &State) -> (Probabilities, Mutations) {
@@ -262,10 +280,8 @@ fn palkia_dimensional_storm(state: &State) -> (Probabilities, Mutations) {
         .chain(std::iter::once((150, 0))) // Add active Pokémon directly
         .collect();
     damage_effect_doutcome(targets, |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.discard_energy(&EnergyType::Water);
-        active.discard_energy(&EnergyType::Water);
-        active.discard_energy(&EnergyType::Water);
+        let cost = [EnergyType::Water, EnergyType::Water, EnergyType::Water];
+        discard_energy_cost(state, action.actor, 0, &cost);
     })
 }
 
@@ -294,9 +310,7 @@ fn moltres_inferno_dance() -> (Probabilities, Mutations) {
 
                 let all_choices = generate_energy_distributions(&fire_bench_idx, heads);
                 if !all_choices.is_empty() {
-                    state
-                        .move_generation_stack
-                        .push((action.actor, all_choices));
+                    state.push_choices(action.actor, all_choices);
                 }
             })
         })
@@ -383,7 +397,7 @@ fn energy_bench_attack(
         if choices.is_empty() {
             return; // do nothing, since we use common_attack_mutation, turn should end, and no damage applied.
         }
-        state.move_generation_stack.push((action.actor, choices));
+        state.push_choices(action.actor, choices);
     })
 }
 
@@ -397,8 +411,7 @@ fn extra_or_self_damage_attack(
     let mutations: Mutations = vec![
         active_damage_mutation(base_damage + extra_damage),
         active_damage_effect_mutation(base_damage, move |_, state, action| {
-            let active = state.get_active_mut(action.actor);
-            active.apply_damage(self_damage);
+            state.update_pokemon(action.actor, 0, |active| active.apply_damage(self_damage));
         }),
     ];
     (probabilities, mutations)
@@ -451,7 +464,7 @@ fn self_benched_damage(damage: u32, attack_index: usize) -> (Probabilities, Muta
         if choices.is_empty() {
             return;
         }
-        state.move_generation_stack.push((action.actor, choices));
+        state.push_choices(action.actor, choices);
     })
 }
 
@@ -477,7 +490,7 @@ fn direct_damage(damage: u32, bench_only: bool) -> (Probabilities, Mutations) {
         if choices.is_empty() {
             return; // do nothing, since we use common_attack_mutation, turn should end, and no damage applied.
         }
-        state.move_generation_stack.push((action.actor, choices));
+        state.push_choices(action.actor, choices);
     })
 }
 
@@ -487,22 +500,7 @@ fn self_energy_discard_attack(
     to_discard: Vec<EnergyType>,
 ) -> (Probabilities, Mutations) {
     index_active_damage_doutcome(attack_index, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        for energy in to_discard.iter() {
-            active.discard_energy(energy);
-        }
-    })
-}
-
-fn self_energy_discard_and_prevent_damage_attack(
-    to_discard: Vec<EnergyType>,
-) -> (Probabilities, Mutations) {
-    active_damage_effect_doutcome(0, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        for energy in to_discard.iter() {
-            active.discard_energy(energy);
-        }
-        state.move_generation_stack.push((action.actor, vec![SimpleAction::PreventDamage { amount: 0, duration: 1 }]));
+        discard_energy_cost(state, action.actor, 0, &to_discard);
     })
 }
 
@@ -510,17 +508,16 @@ fn self_energy_discard_and_prevent_damage_attack(
 fn damage_and_discard_energy(damage: u32, discard_count: usize) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |rng, state, action| {
         let opponent = (action.actor + 1) % 2;
-        let active = state.get_active_mut(opponent);
 
         for _ in 0..discard_count {
-            if active.attached_energy.is_empty() {
+            let energy_count = state.get_active(opponent).attached_energy.len();
+            if energy_count == 0 {
                 break; // No more energy to discard
             }
 
             // Get a random index to discard
-            let energy_count = active.attached_energy.len();
             let rand_idx = rng.gen_range(0..energy_count);
-            active.attached_energy.remove(rand_idx);
+            state.remove_energy(opponent, 0, rand_idx);
         }
     })
 }
@@ -528,8 +525,7 @@ fn damage_and_discard_energy(damage: u32, discard_count: usize) -> (Probabilitie
 /// For attacks that deal damage to opponent and also damage themselves
 fn self_damage_attack(damage: u32, self_damage: u32) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.apply_damage(self_damage);
+        state.update_pokemon(action.actor, 0, |active| active.apply_damage(self_damage));
     })
 }
 
@@ -541,9 +537,7 @@ fn damage_status_attack(damage: u32, status: StatusCondition) -> (Probabilities,
 /// For cards like "Meowth Pay Day" that draw a card and deal damage.
 fn draw_and_damage_outcome(damage: u32) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |_, state, action| {
-        state
-            .move_generation_stack
-            .push((action.actor, vec![SimpleAction::DrawCard]));
+        state.push_choices(action.actor, vec![SimpleAction::DrawCard]);
     })
 }
 
@@ -613,24 +607,37 @@ fn flip_until_tails_attack(damage_per_heads: u32) -> (Probabilities, Mutations)
 
 fn self_heal_attack(heal: u32, index: usize) -> (Probabilities, Mutations) {
     index_active_damage_doutcome(index, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.heal(heal);
+        state.update_pokemon(action.actor, 0, |active| active.heal(heal));
     })
 }
 
-fn damage_and_turn_effect_attack(index: usize, effect_duration: u8) -> (Probabilities, Mutations) {
+/// For attacks with an effect that lasts "during your opponent's next turn".
+fn damage_and_opponent_effect_attack(
+    index: usize,
+    kind: EffectKind,
+    target: fn(usize) -> EffectTarget,
+) -> (Probabilities, Mutations) {
     index_active_damage_doutcome(index, move |_, state, action| {
-        let active = state.get_active(action.actor);
-        // TODO: Maybe create an EffectId enum and have a mapping between card,attack_idx to effect?
-        state.add_turn_effect(active.card.clone(), effect_duration);
+        let opponent = (action.actor + 1) % 2;
+        let source = state.get_active(action.actor).card.get_card_id();
+        state.add_effect(Effect {
+            kind,
+            source,
+            owner: action.actor,
+            target: target(opponent),
+            expires_after_turn: state.turn_count + 1,
+        });
     })
 }
 
 /// For Raichu's Thunderbolt attack that deals 140 damage and discards all energy
 fn thunderbolt_attack() -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(140, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.attached_energy.clear(); // Discard all energy
+        // Discard all energy
+        let energy_count = state.get_active(action.actor).attached_energy.len();
+        for position in (0..energy_count).rev() {
+            state.remove_energy(action.actor, 0, position);
+        }
     })
 }
 
@@ -661,19 +668,6 @@ fn extra_damage_if_hurt(
     }
 }
 
-fn teleport_attack() -> (Probabilities, Mutations) {
-    active_damage_effect_doutcome(0, move |_, state, action| {
-        let mut choices = Vec::new();
-        for (in_play_idx, _) in state.enumerate_bench_pokemon(action.actor) {
-            choices.push(SimpleAction::Activate { in_play_idx });
-        }
-        if choices.is_empty() {
-            return; // No benched pokemon to switch with
-        }
-        state.move_generation_stack.push((action.actor, choices));
-    })
-}
-
 fn damage_based_on_opponent_energy(
     acting_player: usize,
     state: &State,
@@ -696,7 +690,7 @@ fn knock_back_attack(damage: u32) -> (Probabilities, Mutations) {
         if choices.is_empty() {
             return; // No benched pokemon to knock back
         }
-        state.move_generation_stack.push((opponent, choices));
+        state.push_choices(opponent, choices);
     })
 }
 
@@ -708,48 +702,17 @@ fn mawile_crunch() -> (Probabilities, Mutations) {
         active_damage_effect_mutation(20, move |rng, state, action| {
             // Heads: damage + discard random energy
             let opponent = (action.actor + 1) % 2;
-            let active = state.get_active_mut(opponent);
+            let energy_count = state.get_active(opponent).attached_energy.len();
 
-            if !active.attached_energy.is_empty() {
-                let energy_count = active.attached_energy.len();
+            if energy_count > 0 {
                 let rand_idx = rng.gen_range(0..energy_count);
-                active.attached_energy.remove(rand_idx);
+                state.remove_energy(opponent, 0, rand_idx);
             }
         }),
     ];
     (probabilities, mutations)
 }
 
-fn absol_prize_count_attack(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
-    let mut damage = 20;
-    if state.prize_cards[acting_player].len() > state.prize_cards[opponent].len() {
-        damage += 20;
-    }
-    active_damage_doutcome(damage)
-}
-
-fn absol_bad_news_attack(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
-    let opponent_hand_size = state.hands[opponent].len();
-    if opponent_hand_size >= 6 {
-        let cards_to_discard = opponent_hand_size - 5;
-        active_damage_effect_doutcome(0, move |rng, state, action| {
-            let opponent = (action.actor + 1) % 2;
-            for _ in 0..cards_to_discard {
-                if state.hands[opponent].is_empty() {
-                    break;
-                }
-                let rand_idx = rng.gen_range(0..state.hands[opponent].len());
-                let card = state.hands[opponent].remove(rand_idx);
-                state.discard_piles[opponent].push(card);
-            }
-        })
-    } else {
-        active_damage_doutcome(0)
-    }
-}
-
 #[cfg(test)]
 mod test {
     use rand::{rngs::StdRng, SeedableRng};
@@ -963,4 +926,34 @@ mod test {
         assert!((probabilities[0] - 0.5).abs() < 0.001);
         assert!((probabilities[1] - 0.5).abs() < 0.001);
     }
+
+    #[test]
+    fn test_confused_attack_can_fail() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+        let attacker = get_card_by_enum(CardId::A1001Bulbasaur); // Vine Whip: 40 damage
+        let mut confused_attacker = to_playable_card(&attacker, false);
+        confused_attacker.confused = true;
+        state.in_play_pokemon[0][0] = Some(confused_attacker);
+
+        let (probabilities, mutations) = forecast_attack(0, &state, 0).unwrap();
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        let remaining_hps: Vec<u32> = mutations
+            .into_iter()
+            .map(|mutation| {
+                let mut outcome = state.clone();
+                mutation(&mut rng, &mut outcome, &action);
+                assert_eq!(outcome.move_generation_stack.len(), 1); // Turn still ends
+                outcome.get_active(1).remaining_hp
+            })
+            .collect();
+        assert_eq!(remaining_hps, vec![120, 160]);
+    }
 }
@@ -3,14 +3,16 @@ use rand::rngs::StdRng;
 
 use crate::{
     card_ids::CardId,
-    state::GameOutcome,
+    effects::{Effect, EffectKind, EffectTarget},
+    error::DeckgymError,
+    state::{GameOutcome, Zone},
     tool_ids::ToolId,
-    types::{Card, EnergyType, TrainerCard},
+    types::{CardRef, EnergyType, TrainerCard},
     State,
 };
 
 use super::{
-    apply_action_helpers::{apply_common_mutation, Mutations, Probabilities},
+    apply_action_helpers::{apply_common_mutation, draw_outcomes, Mutations, Probabilities},
     Action, SimpleAction,
 };
 
@@ -19,26 +21,27 @@ pub fn forecast_trainer_action(
     acting_player: usize,
     state: &State,
     trainer_card: &TrainerCard,
-) -> (Probabilities, Mutations) {
-    let trainer_id =
-        CardId::from_numeric_id(trainer_card.numeric_id).expect("CardId should be known");
-    match trainer_id {
+) -> Result<(Probabilities, Mutations), DeckgymError> {
+    let trainer_id = CardId::from_numeric_id(trainer_card.numeric_id)
+        .ok_or_else(|| DeckgymError::UnsupportedTrainer(trainer_card.id.clone()))?;
+    let forecast = match trainer_id {
         CardId::PA001Potion => deterministic(potion_effect),
-        CardId::PA002XSpeed => deterministic(turn_effect),
+        CardId::PA002XSpeed => deterministic(x_speed_effect),
         CardId::PA005PokeBall => pokeball_outcomes(acting_player, state),
-        CardId::PA006RedCard => deterministic(red_card_effect),
-        CardId::PA007ProfessorsResearch => deterministic(professor_oak_effect),
+        CardId::PA006RedCard => red_card_outcomes(acting_player, state),
+        CardId::PA007ProfessorsResearch => professors_research_outcomes(acting_player, state),
         CardId::A1219Erika | CardId::A1266Erika => deterministic(erika_effect),
         CardId::A1220Misty | CardId::A1267Misty => misty_outcomes(),
         CardId::A1222Koga | CardId::A1269Koga => deterministic(koga_effect),
         CardId::A1223Giovanni | CardId::A1270Giovanni => deterministic(giovanni_effect),
         CardId::A1225Sabrina | CardId::A1272Sabrina => deterministic(sabrina_effect),
         CardId::A1a065MythicalSlab => deterministic(mythical_slab_effect),
-        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic(turn_effect),
+        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic(leaf_effect),
         CardId::A2150Cyrus | CardId::A2190Cyrus => deterministic(cyrus_effect),
-        CardId::A2147GiantCape => deterministic(attach_tool),
-        _ => panic!("Unsupported Trainer Card"),
-    }
+        _ if ToolId::from_trainer_card(trainer_card).is_some() => deterministic(attach_tool),
+        _ => return Err(DeckgymError::UnsupportedTrainer(trainer_card.id.clone())),
+    };
+    Ok(forecast)
 }
 
 fn deterministic(mutation: fn(&mut StdRng, &mut State, &Action)) -> (Probabilities, Mutations) {
@@ -76,9 +79,7 @@ fn inner_healing_effect(
         })
         .collect::<Vec<_>>();
     if !possible_moves.is_empty() {
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
+        state.push_choices(action.actor, possible_moves);
     }
 }
 
@@ -104,9 +105,7 @@ fn misty_outcomes() -> (Probabilities, Mutations) {
                     })
                     .collect::<Vec<_>>();
                 if !possible_moves.is_empty() {
-                    state
-                        .move_generation_stack
-                        .push((action.actor, possible_moves));
+                    state.push_choices(action.actor, possible_moves);
                 }
             }
         }));
@@ -115,64 +114,109 @@ fn misty_outcomes() -> (Probabilities, Mutations) {
 }
 
 fn pokeball_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
-    let num_basic_in_deck = state.decks[acting_player]
+    let basics_in_deck: Vec<CardRef> = state.decks[acting_player]
         .cards
         .iter()
         .filter(|x| x.is_basic())
-        .count();
-    if num_basic_in_deck == 0 {
+        .copied()
+        .collect();
+    if basics_in_deck.is_empty() {
         deterministic({
             |rng, state, action| {
                 // If there are no basic Pokemon in the deck, just shuffle it
-                state.decks[action.actor].shuffle(false, rng);
+                state.shuffle_deck(action.actor, false, rng);
             }
         })
     } else {
-        let probabilities = vec![1.0 / (num_basic_in_deck as f64); num_basic_in_deck];
+        // Put 1 random Basic Pokemon from your deck into your hand (one outcome per distinct card).
+        let mut probabilities = vec![];
         let mut outcomes: Mutations = vec![];
-        for i in 0..num_basic_in_deck {
-            outcomes.push(Box::new({
-                move |rng, state, action| {
-                    apply_common_mutation(state, action);
-
-                    let card = state.decks[action.actor]
-                        .cards
-                        .iter()
-                        .filter(|x| x.is_basic())
-                        .nth(i)
-                        .cloned()
-                        .expect("Should be a basic card");
-
-                    // Put 1 random Basic Pokemon from your deck into your hand.
-                    let deck = &mut state.decks[action.actor];
-                    // Select a random one
+        for (probability, cards) in draw_outcomes(&basics_in_deck, 1) {
+            probabilities.push(probability);
+            outcomes.push(Box::new(move |rng, state, action| {
+                apply_common_mutation(state, action);
+                for &card in &cards {
                     debug!("Pokeball selected card: {:?}", card);
-                    // Add it to hand and remove one of it from deck
-                    state.hands[action.actor].push(card.clone());
-                    if let Some(pos) = deck.cards.iter().position(|x| x == &card) {
-                        deck.cards.remove(pos);
-                    } else {
-                        panic!("Card should be in deck");
-                    }
-
-                    deck.shuffle(false, rng);
+                    // Add it to hand (revealing it) and remove one of it from deck
+                    state.draw_card(action.actor, card);
+                    state.reveal_card_in_hand(action.actor, card);
                 }
+                state.shuffle_deck(action.actor, false, rng);
             }));
         }
         (probabilities, outcomes)
     }
 }
 
-// Remember to implement these in the main controller / hooks.
-fn turn_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+fn professors_research_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
+    // Draw 2 cards.
+    let mut probabilities = vec![];
+    let mut outcomes: Mutations = vec![];
+    for (probability, cards) in draw_outcomes(&state.decks[acting_player].cards, 2) {
+        probabilities.push(probability);
+        outcomes.push(Box::new(move |_, state, action| {
+            apply_common_mutation(state, action);
+            for &card in &cards {
+                state.draw_card(action.actor, card);
+            }
+        }));
+    }
+    (probabilities, outcomes)
+}
+
+fn red_card_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
+    // Your opponent shuffles their hand into their deck and draws 3 cards.
+    let opponent = (acting_player + 1) % 2;
+    let mut opponent_cards = state.decks[opponent].cards.clone();
+    opponent_cards.extend(state.hands[opponent].iter().copied());
+    let mut probabilities = vec![];
+    let mut outcomes: Mutations = vec![];
+    for (probability, cards) in draw_outcomes(&opponent_cards, 3) {
+        probabilities.push(probability);
+        outcomes.push(Box::new(move |rng, state, action| {
+            apply_common_mutation(state, action);
+            let opponent = (action.actor + 1) % 2;
+            let mut opponent_deck = state.decks[opponent].cards.clone();
+            opponent_deck.extend(state.hands[opponent].iter().copied());
+            state.set_cards(Zone::Hand, opponent, vec![]);
+            state.set_cards(Zone::Deck, opponent, opponent_deck);
+            state.shuffle_deck(opponent, false, rng);
+            state.forget_revealed_not_in_hand(opponent);
+            for &card in &cards {
+                state.draw_card(opponent, card);
+            }
+        }));
+    }
+    (probabilities, outcomes)
+}
+
+// Adds an effect on the acting player that lasts for the current turn. Remember to
+// handle its EffectKind in the hooks.
+fn add_this_turn_effect(state: &mut State, action: &Action, kind: EffectKind) {
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
-        state.add_turn_effect(card, 0);
+        state.add_effect(Effect {
+            kind,
+            source: CardId::from_numeric_id(trainer_card.numeric_id)
+                .expect("Played trainer should have a CardId"),
+            owner: action.actor,
+            target: EffectTarget::Player(action.actor),
+            expires_after_turn: state.turn_count,
+        });
     } else {
         panic!("Something went wrong. An action was played but couldnt get the card");
     }
 }
 
+fn x_speed_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // During this turn, the Retreat Cost of your Active Pokémon is 1 less.
+    add_this_turn_effect(state, action, EffectKind::RetreatCostReduction(1));
+}
+
+fn leaf_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // During this turn, the Retreat Cost of your Active Pokémon is 2 less.
+    add_this_turn_effect(state, action, EffectKind::RetreatCostReduction(2));
+}
+
 fn sabrina_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Switch out your opponent's Active Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)
     let opponent_player = (action.actor + 1) % 2;
@@ -180,9 +224,7 @@ fn sabrina_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         .enumerate_bench_pokemon(opponent_player)
         .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
         .collect::<Vec<_>>();
-    state
-        .move_generation_stack
-        .push((opponent_player, possible_moves));
+    state.push_choices(opponent_player, possible_moves);
 }
 
 fn cyrus_effect(_: &mut StdRng, state: &mut State, action: &Action) {
@@ -193,19 +235,12 @@ fn cyrus_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         .filter(|(_, x)| x.is_damaged())
         .map(|(in_play_idx, _)| SimpleAction::Activate { in_play_idx })
         .collect::<Vec<_>>();
-    state
-        .move_generation_stack
-        .push((opponent_player, possible_moves));
+    state.push_choices(opponent_player, possible_moves);
 }
 
 fn giovanni_effect(_: &mut StdRng, state: &mut State, action: &Action) {
-    if let SimpleAction::Play { trainer_card } = &action.action {
-        // During this turn, attacks used by your Pokémon do +10 damage to your opponent's Active Pokémon.
-        let card = Card::Trainer(trainer_card.clone());
-        state.add_turn_effect(card, 0);
-    } else {
-        panic!("XSpeed should be played");
-    }
+    // During this turn, attacks used by your Pokémon do +10 damage to your opponent's Active Pokémon.
+    add_this_turn_effect(state, action, EffectKind::AttackDamageBonus(10));
 }
 
 fn koga_effect(_: &mut StdRng, state: &mut State, action: &Action) {
@@ -214,10 +249,15 @@ fn koga_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         .as_ref()
         .expect("Active Pokemon should be there if Koga is played");
     let mut cards_to_collect = active_pokemon.cards_behind.clone();
-    cards_to_collect.push(active_pokemon.card.clone());
-    state.hands[action.actor].extend(cards_to_collect);
+    cards_to_collect.push(active_pokemon.card);
+    for &card in &cards_to_collect {
+        state.reveal_card_in_hand(action.actor, card);
+    }
+    for card in cards_to_collect {
+        state.push_card(Zone::Hand, action.actor, card);
+    }
     // Energy dissapears
-    state.in_play_pokemon[action.actor][0] = None;
+    state.set_in_play(action.actor, 0, None);
 
     // if no bench pokemon, finish game as a loss
     let bench_pokemon = state.enumerate_bench_pokemon(action.actor).count();
@@ -230,18 +270,7 @@ fn koga_effect(_: &mut StdRng, state: &mut State, action: &Action) {
             .enumerate_bench_pokemon(action.actor)
             .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
             .collect::<Vec<_>>();
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
-    }
-}
-
-// TODO: Problem. With doing 1.0, we are basically giving bots the ability to see the cards in deck.
-// TODO: In theory this should give a probability distribution over cards in deck.
-fn professor_oak_effect(_: &mut StdRng, state: &mut State, action: &Action) {
-    // Draw 2 cards.
-    for _ in 0..2 {
-        state.maybe_draw_card(action.actor);
+        state.push_choices(action.actor, possible_moves);
     }
 }
 
@@ -249,36 +278,22 @@ fn professor_oak_effect(_: &mut StdRng, state: &mut State, action: &Action) {
 // of pulling the different psychic left in deck vs pushing an item to the bottom.
 fn mythical_slab_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Look at the top card of your deck. If that card is a Psychic Pokemon,\n        put it in your hand. If it is not a Psychic Pokemon, put it on the\n        bottom of your deck.
-    if let Some(card) = state.decks[action.actor].cards.first() {
+    if let Some(card) = state.decks[action.actor].cards.first().copied() {
         if card.is_basic() {
-            state.hands[action.actor].push(card.clone());
-            state.decks[action.actor].cards.remove(0);
+            state.reveal_card_in_hand(action.actor, card);
+            state.push_card(Zone::Hand, action.actor, card);
+            state.remove_card(Zone::Deck, action.actor, 0);
         } else {
-            let card = state.decks[action.actor].cards.remove(0);
-            state.decks[action.actor].cards.push(card);
+            let card = state.remove_card(Zone::Deck, action.actor, 0);
+            state.push_card(Zone::Deck, action.actor, card);
         }
     } // else do nothing
 }
 
-// Here we will simplify the output possibilities, counting with the fact that value functions
-// should not use the cards of the enemy as input.
-fn red_card_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
-    // Your opponent shuffles their hand into their deck and draws 3 cards.
-    let acting_player = action.actor;
-    let opponent = (acting_player + 1) % 2;
-    let opponent_hand = &mut state.hands[opponent];
-    let opponent_deck = &mut state.decks[opponent];
-    opponent_deck.cards.append(opponent_hand);
-    opponent_deck.shuffle(false, rng);
-    for _ in 0..3 {
-        state.maybe_draw_card(opponent);
-    }
-}
-
 // Give the choice to the player to attach a tool to one of their pokemon.
 fn attach_tool(_: &mut StdRng, state: &mut State, action: &Action) {
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let &tool_id = ToolId::from_trainer_card(trainer_card).expect("ToolId should exist");
+        let tool_id = ToolId::from_trainer_card(trainer_card).expect("ToolId should exist");
         let choices = state
             .enumerate_in_play_pokemon(action.actor)
             .filter(|(_, x)| !x.has_tool_attached())
@@ -287,7 +302,7 @@ fn attach_tool(_: &mut StdRng, state: &mut State, action: &Action) {
                 tool_id,
             })
             .collect::<Vec<_>>();
-        state.move_generation_stack.push((action.actor, choices));
+        state.push_choices(action.actor, choices);
     } else {
         panic!("Tool should have been played");
     }
@@ -5,8 +5,15 @@ mod apply_attack_action;
 mod apply_trainer_action;
 mod mutations;
 mod types;
+mod undo;
 
 pub(crate) use apply_action::apply_action;
 pub(crate) use apply_action::forecast_action;
+pub use apply_action::try_apply_action;
+pub(crate) use apply_attack_action::forecast_attack;
+pub(crate) use apply_trainer_action::forecast_trainer_action;
 pub use types::Action;
 pub use types::SimpleAction;
+pub(crate) use undo::apply_mutation_with_undo;
+pub use undo::{apply_action_with_undo, try_apply_action_with_undo, undo, UndoRecord};
+pub(crate) use undo::{Change, PokemonFields, Trail};
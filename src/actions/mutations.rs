@@ -1,7 +1,11 @@
 use log::trace;
 use rand::rngs::StdRng;
 
-use crate::{card_ids::CardId, hooks::get_damage_from_attack, types::StatusCondition, State};
+use crate::{
+    hooks::{can_be_affected_by_status, get_damage_from_attack},
+    types::StatusCondition,
+    State,
+};
 
 use super::{
     apply_action_helpers::{
@@ -47,9 +51,7 @@ where
         vec![1.0],
         vec![Box::new(move |rng, state, action| {
             apply_common_mutation(state, action);
-            state
-                .move_generation_stack
-                .push((action.actor, vec![SimpleAction::EndTurn]));
+            state.push_choices(action.actor, vec![SimpleAction::EndTurn]);
             additional_effect(rng, state, action);
 
             let damage = get_damage_from_attack(state, action.actor, attack_index, 0);
@@ -97,9 +99,7 @@ where
     Box::new({
         move |rng, state, action| {
             apply_common_mutation(state, action);
-            state
-                .move_generation_stack
-                .push((action.actor, vec![SimpleAction::EndTurn]));
+            state.push_choices(action.actor, vec![SimpleAction::EndTurn]);
             additional_effect(rng, state, action);
             handle_attack_damage(state, action.actor, &targets);
         }
@@ -111,27 +111,34 @@ pub(crate) fn build_status_effect(status: StatusCondition) -> FnMutation {
     Box::new({
         move |_, state: &mut State, action: &Action| {
             let opponent = (action.actor + 1) % 2;
-            let opponent_active = state.get_active_mut(opponent);
-
-            // Arceus Ex avoids status effects
-            let string_id = opponent_active.get_id();
-            let arceus_ids = [
-                CardId::A2a071ArceusEx,
-                CardId::A2a086ArceusEx,
-                CardId::A2a095ArceusEx,
-                CardId::A2a096ArceusEx,
-            ];
-            let card_id = CardId::from_card_id(&string_id).unwrap();
-            if arceus_ids.contains(&card_id) {
-                trace!("Arceus Ex avoids status effect");
+            let opponent_active = state.get_active(opponent);
+
+            // Some abilities avoid status effects (e.g. Arceus ex)
+            if !can_be_affected_by_status(opponent_active) {
+                trace!("{} avoids status effect", opponent_active.get_name());
                 return;
             }
 
-            match status {
-                StatusCondition::Asleep => opponent_active.asleep = true,
-                StatusCondition::Paralyzed => opponent_active.paralyzed = true,
+            state.update_pokemon(opponent, 0, |opponent_active| match status {
+                // Asleep, Paralyzed and Confused replace each other
+                StatusCondition::Asleep => {
+                    opponent_active.asleep = true;
+                    opponent_active.paralyzed = false;
+                    opponent_active.confused = false;
+                }
+                StatusCondition::Paralyzed => {
+                    opponent_active.paralyzed = true;
+                    opponent_active.asleep = false;
+                    opponent_active.confused = false;
+                }
+                StatusCondition::Confused => {
+                    opponent_active.confused = true;
+                    opponent_active.asleep = false;
+                    opponent_active.paralyzed = false;
+                }
+                StatusCondition::Burned => opponent_active.burned = true,
                 StatusCondition::Poisoned => opponent_active.poisoned = true,
-            }
+            });
         }
     })
 }
@@ -160,6 +167,26 @@ mod test {
         assert!(state.get_active(1).asleep);
     }
 
+    #[test]
+    fn test_confusion_replaces_sleep_but_not_burn() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+        let bulbasuar = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasuar, false));
+        build_status_effect(StatusCondition::Asleep)(&mut rng, &mut state, &action);
+        build_status_effect(StatusCondition::Burned)(&mut rng, &mut state, &action);
+        build_status_effect(StatusCondition::Confused)(&mut rng, &mut state, &action);
+
+        let active = state.get_active(1);
+        assert!(active.confused && active.burned);
+        assert!(!active.asleep);
+    }
+
     #[test]
     fn test_arceus_avoids_status() {
         let mut rng = StdRng::seed_from_u64(0);
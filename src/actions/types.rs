@@ -1,19 +1,21 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
     tool_ids::ToolId,
-    types::{Card, EnergyType, TrainerCard},
+    types::{CardRef, EnergyType, TrainerCard},
 };
 use std::fmt;
 
 /// Main structure for following Game Tree design. Using "nesting" with a
 /// SimpleAction to share common fields here.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Action {
     pub actor: usize,
     pub action: SimpleAction,
     pub is_stack: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SimpleAction {
     DrawCard,
     Play {
@@ -22,8 +24,8 @@ pub enum SimpleAction {
 
     // Card because of the fossil Trainer Cards...
     // usize is bench 1-based index, with 0 meaning Active pokemon, 1..4 meaning Bench
-    Place(Card, usize),
-    Evolve(Card, usize),
+    Place(CardRef, usize),
+    Evolve(CardRef, usize),
     UseAbility(usize),
 
     // Its given it is with the active pokemon, to the other active.
@@ -56,6 +58,13 @@ pub enum SimpleAction {
         amount: u32,
         duration: u32,
     },
+    // Pays a discard cost (e.g. retreating) with the chosen energies
+    DiscardEnergy {
+        in_play_idx: usize,
+        energies: Vec<EnergyType>,
+    },
+    // Declines an optional effect (e.g. an ability that says "you may")
+    Noop,
 }
 
 impl fmt::Display for SimpleAction {
@@ -102,6 +111,11 @@ impl fmt::Display for SimpleAction {
             }
             SimpleAction::Activate { in_play_idx } => write!(f, "Activate({in_play_idx})"),
             SimpleAction::PreventDamage { amount, duration } => write!(f, "PreventDamage({amount}, {duration})"),
+            SimpleAction::DiscardEnergy {
+                in_play_idx,
+                energies,
+            } => write!(f, "DiscardEnergy({in_play_idx}, {energies:?})"),
+            SimpleAction::Noop => write!(f, "Noop"),
         }
     }
 }
@@ -0,0 +1,263 @@
+use std::hash::{Hash, Hasher};
+
+use rand::rngs::StdRng;
+
+use crate::{
+    effects::Effect,
+    error::DeckgymError,
+    state::{GameOutcome, Zone},
+    tool_ids::ToolId,
+    types::{CardRef, EnergyType, PlayedCard},
+    zobrist::ZobristHashes,
+    State,
+};
+
+use super::{apply_action::try_apply_action, apply_action_helpers::Mutation, Action, SimpleAction};
+
+/// What `undo` needs to restore a `State` to how it was before an action: the turn fields,
+/// and how many changes the state had recorded (see `Trail`) when the action started.
+#[derive(Debug, Clone, Copy)]
+pub struct UndoRecord {
+    trail_len: usize,
+    winner: Option<GameOutcome>,
+    points: [u8; 2],
+    turn_count: u8,
+    current_player: usize,
+    current_energy: Option<EnergyType>,
+    next_energy: [Option<EnergyType>; 2],
+    has_played_support: bool,
+    has_retreated: bool,
+    hashes: ZobristHashes,
+}
+
+/// Like `apply_action`, but returns what is needed to `undo` it. Lets tree search walk
+/// deep lines on a single state instead of cloning it for every node.
+pub fn apply_action_with_undo(rng: &mut StdRng, state: &mut State, action: &Action) -> UndoRecord {
+    try_apply_action_with_undo(rng, state, action).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Like `try_apply_action`, but returns what is needed to `undo` it.
+pub fn try_apply_action_with_undo(
+    rng: &mut StdRng,
+    state: &mut State,
+    action: &Action,
+) -> Result<UndoRecord, DeckgymError> {
+    let (record, result) = recorded(state, |state| try_apply_action(rng, state, action));
+    result.map(|_| record)
+}
+
+/// Like `apply_action_with_undo`, but applies one of the outcomes of `forecast_action`.
+pub(crate) fn apply_mutation_with_undo(
+    rng: &mut StdRng,
+    state: &mut State,
+    action: &Action,
+    mutation: Mutation,
+) -> UndoRecord {
+    recorded(state, |state| mutation(rng, state, action)).0
+}
+
+// Applies `apply` to the state, recording its changes.
+fn recorded<T>(state: &mut State, apply: impl FnOnce(&mut State) -> T) -> (UndoRecord, T) {
+    let record = UndoRecord {
+        trail_len: state.trail.changes.len(),
+        winner: state.winner,
+        points: state.points,
+        turn_count: state.turn_count,
+        current_player: state.current_player,
+        current_energy: state.current_energy,
+        next_energy: state.next_energy,
+        has_played_support: state.has_played_support,
+        has_retreated: state.has_retreated,
+        hashes: state.hashes,
+    };
+    let was_recording = state.trail.recording;
+    state.trail.recording = true;
+    let result = apply(state);
+    state.trail.recording = was_recording;
+    (record, result)
+}
+
+/// Restores the state as it was before the action of `record`. Actions have to be undone
+/// in the reverse order they were applied in.
+pub fn undo(state: &mut State, record: UndoRecord) {
+    assert!(
+        state.trail.changes.len() >= record.trail_len,
+        "Actions should be undone in reverse order"
+    );
+    while state.trail.changes.len() > record.trail_len {
+        let change = state.trail.changes.pop().unwrap();
+        change.revert(state);
+    }
+    state.winner = record.winner;
+    state.points = record.points;
+    state.turn_count = record.turn_count;
+    state.current_player = record.current_player;
+    state.current_energy = record.current_energy;
+    state.next_energy = record.next_energy;
+    state.has_played_support = record.has_played_support;
+    state.has_retreated = record.has_retreated;
+    state.hashes = record.hashes;
+}
+
+/// The changes made to a state by the actions applied with `apply_action_with_undo` (and
+/// not undone yet), in order. The mutation methods of `State` record them. Not part of the
+/// state: clones start with an empty trail, and states compare (and `Hash`) equal
+/// regardless of it.
+#[derive(Debug, Default)]
+pub(crate) struct Trail {
+    changes: Vec<Change>,
+    recording: bool,
+}
+impl Trail {
+    pub(crate) fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    pub(crate) fn record(&mut self, change: Change) {
+        if self.recording {
+            self.changes.push(change);
+        }
+    }
+}
+impl Clone for Trail {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+impl PartialEq for Trail {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Trail {}
+impl Hash for Trail {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// A change to a state, with what is needed to revert it.
+#[derive(Debug)]
+pub(crate) enum Change {
+    // Zone and player
+    CardPushed(Zone, usize),
+    // Zone, player, index and card
+    CardRemoved(Zone, usize, usize, CardRef),
+    CardSwapRemoved(Zone, usize, usize, CardRef),
+    // Zone, player and the cards that were there
+    CardsReplaced(Zone, usize, Vec<CardRef>),
+    // Player, slot and the Pokémon that was there
+    InPlaySet(usize, usize, Option<PlayedCard>),
+    InPlaySwapped(usize, usize, usize),
+    PokemonUpdated(usize, usize, PokemonFields),
+    // Player, slot and amount
+    EnergyAttached(usize, usize, u32),
+    // Player, slot, position and energy
+    EnergyRemoved(usize, usize, usize, EnergyType),
+    EnergySwapRemoved(usize, usize, usize, EnergyType),
+    ChoicesPushed,
+    ChoicesPopped((usize, Vec<SimpleAction>)),
+    EffectAdded,
+    EffectRemoved(usize, Effect),
+}
+
+impl Change {
+    fn revert(self, state: &mut State) {
+        fn pokemon(state: &mut State, player: usize, index: usize) -> &mut PlayedCard {
+            state.in_play_pokemon[player][index]
+                .as_mut()
+                .expect("Pokemon should be there when undoing a change to it")
+        }
+        match self {
+            Change::CardPushed(zone, player) => {
+                state.cards_mut(zone, player).pop();
+            }
+            Change::CardRemoved(zone, player, index, card) => {
+                state.cards_mut(zone, player).insert(index, card);
+            }
+            Change::CardSwapRemoved(zone, player, index, card) => {
+                let cards = state.cards_mut(zone, player);
+                cards.push(card);
+                let last = cards.len() - 1;
+                cards.swap(index, last);
+            }
+            Change::CardsReplaced(zone, player, cards) => {
+                *state.cards_mut(zone, player) = cards;
+            }
+            Change::InPlaySet(player, index, old) => state.in_play_pokemon[player][index] = old,
+            Change::InPlaySwapped(player, a, b) => state.in_play_pokemon[player].swap(a, b),
+            Change::PokemonUpdated(player, index, fields) => {
+                fields.restore(pokemon(state, player, index));
+            }
+            Change::EnergyAttached(player, index, amount) => {
+                let energy = &mut pokemon(state, player, index).attached_energy;
+                energy.truncate(energy.len() - amount as usize);
+            }
+            Change::EnergyRemoved(player, index, position, energy) => {
+                pokemon(state, player, index)
+                    .attached_energy
+                    .insert(position, energy);
+            }
+            Change::EnergySwapRemoved(player, index, position, energy) => {
+                let attached = &mut pokemon(state, player, index).attached_energy;
+                attached.push(energy);
+                let last = attached.len() - 1;
+                attached.swap(position, last);
+            }
+            Change::ChoicesPushed => {
+                state.move_generation_stack.pop();
+            }
+            Change::ChoicesPopped(entry) => state.move_generation_stack.push(entry),
+            Change::EffectAdded => {
+                state.effects.pop();
+            }
+            Change::EffectRemoved(index, effect) => state.effects.insert(index, effect),
+        }
+    }
+}
+
+/// The fields of a Pokémon in play that `State::update_pokemon` can change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PokemonFields {
+    remaining_hp: u32,
+    total_hp: u32,
+    attached_tool: Option<ToolId>,
+    played_this_turn: bool,
+    ability_used: bool,
+    poisoned: bool,
+    paralyzed: bool,
+    asleep: bool,
+    burned: bool,
+    confused: bool,
+    damage_prevention: Option<(u32, u32)>,
+}
+
+impl PokemonFields {
+    pub(crate) fn of(pokemon: &PlayedCard) -> Self {
+        Self {
+            remaining_hp: pokemon.remaining_hp,
+            total_hp: pokemon.total_hp,
+            attached_tool: pokemon.attached_tool,
+            played_this_turn: pokemon.played_this_turn,
+            ability_used: pokemon.ability_used,
+            poisoned: pokemon.poisoned,
+            paralyzed: pokemon.paralyzed,
+            asleep: pokemon.asleep,
+            burned: pokemon.burned,
+            confused: pokemon.confused,
+            damage_prevention: pokemon.damage_prevention,
+        }
+    }
+
+    fn restore(self, pokemon: &mut PlayedCard) {
+        pokemon.remaining_hp = self.remaining_hp;
+        pokemon.total_hp = self.total_hp;
+        pokemon.attached_tool = self.attached_tool;
+        pokemon.played_this_turn = self.played_this_turn;
+        pokemon.ability_used = self.ability_used;
+        pokemon.poisoned = self.poisoned;
+        pokemon.paralyzed = self.paralyzed;
+        pokemon.asleep = self.asleep;
+        pokemon.burned = self.burned;
+        pokemon.confused = self.confused;
+        pokemon.damage_prevention = self.damage_prevention;
+    }
+}
@@ -1,10 +1,12 @@
-// TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
-use std::collections::HashMap;
+// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.
 
+/// Attacks with an effect. Reprints share the variant of the first card with the attack,
+/// named like its `CardId` followed by the attack title.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AttackId {
     A1003VenusaurMegaDrain,
     A1004VenusaurExGiantBloom,
+    A1005CaterpieFinda,
     A1013VileplumeSoothingScent,
     A1017VenomothPoisonPowder,
     A1022ExeggutorStomp,
@@ -13,29 +15,32 @@ pub enum AttackId {
     A1026PinsirDoubleHorn,
     A1029PetililBlot,
     A1030LilligantLeafSupply,
-    A1031Skiddo,
+    A1031SkiddoSurpriseAttack,
     A1033CharmanderEmber,
     A1035CharizardFireSpin,
     A1036CharizardExCrimsonStorm,
+    A1037VulpixTail,
     A1038NinetalesFlamethrower,
     A1040ArcanineHeatTackle,
     A1041ArcanineExInfernoOnrush,
     A1045FlareonFlamethrower,
     A1046MoltresSkyAttack,
-    A1047MoltresExInfernoDance,
+    A1047MoltresExInferno,
     A1052CentiskorchFireBlast,
     A1055BlastoiseHydroPump,
     A1056BlastoiseExHydroBazooka,
     A1057PsyduckHeadache,
     A1063TentacruelPoisonTentacles,
     A1069KinglerKOCrab,
-    A1071SeadraWaterArrow,
+    A1071SeadraWater,
     A1073SeakingHornHazard,
     A1078GyaradosHyperBeam,
     A1079LaprasHydroPump,
     A1080VaporeonBubbleDrain,
+    A1082OmastarAncientWhirlpool,
     A1083ArticunoIceBeam,
     A1084ArticunoExBlizzard,
+    A3112AbsolUnseenClaw,
     A1091BruxishSecondStrike,
     A1093FrosmothPowderSnow,
     A1095RaichuThunderbolt,
@@ -44,205 +49,1033 @@ pub enum AttackId {
     A1102JolteonPinMissile,
     A1103ZapdosRagingThunder,
     A1104ZapdosExThunderingHurricane,
-    A1106ZebstrikaThunderSpear,
+    A1106ZebstrikaThunder,
     A1109EelektrossThunderFang,
     A1111HelioliskQuickAttack,
     A1112PincurchinThunderShock,
     A1115AbraPsyshock,
-    A1115AbraTeleport,
     A1117AlakazamPsychic,
+    A1122GengarBother,
+    A1126MrMimeBarrierAttack,
     A1127JynxPsychic,
-    A1010MewtwoPsychic,
-    A1010MewtwoBarrier,
     A1128MewtwoPowerBlast,
     A1129MewtwoExPsydrive,
     A1136GolurkDoubleLariat,
+    A1140DugtrioDig,
     A1142PrimeapeFightBack,
     A1149GolemDoubleEdge,
+    A1151CuboneUnknown,
     A1153MarowakExBonemerang,
-    A1154HitmonleeStretchKick,
+    A1154HitmonleeStretch,
+    A1159KabutopsLeechLife,
     A1163GrapploctKnockBack,
-    A107Jab,
-    A107SpecialPunch,
     A1165ArbokCorner,
+    A1166NidoranFCallfor,
+    A1168NidoqueenLovestrike,
     A1171NidokingPoisonHorn,
     A1174GrimerPoisonGas,
+    A1175MukVenoshock,
     A1178MawileCrunch,
-    A1195WigglytuffSleepySong,
+    A1181MeltanUnknown,
+    A1185DragoniteDraco,
+    A1192FearowDrillRun,
+    A1195WigglytuffExSleepySong,
     A1196MeowthPayDay,
+    A1197PersianShadowClaw,
     A1201LickitungContinuousLick,
     A1203KangaskhanDizzyPunch,
-    A1213CinccinoDoTheWave,
+    A1205Dittoopy,
+    A1210AerodactylPrimal,
+    A1213CinccinoDotheWave,
+    A1283MewsyReport,
+    A1a001ExeggcuteGrowth,
+    A1a002ExeggutorPsychic,
     A1a003CelebiExPowerfulBloom,
+    A1a008ShiinoticFlickeringSpores,
+    A1a009DhelmiseEnergyWhip,
     A1a010PonytaStomp,
     A1a011RapidashRisingLunge,
-    A1a026RaichuGigashock,
+    A1a012MagmarFireBlast,
+    A1a014VolcaronaVolcanic,
+    A1a015SalanditVenoshock,
+    A1a016SalazzlePoisonClaws,
+    A1a017MagikarpLeap,
+    A1a018GyaradosExRampagingWhirlpool,
     A1a021LumineonAqua,
+    A1a023DrednawCrunch,
+    A1a024CramorantDive,
+    A1a025PikachuCircleCircuit,
+    A1a026RaichuGigashock,
+    A1a027ElectabuzzThunder,
+    A1a029GalvantulaElectroweb,
     A1a030DedenneThunderShock,
-    A1a041MankeyFocusFist,
-    A1a061EeveeContinuousSteps,
-    A2035PiplupHeal,
-    A2049PalkiaDimensionalStorm,
+    A1a032MewExGenome,
+    A1a033SigilyphSpikeDraw,
+    A1a035BeheeyemMindJack,
+    A1a036FlabebeHypnotic,
+    A1a037FloetteLeafDrain,
+    A1a038FlorgesBloomshine,
+    A1a041Mankeyocusist,
+    A1a045GolemGuardPress,
+    A1a047MarshadowRevenge,
+    A1a049KoffingUnknown,
+    A1a050WeezingSmokescreen,
+    A1a054WhirlipedePoisonSting,
+    A1a055ScolipedeVenoshock,
+    A1a059PidgeotExScatteringCyclone,
+    A1a060TaurosFightingTackle,
+    A1a061EeveeontinuousSteps,
+    A1a062ChatotUnknown,
+    A2001OddishBlot,
+    A2005TangrowthMegaDrain,
+    A2007YanmegaExAirSlash,
+    A2009RoseradePoisonousWhip,
+    A2012TorterraFrenzyPlant,
+    A2014KricketuneEntrancingMelody,
+    A2016WormadamLeafCutter,
+    A2017Combeeallfor,
+    A2019CarnivineFlog,
+    A2020LeafeonLeafyCyclone,
+    A2021MowRotomEnergyCutoff,
+    A2023MagmarUnknown,
+    A2024MagmortarBurstingInferno,
+    A2026MagcargoSearingFlame,
+    A2029InfernapeExFlareBlitz,
+    A2030HeatRotomHeatBreath,
+    A2035PiplupUnknown,
+    A2037EmpoleonAquaJet,
+    A2039FloatzelJetScrew,
+    A2041GastrodonMuddyWater,
+    A2042FinneonElegantSwim,
+    A2044SnoverIceShard,
+    A2046GlaceonIceBeam,
+    A2048FrostRotomBlizzard,
+    A2049PalkiaExDimensionalStorm,
+    A2050ManaphyOceanic,
+    A2053MagnezoneThunderBlast,
+    A2054VoltorbBigExplosion,
+    A2056ElectabuzzUnknown,
+    A2057ElectivireExcitingVoltage,
+    A2058ShinxUnknown,
+    A2060LuxrayVolt,
+    A2061PachirisuExSparkingGadget,
+    A2062RotomAssaultLaser,
+    A2065TogekissOverdriveSmash,
+    A2067MismagiusExMagicalDelusion,
+    A2068RaltsUnknown,
+    A2073DrifloonExpand,
+    A2075UxieMindBoost,
+    A2076MespritSupremeBlast,
+    A2077Azelfsychic,
+    A2079CresseliaMoonlightGain,
+    A2082RhyperiorMountainSwing,
+    A2084GliscorAcrobatics,
+    A2089RampardosHeadSmash,
+    A2095GalladeExEnergizedBlade,
+    A2097HonchkrowSkill,
+    A2098SneaseloubleScratch,
+    A2099WeavileExScratchingNails,
+    A2103SkuntankPoisonGas,
+    A2104SpiritombSwirling,
+    A2106DrapionCrossPoison,
+    A2107CroagunkGroupBeatdown,
+    A2108ToxicroakGroupBeatdown,
+    A2109DarkraiDarkVoid,
+    A2111SkarmoryetalArms,
+    A2115WormadamIronHead,
+    A2117BronzongGuardPress,
+    A2118ProbopassTripleNose,
     A2119DialgaExMetallicTurbo,
+    A2120HeatranSteelTackle,
+    A2125LickilickyExLickingFury,
+    A2126EeveeQuickAttack,
+    A2129PorygonZBuggyBeam,
+    A2131AmbipomDoubleHit,
+    A2132StarlyPluck,
+    A2134StaraptorClutch,
+    A2135BidoofSuper,
+    A2138LopunnyJumpKick,
+    A2139GlameowPose,
+    A2140PuruglyInterrupt,
+    A2141ChatotFuryAttack,
+    A2142FanRotomSpin,
+    A2143RegigigasRagingHammer,
+    A2a001HeracrossSingleHornThrow,
+    A2a005VespiquenRecklessCharge,
+    A2a007CherrimWorrySeed,
+    A2a008CherrimLeechSeed,
+    A2a012HoundoomCorner,
+    A2a013HeatranRaginMadStrike,
+    A2a017WhiscashThrash,
+    A2a019FroslassBlizzard,
+    A2a023OriginFormePalkiaZoneSmash,
+    A2a024PhioneWaterPulse,
+    A2a025PikachuSpark,
+    A2a026RaichuSpark,
+    A2a028ManectricFlash,
+    A2a030ClefableMoonblast,
+    A2a031GastlyUnknown,
+    A2a033GengarHypnoblast,
+    A2a035RotomDash,
+    A2a036SudowoodoightingHeadbutt,
+    A2a038DonphanRollingSpin,
+    A2a044MedichamKickShot,
+    A2a047GarchompExLinear,
+    A2a052ToxicroakUnknown,
+    A2a056MawileIronBeamBreaker,
+    A2a057ProbopassExDefensiveUnit,
+    A2a059BronzongPsychic,
+    A2a060OriginFormeDialgaTimeMash,
+    A2a061GiratinaCrisisDive,
+    A2a063SnorlaxCollapse,
+    A2a065NoctowlSilentWing,
+    A2a068StaraptorBraveBird,
+    A2a070ArceusPowerBlast,
     A2a071ArceusExUltimateForce,
-    A3112AbsolBadNews,
-    A3112AbsolPrizeCount,
-    A3a094JynxPsychic,
-    PA031CinccinoDoTheWave,
-    PA034PiplupHeal,
+    A2b001WeedleUnknown,
+    A2b002KakunaStringShot,
+    A2b003BeedrillExCrushingSpear,
+    A2b004PinsirGuillotineRush,
+    A2b005SprigatitoCryfor,
+    A2b007MeowscaradaFightingClaws,
+    A2b010CharizardExUnknown,
+    A2b012MagmortarSmokeBomb,
+    A2b013PaldeanTaurosBlazeTackle,
+    A2b017Floatzelater,
+    A2b018WiglettSpring,
+    A2b019WugtrioExPopOut,
+    A2b020DondozoOceanCyclone,
+    A2b022PikachuExThunderbolt,
+    A2b024ElectrodeTumblingAttack,
+    A2b025PachirisuPlasma,
+    A2b031AlakazamPsychicSuppression,
+    A2b032MrMimeJuggling,
+    A2b035GiratinaExChaoticImpact,
+    A2b036GimmighoulUnknown,
+    A2b038MachokePummel,
+    A2b039MachampPowerPress,
+    A2b043LucarioExAuraSphere,
+    A2b044FlamigoDoubleKick,
+    A2b046ArbokVenomousFang,
+    A2b047PaldeanWooperPoisonJab,
+    A2b048PaldeanClodsireExVenoshock,
+    A2b049SpiritombConfuseRay,
+    A2b053TinkatuffTenaciousHammer,
+    A2b054TinkatonExTerrificThumping,
+    A2b056RevavroomSpinningDrift,
+    A2b057GholdengoScintillatingSurfing,
+    A2b065BibarelExCarefreePress,
+    A2b068CyclizarOveracceleration,
+    A3002AlolanExeggutorTropicalHammer,
+    A3003SurskitQuickAttack,
+    A3010RowletSkill,
+    A3012DecidueyeExPiercethe,
+    A3015LurantisPetal,
+    A3017ShiinoticFlickeringLight,
+    A3019SteeneeDoubleSpin,
+    A3020TsareenaThreeKickCombo,
+    A3022GolisopodFirstImpression,
+    A3023DhelmiseExAnchorShot,
+    A3024TapuBuluStuckInTackle,
+    A3027AlolanMarowakBurningBonemerang,
+    A3030LittenHeatTackle,
+    A3032TorracatHeatTackle,
+    A3033IncineroarExFireFang,
+    A3033IncineroarExScarChargedSmash,
+    A3034OricorioKindle,
+    A3036SalazzleHeatedPoison,
+    A3037TurtonatorFireSpin,
+    A3039AlolanSandslashSpikeArmor,
+    A3040AlolanVulpixCallForth,
+    A3041AlolanNinetalesBlizzard,
+    A3043CloysterGuardPress,
+    A3045PopplioUnknown,
+    A3049CrabominableExInsatiableStriking,
+    A3050WishiwashiCallfor,
+    A3051WishiwashiExSchoolStorm,
+    A3053AraquanidDangerousClaws,
+    A3056TapuFiniSpiralDrain,
+    A3058AlolanRaichuExPsychic,
+    A3061AlolanGolemSuperZapCannon,
+    A3065VikavoltDisconnect,
+    A3067TogedemaruElectrosmash,
+    A3068TapuKokoVoltSwitch,
+    A3069MrMimeBarrierShove,
+    A3070SableyeCorner,
+    A3071SpoinkUnknown,
+    A3075BanetteNightBind,
+    A3076OricorioDazzleDance,
+    A3077OricorioSpitefulDance,
+    A3083MimikyuShadowHit,
+    A3084TapuLeleEnergy,
+    A3085CosmogUnknown,
+    A3086CosmoemUnknown,
+    A3088NecrozmaPrismaticLaser,
+    A3091HariyamaPushOut,
+    A3098RockruffSignsof,
+    A3100LycanrocCrunch,
+    A3101LycanrocBloodFang,
+    A3103MudsdaleHighHorsepower,
+    A3105MiniorStar,
+    A3107AlolanRaticateScroungeandScarf,
+    A3111AlolanMukExChemicalPanic,
+    A3114GarbodorSuperPoisonBreath,
+    A3116ToxapExSpikeCannon,
+    A3118AlolanDugtrioIronHead,
+    A3120EscavalierPike,
+    A3121KlefkiUnlock,
+    A3122SolgaleoExSolBreaker,
+    A3123MagearnaSilverCannon,
+    A3124DrampaBerserk,
+    A3127KommooClangingScales,
+    A3128TaurosRisingLunge,
+    A3130DelcattyEnergyAssist,
+    A3132HawluchaJustifiedPress,
+    A3139BewearTantrum,
+    A3140OranguruPrimatesTrap,
+    A3a003RowletFuryAttack,
+    A3a004DartrixSkill,
+    A3a005DecidueyeSniping,
+    A3a006BuzzwoleExBigBeat,
+    A3a007PheromosaJumpBlues,
+    A3a009BlacephalonBeatPunk,
+    A3a012SharpedoCrunch,
+    A3a019TapuKokoExPlasmaHurricane,
+    A3a020XurkitreeElectronica,
+    A3a028MedititeTripOver,
+    A3a033LycanrocExLycanfang,
+    A3a034PassimianLetsThrow,
+    A3a036PalossandSandTomb,
+    A3a037AlolanMeowthUnknown,
+    A3a038AlolanPersianFakeOut,
+    A3a041KrookodilePoachingFangs,
+    A3a042NihilegoNewWave,
+    A3a043GuzzlordExGrindcore,
+    A3a044Poipole2Step,
+    A3a045NaganadelElectroHouse,
+    A3a046AlolanDiglettHappenedtoHeadbutt,
+    A3a047AlolanDugtrioExTripletHeadbutt,
+    A3a050AggronGigaImpact,
+    A3a053StakatakaBrassRock,
+    A3a058BewearSuperpowered,
+    A3a060TypeNullQuickBlow,
+    A3a061SilvallyBraveBuddies,
+    A3a062CelesteelaMoombahton,
+    A3b002LeafeonLeafBlast,
+    A3b005TsareenaKickDown,
+    A3b007AppletunSweetsRelay,
+    A3b008FlareonAssistingHeater,
+    A3b009FlareonExFireSpin,
+    A3b010TorkoalFlamethrower,
+    A3b013IncineroarDarkestLariat,
+    A3b014SalanditEmber,
+    A3b016VaporeonHyperWhirlpool,
+    A3b017GlaceonIce,
+    A3b018VanilliteSweetsRelay,
+    A3b019VanillishSweetsRelay,
+    A3b020VanilluxeDoubleSpin,
+    A3b021AlomomolaWaterPulse,
+    A3b024PrimarinaExHydroPump,
+    A3b024PrimarinaExSparklingAria,
+    A3b025JolteonBeginningBolt,
+    A3b026JoltikJolt,
+    A3b027GalvantulaElectricShock,
+    A3b028EspeonEnergyCrush,
+    A3b031SwirlixSweetsRelay,
+    A3b032SlurpuffSweetsRelay,
+    A3b033SylveonEvoharmony,
+    A3b035MimikyuTryto,
+    A3b036MilcerySweetsRelay,
+    A3b037AlcremieSweetsOverload,
+    A3b039WhiscashEarthquake,
+    A3b040MienfooKickShot,
+    A3b042CarbinkHardRoll,
+    A3b043UmbreonarkBinding,
+    A3b045PurrloinPlayful,
+    A3b047MawilePowerfulVise,
+    A3b048TogedemaruBristlingSpikes,
+    A3b053DragoniteExGigaImpact,
+    A3b054DrampaDragonBreath,
+    A3b055EeveeUnknown,
+    A3b057SnorlaxExFlopDownPunch,
+    A3b058AipomDoubleHit,
+    A3b060ChatotToneDeaf,
+    A3b061AudinoDotheWave,
+    A3b065GreedentEnhancedFang,
+    PA014LaprasExBubbleDrain,
+    PA017MankeyRecklessCharge,
+    PA020HaunterSurpriseAttack,
+    PA022JigglypuffUnknown,
+    PA030EeveeUnknown,
+    PA038MisdreavusConfuse,
+    PA045NosepassIron,
+    PA053FloatzelAttacktheound,
+    PA056EkansPoison,
+    PA063RayquazaSpiralRush,
+    PA064RayquazaExDraco,
     PA072AlolanGrimerPoison,
-}
-
-// Create a static HashMap for fast (pokemon, index) lookup
-lazy_static::lazy_static! {
-    static ref ATTACK_ID_MAP: HashMap<(&'static str, usize), AttackId> = {
-        let mut m = HashMap::new();
-        m.insert(("A1 003", 0), AttackId::A1003VenusaurMegaDrain);
-        m.insert(("A1 004", 1), AttackId::A1004VenusaurExGiantBloom);
-        m.insert(("A1 013", 0), AttackId::A1013VileplumeSoothingScent);
-        m.insert(("A1 017", 0), AttackId::A1017VenomothPoisonPowder);
-        m.insert(("A1 022", 0), AttackId::A1022ExeggutorStomp);
-        m.insert(("A1 023", 0), AttackId::A1023ExeggutorExTropicalSwing);
-        m.insert(("A1 024", 0), AttackId::A1024TangelaAbsorb);
-        m.insert(("A1 026", 0), AttackId::A1026PinsirDoubleHorn);
-        m.insert(("A1 029", 0), AttackId::A1029PetililBlot);
-        m.insert(("A1 030", 0), AttackId::A1030LilligantLeafSupply);
-        m.insert(("A1 031", 0), AttackId::A1031Skiddo);
-        m.insert(("A1 033", 0), AttackId::A1033CharmanderEmber);
-        m.insert(("A1 035", 0), AttackId::A1035CharizardFireSpin);
-        m.insert(("A1 036", 1), AttackId::A1036CharizardExCrimsonStorm);
-        m.insert(("A1 038", 0), AttackId::A1038NinetalesFlamethrower);
-        m.insert(("A1 040", 0), AttackId::A1040ArcanineHeatTackle);
-        m.insert(("A1 041", 0), AttackId::A1041ArcanineExInfernoOnrush);
-        m.insert(("A1 045", 0), AttackId::A1045FlareonFlamethrower);
-        m.insert(("A1 046", 0), AttackId::A1046MoltresSkyAttack);
-        m.insert(("A1 047", 0), AttackId::A1047MoltresExInfernoDance);
-        m.insert(("A1 052", 0), AttackId::A1052CentiskorchFireBlast);
-        m.insert(("A1 055", 0), AttackId::A1055BlastoiseHydroPump);
-        m.insert(("A1 056", 1), AttackId::A1056BlastoiseExHydroBazooka);
-        m.insert(("A1 057", 0), AttackId::A1057PsyduckHeadache);
-        m.insert(("A1 063", 0), AttackId::A1063TentacruelPoisonTentacles);
-        m.insert(("A1 069", 0), AttackId::A1069KinglerKOCrab);
-        m.insert(("A1 071", 0), AttackId::A1071SeadraWaterArrow);
-        m.insert(("A1 073", 0), AttackId::A1073SeakingHornHazard);
-        m.insert(("A1 078", 0), AttackId::A1078GyaradosHyperBeam);
-        m.insert(("A1 233", 0), AttackId::A1078GyaradosHyperBeam); // Full art version
-        m.insert(("A1 079", 0), AttackId::A1079LaprasHydroPump);
-        m.insert(("A1 234", 0), AttackId::A1079LaprasHydroPump); // Full art version
-        m.insert(("A1 080", 0), AttackId::A1080VaporeonBubbleDrain);
-        m.insert(("A1 083", 0), AttackId::A1083ArticunoIceBeam);
-        m.insert(("A1 084", 1), AttackId::A1084ArticunoExBlizzard);
-        m.insert(("A1 091", 0), AttackId::A1091BruxishSecondStrike);
-        m.insert(("A1 093", 0), AttackId::A1093FrosmothPowderSnow);
-        m.insert(("A1 095", 0), AttackId::A1095RaichuThunderbolt);
-        m.insert(("A1 096", 0), AttackId::A1096PikachuExCircleCircuit);
-        m.insert(("A1 101", 0), AttackId::A1101ElectabuzzThunderPunch);
-        m.insert(("A1 102", 0), AttackId::A1102JolteonPinMissile);
-        m.insert(("A1 103", 0), AttackId::A1103ZapdosRagingThunder);
-        m.insert(("A1 104", 1), AttackId::A1104ZapdosExThunderingHurricane);
-        m.insert(("A1 106", 0), AttackId::A1106ZebstrikaThunderSpear);
-        m.insert(("A1 109", 0), AttackId::A1109EelektrossThunderFang);
-        m.insert(("A1 111", 0), AttackId::A1111HelioliskQuickAttack);
-        m.insert(("A1 112", 0), AttackId::A1112PincurchinThunderShock);
-        m.insert(("A1 115", 0), AttackId::A1115AbraPsyshock);
-        m.insert(("A1 115", 0), AttackId::A1115AbraTeleport);
-        m.insert(("A1 117", 0), AttackId::A1117AlakazamPsychic);
-        m.insert(("A1 127", 0), AttackId::A1127JynxPsychic);
-        m.insert(("base1-10", 0), AttackId::A1010MewtwoPsychic);
-        m.insert(("base1-10", 1), AttackId::A1010MewtwoBarrier);
-        m.insert(("A1 128", 0), AttackId::A1128MewtwoPowerBlast);
-        m.insert(("A1 129", 1), AttackId::A1129MewtwoExPsydrive);
-        m.insert(("A1 136", 0), AttackId::A1136GolurkDoubleLariat);
-        m.insert(("A1 142", 0), AttackId::A1142PrimeapeFightBack);
-        m.insert(("A1 149", 0), AttackId::A1149GolemDoubleEdge);
-        m.insert(("A1 153", 0), AttackId::A1153MarowakExBonemerang);
-        m.insert(("A1 154", 0), AttackId::A1154HitmonleeStretchKick);
-        m.insert(("A1 163", 0), AttackId::A1163GrapploctKnockBack);
-        m.insert(("A1 165", 0), AttackId::A1165ArbokCorner);
-        m.insert(("base1-7", 0), AttackId::A107Jab);
-        m.insert(("base1-7", 1), AttackId::A107SpecialPunch);
-        m.insert(("A1 171", 0), AttackId::A1171NidokingPoisonHorn);
-        m.insert(("A1 174", 0), AttackId::A1174GrimerPoisonGas);
-        m.insert(("A1 178", 0), AttackId::A1178MawileCrunch);
-        m.insert(("A1 195", 0), AttackId::A1195WigglytuffSleepySong);
-        m.insert(("A1 196", 0), AttackId::A1196MeowthPayDay);
-        m.insert(("A1 201", 0), AttackId::A1201LickitungContinuousLick);
-        m.insert(("A1 203", 0), AttackId::A1203KangaskhanDizzyPunch);
-        m.insert(("A1 213", 0), AttackId::A1213CinccinoDoTheWave);
-        // Full Arts A1
-        m.insert(("A1 229", 0), AttackId::A1026PinsirDoubleHorn);
-        m.insert(("A1 230", 0), AttackId::A1033CharmanderEmber);
-        m.insert(("A1 241", 0), AttackId::A1171NidokingPoisonHorn);
-        m.insert(("A1 246", 0), AttackId::A1196MeowthPayDay);
-        m.insert(("A1 251", 1), AttackId::A1004VenusaurExGiantBloom);
-        m.insert(("A1 252", 0), AttackId::A1023ExeggutorExTropicalSwing);
-        m.insert(("A1 253", 1), AttackId::A1036CharizardExCrimsonStorm);
-        m.insert(("A1 254", 0), AttackId::A1041ArcanineExInfernoOnrush);
-        m.insert(("A1 255", 0), AttackId::A1047MoltresExInfernoDance);
-        m.insert(("A1 256", 1), AttackId::A1056BlastoiseExHydroBazooka);
-        m.insert(("A1 259", 0), AttackId::A1096PikachuExCircleCircuit);
-        m.insert(("A1 260", 1), AttackId::A1104ZapdosExThunderingHurricane);
-        m.insert(("A1 262", 1), AttackId::A1129MewtwoExPsydrive);
-        m.insert(("A1 264", 0), AttackId::A1153MarowakExBonemerang);
-        m.insert(("A1 265", 0), AttackId::A1195WigglytuffSleepySong);
-        m.insert(("A1 274", 0), AttackId::A1047MoltresExInfernoDance);
-        m.insert(("A1 276", 1), AttackId::A1104ZapdosExThunderingHurricane);
-        m.insert(("A1 279", 0), AttackId::A1195WigglytuffSleepySong);
-        m.insert(("A1 280", 1), AttackId::A1036CharizardExCrimsonStorm);
-        m.insert(("A1 281", 0), AttackId::A1096PikachuExCircleCircuit);
-        m.insert(("A1 282", 1), AttackId::A1129MewtwoExPsydrive);
-        m.insert(("A1 284", 1), AttackId::A1036CharizardExCrimsonStorm);
-        m.insert(("A1 285", 0), AttackId::A1096PikachuExCircleCircuit);
-        m.insert(("A1 286", 1), AttackId::A1129MewtwoExPsydrive);
-        // A1a
-        m.insert(("A1a 003", 0), AttackId::A1a003CelebiExPowerfulBloom);
-        m.insert(("A1a 010", 0), AttackId::A1a010PonytaStomp);
-        m.insert(("A1a 011", 0), AttackId::A1a011RapidashRisingLunge);
-        m.insert(("A1a 021", 0), AttackId::A1a021LumineonAqua);
-        m.insert(("A1a 026", 0), AttackId::A1a026RaichuGigashock);
-        m.insert(("A1a 030", 0), AttackId::A1a030DedenneThunderShock);
-        m.insert(("A1a 041", 0), AttackId::A1a041MankeyFocusFist);
-        m.insert(("A1a 061", 0), AttackId::A1a061EeveeContinuousSteps);
-        // Full Arts A1a
-        m.insert(("A1a 073", 0), AttackId::A1a030DedenneThunderShock);
-        m.insert(("A1a 075", 0), AttackId::A1a003CelebiExPowerfulBloom);
-        m.insert(("A1a 085", 0), AttackId::A1a003CelebiExPowerfulBloom);
-
-        // A2
-        m.insert(("A2 035", 0), AttackId::A2035PiplupHeal);
-        m.insert(("A2 049", 1), AttackId::A2049PalkiaDimensionalStorm);
-        m.insert(("A2 182", 1), AttackId::A2049PalkiaDimensionalStorm);
-        m.insert(("A2 204", 1), AttackId::A2049PalkiaDimensionalStorm);
-        m.insert(("A2 206", 1), AttackId::A2049PalkiaDimensionalStorm);
-        m.insert(("A2 119", 0), AttackId::A2119DialgaExMetallicTurbo);
-        m.insert(("A2 188", 0), AttackId::A2119DialgaExMetallicTurbo);
-        m.insert(("A2 205", 0), AttackId::A2119DialgaExMetallicTurbo);
-        m.insert(("A2 207", 0), AttackId::A2119DialgaExMetallicTurbo);
-
-        // A2a
-        m.insert(("A2a 071", 0), AttackId::A2a071ArceusExUltimateForce);
-        m.insert(("A2a 086", 0), AttackId::A2a071ArceusExUltimateForce);
-        m.insert(("A2a 095", 0), AttackId::A2a071ArceusExUltimateForce);
-        m.insert(("A2a 096", 0), AttackId::A2a071ArceusExUltimateForce);
-
-        // A3a
-        m.insert(("A3a 094", 0), AttackId::A3a094JynxPsychic);
-        m.insert(("A3 112", 0), AttackId::A3112AbsolBadNews);
-        m.insert(("A3 112", 1), AttackId::A3112AbsolPrizeCount);
-
-        // Promo
-        m.insert(("P-A 012", 0), AttackId::A1196MeowthPayDay);
-        m.insert(("P-A 031", 0), AttackId::PA031CinccinoDoTheWave);
-        m.insert(("P-A 034", 0), AttackId::PA034PiplupHeal);
-        m.insert(("P-A 072", 0), AttackId::PA072AlolanGrimerPoison);
-
-        m
-    };
+    PA073ToucannonBeakBlast,
 }
 
 impl AttackId {
-    // None if not found or implemented
+    /// None if the attack has no effect (it just does its fixed damage) or is not found.
     pub fn from_pokemon_index(pokemon_id: &str, index: usize) -> Option<Self> {
-        ATTACK_ID_MAP.get(&(pokemon_id, index)).copied()
+        match (pokemon_id, index) {
+            ("A1 003", 0) => Some(AttackId::A1003VenusaurMegaDrain),
+            ("A3 212", 0) => Some(AttackId::A1003VenusaurMegaDrain),
+            ("P-A 018", 0) => Some(AttackId::A1003VenusaurMegaDrain),
+            ("A1 004", 1) => Some(AttackId::A1004VenusaurExGiantBloom),
+            ("A1 251", 1) => Some(AttackId::A1004VenusaurExGiantBloom),
+            ("A3 230", 1) => Some(AttackId::A1004VenusaurExGiantBloom),
+            ("A1 005", 0) => Some(AttackId::A1005CaterpieFinda),
+            ("A1 013", 0) => Some(AttackId::A1013VileplumeSoothingScent),
+            ("A1 017", 0) => Some(AttackId::A1017VenomothPoisonPowder),
+            ("A1 022", 0) => Some(AttackId::A1022ExeggutorStomp),
+            ("A1 023", 0) => Some(AttackId::A1023ExeggutorExTropicalSwing),
+            ("A1 252", 0) => Some(AttackId::A1023ExeggutorExTropicalSwing),
+            ("A3 231", 0) => Some(AttackId::A1023ExeggutorExTropicalSwing),
+            ("A1 024", 0) => Some(AttackId::A1024TangelaAbsorb),
+            ("A1 026", 0) => Some(AttackId::A1026PinsirDoubleHorn),
+            ("A1 229", 0) => Some(AttackId::A1026PinsirDoubleHorn),
+            ("A1 029", 0) => Some(AttackId::A1029PetililBlot),
+            ("A1 030", 0) => Some(AttackId::A1030LilligantLeafSupply),
+            ("A1 031", 0) => Some(AttackId::A1031SkiddoSurpriseAttack),
+            ("A1 033", 0) => Some(AttackId::A1033CharmanderEmber),
+            ("A1 230", 0) => Some(AttackId::A1033CharmanderEmber),
+            ("P-A 032", 0) => Some(AttackId::A1033CharmanderEmber),
+            ("A1 035", 0) => Some(AttackId::A1035CharizardFireSpin),
+            ("A1 036", 1) => Some(AttackId::A1036CharizardExCrimsonStorm),
+            ("A1 253", 1) => Some(AttackId::A1036CharizardExCrimsonStorm),
+            ("A1 280", 1) => Some(AttackId::A1036CharizardExCrimsonStorm),
+            ("A1 284", 1) => Some(AttackId::A1036CharizardExCrimsonStorm),
+            ("A1 037", 0) => Some(AttackId::A1037VulpixTail),
+            ("A1 038", 0) => Some(AttackId::A1038NinetalesFlamethrower),
+            ("A1 040", 0) => Some(AttackId::A1040ArcanineHeatTackle),
+            ("A3a 090", 0) => Some(AttackId::A1040ArcanineHeatTackle),
+            ("A1 041", 0) => Some(AttackId::A1041ArcanineExInfernoOnrush),
+            ("A1 254", 0) => Some(AttackId::A1041ArcanineExInfernoOnrush),
+            ("A3a 100", 0) => Some(AttackId::A1041ArcanineExInfernoOnrush),
+            ("A1 045", 0) => Some(AttackId::A1045FlareonFlamethrower),
+            ("A1 046", 0) => Some(AttackId::A1046MoltresSkyAttack),
+            ("A1 047", 0) => Some(AttackId::A1047MoltresExInferno),
+            ("A1 255", 0) => Some(AttackId::A1047MoltresExInferno),
+            ("A1 274", 0) => Some(AttackId::A1047MoltresExInferno),
+            ("A3b 103", 0) => Some(AttackId::A1047MoltresExInferno),
+            ("P-A 025", 0) => Some(AttackId::A1047MoltresExInferno),
+            ("A1 052", 0) => Some(AttackId::A1052CentiskorchFireBlast),
+            ("A1 055", 0) => Some(AttackId::A1055BlastoiseHydroPump),
+            ("A3 217", 0) => Some(AttackId::A1055BlastoiseHydroPump),
+            ("P-A 029", 0) => Some(AttackId::A1055BlastoiseHydroPump),
+            ("A1 056", 1) => Some(AttackId::A1056BlastoiseExHydroBazooka),
+            ("A1 256", 1) => Some(AttackId::A1056BlastoiseExHydroBazooka),
+            ("A3 232", 1) => Some(AttackId::A1056BlastoiseExHydroBazooka),
+            ("A1 057", 0) => Some(AttackId::A1057PsyduckHeadache),
+            ("A1 063", 0) => Some(AttackId::A1063TentacruelPoisonTentacles),
+            ("A1 069", 0) => Some(AttackId::A1069KinglerKOCrab),
+            ("A1 071", 0) => Some(AttackId::A1071SeadraWater),
+            ("A1 073", 0) => Some(AttackId::A1073SeakingHornHazard),
+            ("A1 078", 0) => Some(AttackId::A1078GyaradosHyperBeam),
+            ("A1 233", 0) => Some(AttackId::A1078GyaradosHyperBeam),
+            ("A1 079", 0) => Some(AttackId::A1079LaprasHydroPump),
+            ("A1 234", 0) => Some(AttackId::A1079LaprasHydroPump),
+            ("A3b 094", 0) => Some(AttackId::A1079LaprasHydroPump),
+            ("A1 080", 0) => Some(AttackId::A1080VaporeonBubbleDrain),
+            ("A1 082", 0) => Some(AttackId::A1082OmastarAncientWhirlpool),
+            ("A1 083", 0) => Some(AttackId::A1083ArticunoIceBeam),
+            ("A1 084", 1) => Some(AttackId::A1084ArticunoExBlizzard),
+            ("A1 258", 1) => Some(AttackId::A1084ArticunoExBlizzard),
+            ("A1 275", 1) => Some(AttackId::A1084ArticunoExBlizzard),
+            ("A3b 104", 1) => Some(AttackId::A1084ArticunoExBlizzard),
+            ("A3 112", 0) => Some(AttackId::A3112AbsolUnseenClaw),
+            ("A1 091", 0) => Some(AttackId::A1091BruxishSecondStrike),
+            ("A1 093", 0) => Some(AttackId::A1093FrosmothPowderSnow),
+            ("A1 095", 0) => Some(AttackId::A1095RaichuThunderbolt),
+            ("A1 096", 0) => Some(AttackId::A1096PikachuExCircleCircuit),
+            ("A1 259", 0) => Some(AttackId::A1096PikachuExCircleCircuit),
+            ("A1 281", 0) => Some(AttackId::A1096PikachuExCircleCircuit),
+            ("A1 285", 0) => Some(AttackId::A1096PikachuExCircleCircuit),
+            ("A1 101", 0) => Some(AttackId::A1101ElectabuzzThunderPunch),
+            ("A1 102", 0) => Some(AttackId::A1102JolteonPinMissile),
+            ("A1 103", 0) => Some(AttackId::A1103ZapdosRagingThunder),
+            ("A1 104", 1) => Some(AttackId::A1104ZapdosExThunderingHurricane),
+            ("A1 260", 1) => Some(AttackId::A1104ZapdosExThunderingHurricane),
+            ("A1 276", 1) => Some(AttackId::A1104ZapdosExThunderingHurricane),
+            ("A3b 105", 1) => Some(AttackId::A1104ZapdosExThunderingHurricane),
+            ("A1 106", 0) => Some(AttackId::A1106ZebstrikaThunder),
+            ("A1 109", 0) => Some(AttackId::A1109EelektrossThunderFang),
+            ("A1 111", 0) => Some(AttackId::A1111HelioliskQuickAttack),
+            ("A1 112", 0) => Some(AttackId::A1112PincurchinThunderShock),
+            ("A1 115", 0) => Some(AttackId::A1115AbraPsyshock),
+            ("A1 117", 0) => Some(AttackId::A1117AlakazamPsychic),
+            ("A1 236", 0) => Some(AttackId::A1117AlakazamPsychic),
+            ("A1 122", 0) => Some(AttackId::A1122GengarBother),
+            ("A3 222", 0) => Some(AttackId::A1122GengarBother),
+            ("A1 126", 0) => Some(AttackId::A1126MrMimeBarrierAttack),
+            ("A1 127", 0) => Some(AttackId::A1127JynxPsychic),
+            ("A3a 094", 0) => Some(AttackId::A1127JynxPsychic),
+            ("A1 128", 0) => Some(AttackId::A1128MewtwoPowerBlast),
+            ("P-A 010", 0) => Some(AttackId::A1128MewtwoPowerBlast),
+            ("A1 129", 1) => Some(AttackId::A1129MewtwoExPsydrive),
+            ("A1 262", 1) => Some(AttackId::A1129MewtwoExPsydrive),
+            ("A1 282", 1) => Some(AttackId::A1129MewtwoExPsydrive),
+            ("A1 286", 1) => Some(AttackId::A1129MewtwoExPsydrive),
+            ("P-A 050", 1) => Some(AttackId::A1129MewtwoExPsydrive),
+            ("A1 136", 0) => Some(AttackId::A1136GolurkDoubleLariat),
+            ("A1 140", 0) => Some(AttackId::A1140DugtrioDig),
+            ("A1 142", 0) => Some(AttackId::A1142PrimeapeFightBack),
+            ("A1 149", 0) => Some(AttackId::A1149GolemDoubleEdge),
+            ("A1 151", 0) => Some(AttackId::A1151CuboneUnknown),
+            ("A1 239", 0) => Some(AttackId::A1151CuboneUnknown),
+            ("A3 226", 0) => Some(AttackId::A1151CuboneUnknown),
+            ("A1 153", 0) => Some(AttackId::A1153MarowakExBonemerang),
+            ("A1 264", 0) => Some(AttackId::A1153MarowakExBonemerang),
+            ("A3 236", 0) => Some(AttackId::A1153MarowakExBonemerang),
+            ("A1 154", 0) => Some(AttackId::A1154HitmonleeStretch),
+            ("A1 159", 0) => Some(AttackId::A1159KabutopsLeechLife),
+            ("A1 163", 0) => Some(AttackId::A1163GrapploctKnockBack),
+            ("A1 165", 0) => Some(AttackId::A1165ArbokCorner),
+            ("A3b 101", 0) => Some(AttackId::A1165ArbokCorner),
+            ("A1 166", 0) => Some(AttackId::A1166NidoranFCallfor),
+            ("A1 168", 0) => Some(AttackId::A1168NidoqueenLovestrike),
+            ("A1 240", 0) => Some(AttackId::A1168NidoqueenLovestrike),
+            ("A1 171", 0) => Some(AttackId::A1171NidokingPoisonHorn),
+            ("A1 241", 0) => Some(AttackId::A1171NidokingPoisonHorn),
+            ("A1 174", 0) => Some(AttackId::A1174GrimerPoisonGas),
+            ("A1 175", 0) => Some(AttackId::A1175MukVenoshock),
+            ("A1 178", 0) => Some(AttackId::A1178MawileCrunch),
+            ("A1 181", 0) => Some(AttackId::A1181MeltanUnknown),
+            ("A1 185", 0) => Some(AttackId::A1185DragoniteDraco),
+            ("A1 244", 0) => Some(AttackId::A1185DragoniteDraco),
+            ("A1 192", 0) => Some(AttackId::A1192FearowDrillRun),
+            ("A1 195", 0) => Some(AttackId::A1195WigglytuffExSleepySong),
+            ("A1 265", 0) => Some(AttackId::A1195WigglytuffExSleepySong),
+            ("A1 279", 0) => Some(AttackId::A1195WigglytuffExSleepySong),
+            ("A3 237", 0) => Some(AttackId::A1195WigglytuffExSleepySong),
+            ("A1 196", 0) => Some(AttackId::A1196MeowthPayDay),
+            ("A1 246", 0) => Some(AttackId::A1196MeowthPayDay),
+            ("P-A 012", 0) => Some(AttackId::A1196MeowthPayDay),
+            ("A1 197", 0) => Some(AttackId::A1197PersianShadowClaw),
+            ("A1 201", 0) => Some(AttackId::A1201LickitungContinuousLick),
+            ("A1 203", 0) => Some(AttackId::A1203KangaskhanDizzyPunch),
+            ("A1 205", 0) => Some(AttackId::A1205Dittoopy),
+            ("A1 247", 0) => Some(AttackId::A1205Dittoopy),
+            ("A1 210", 0) => Some(AttackId::A1210AerodactylPrimal),
+            ("A3a 098", 0) => Some(AttackId::A1210AerodactylPrimal),
+            ("A1 213", 0) => Some(AttackId::A1213CinccinoDotheWave),
+            ("P-A 031", 0) => Some(AttackId::A1213CinccinoDotheWave),
+            ("A1 283", 0) => Some(AttackId::A1283MewsyReport),
+            ("A1a 031", 0) => Some(AttackId::A1283MewsyReport),
+            ("A1a 001", 0) => Some(AttackId::A1a001ExeggcuteGrowth),
+            ("P-A 060", 0) => Some(AttackId::A1a001ExeggcuteGrowth),
+            ("A1a 002", 0) => Some(AttackId::A1a002ExeggutorPsychic),
+            ("A1a 069", 0) => Some(AttackId::A1a002ExeggutorPsychic),
+            ("A3 214", 0) => Some(AttackId::A1a002ExeggutorPsychic),
+            ("A1a 003", 0) => Some(AttackId::A1a003CelebiExPowerfulBloom),
+            ("A1a 075", 0) => Some(AttackId::A1a003CelebiExPowerfulBloom),
+            ("A1a 085", 0) => Some(AttackId::A1a003CelebiExPowerfulBloom),
+            ("A3a 099", 0) => Some(AttackId::A1a003CelebiExPowerfulBloom),
+            ("A1a 008", 0) => Some(AttackId::A1a008ShiinoticFlickeringSpores),
+            ("A1a 009", 0) => Some(AttackId::A1a009DhelmiseEnergyWhip),
+            ("A1a 010", 0) => Some(AttackId::A1a010PonytaStomp),
+            ("A1a 011", 0) => Some(AttackId::A1a011RapidashRisingLunge),
+            ("A1a 012", 0) => Some(AttackId::A1a012MagmarFireBlast),
+            ("A1a 014", 0) => Some(AttackId::A1a014VolcaronaVolcanic),
+            ("P-A 028", 0) => Some(AttackId::A1a014VolcaronaVolcanic),
+            ("A1a 015", 0) => Some(AttackId::A1a015SalanditVenoshock),
+            ("A1a 071", 0) => Some(AttackId::A1a015SalanditVenoshock),
+            ("A1a 016", 0) => Some(AttackId::A1a016SalazzlePoisonClaws),
+            ("A1a 017", 0) => Some(AttackId::A1a017MagikarpLeap),
+            ("A1a 018", 0) => Some(AttackId::A1a018GyaradosExRampagingWhirlpool),
+            ("A1a 076", 0) => Some(AttackId::A1a018GyaradosExRampagingWhirlpool),
+            ("A1a 021", 0) => Some(AttackId::A1a021LumineonAqua),
+            ("A1a 023", 0) => Some(AttackId::A1a023DrednawCrunch),
+            ("A1a 024", 0) => Some(AttackId::A1a024CramorantDive),
+            ("A1a 025", 0) => Some(AttackId::A1a025PikachuCircleCircuit),
+            ("A1a 026", 0) => Some(AttackId::A1a026RaichuGigashock),
+            ("A1a 027", 0) => Some(AttackId::A1a027ElectabuzzThunder),
+            ("A1a 029", 0) => Some(AttackId::A1a029GalvantulaElectroweb),
+            ("A1a 030", 0) => Some(AttackId::A1a030DedenneThunderShock),
+            ("A1a 073", 0) => Some(AttackId::A1a030DedenneThunderShock),
+            ("A1a 032", 1) => Some(AttackId::A1a032MewExGenome),
+            ("A1a 077", 1) => Some(AttackId::A1a032MewExGenome),
+            ("A1a 083", 1) => Some(AttackId::A1a032MewExGenome),
+            ("A1a 086", 1) => Some(AttackId::A1a032MewExGenome),
+            ("A1a 033", 0) => Some(AttackId::A1a033SigilyphSpikeDraw),
+            ("A1a 035", 0) => Some(AttackId::A1a035BeheeyemMindJack),
+            ("A1a 036", 0) => Some(AttackId::A1a036FlabebeHypnotic),
+            ("A1a 037", 0) => Some(AttackId::A1a037FloetteLeafDrain),
+            ("A1a 038", 0) => Some(AttackId::A1a038FlorgesBloomshine),
+            ("A1a 041", 0) => Some(AttackId::A1a041Mankeyocusist),
+            ("A1a 045", 0) => Some(AttackId::A1a045GolemGuardPress),
+            ("A1a 047", 0) => Some(AttackId::A1a047MarshadowRevenge),
+            ("A1a 074", 0) => Some(AttackId::A1a047MarshadowRevenge),
+            ("A1a 049", 0) => Some(AttackId::A1a049KoffingUnknown),
+            ("A1a 050", 0) => Some(AttackId::A1a050WeezingSmokescreen),
+            ("A1a 054", 0) => Some(AttackId::A1a054WhirlipedePoisonSting),
+            ("A1a 055", 0) => Some(AttackId::A1a055ScolipedeVenoshock),
+            ("A1a 059", 0) => Some(AttackId::A1a059PidgeotExScatteringCyclone),
+            ("A1a 079", 0) => Some(AttackId::A1a059PidgeotExScatteringCyclone),
+            ("A3a 102", 0) => Some(AttackId::A1a059PidgeotExScatteringCyclone),
+            ("A1a 060", 0) => Some(AttackId::A1a060TaurosFightingTackle),
+            ("A1a 061", 0) => Some(AttackId::A1a061EeveeontinuousSteps),
+            ("A1a 062", 0) => Some(AttackId::A1a062ChatotUnknown),
+            ("A2 001", 0) => Some(AttackId::A2001OddishBlot),
+            ("A2 005", 0) => Some(AttackId::A2005TangrowthMegaDrain),
+            ("A2 156", 0) => Some(AttackId::A2005TangrowthMegaDrain),
+            ("A2 007", 0) => Some(AttackId::A2007YanmegaExAirSlash),
+            ("A2 180", 0) => Some(AttackId::A2007YanmegaExAirSlash),
+            ("A2 196", 0) => Some(AttackId::A2007YanmegaExAirSlash),
+            ("A2 009", 0) => Some(AttackId::A2009RoseradePoisonousWhip),
+            ("A2 012", 0) => Some(AttackId::A2012TorterraFrenzyPlant),
+            ("A2 014", 0) => Some(AttackId::A2014KricketuneEntrancingMelody),
+            ("A2 016", 0) => Some(AttackId::A2016WormadamLeafCutter),
+            ("A2 017", 0) => Some(AttackId::A2017Combeeallfor),
+            ("A2 157", 0) => Some(AttackId::A2017Combeeallfor),
+            ("A2 019", 0) => Some(AttackId::A2019CarnivineFlog),
+            ("A2 158", 0) => Some(AttackId::A2019CarnivineFlog),
+            ("A2 020", 0) => Some(AttackId::A2020LeafeonLeafyCyclone),
+            ("A2 021", 0) => Some(AttackId::A2021MowRotomEnergyCutoff),
+            ("A2 023", 0) => Some(AttackId::A2023MagmarUnknown),
+            ("A2 024", 0) => Some(AttackId::A2024MagmortarBurstingInferno),
+            ("A2 026", 0) => Some(AttackId::A2026MagcargoSearingFlame),
+            ("A2 029", 0) => Some(AttackId::A2029InfernapeExFlareBlitz),
+            ("A2 181", 0) => Some(AttackId::A2029InfernapeExFlareBlitz),
+            ("A2 197", 0) => Some(AttackId::A2029InfernapeExFlareBlitz),
+            ("A2 030", 0) => Some(AttackId::A2030HeatRotomHeatBreath),
+            ("A2 035", 0) => Some(AttackId::A2035PiplupUnknown),
+            ("P-A 034", 0) => Some(AttackId::A2035PiplupUnknown),
+            ("A2 037", 0) => Some(AttackId::A2037EmpoleonAquaJet),
+            ("A2 039", 0) => Some(AttackId::A2039FloatzelJetScrew),
+            ("A2 041", 0) => Some(AttackId::A2041GastrodonMuddyWater),
+            ("A2 161", 0) => Some(AttackId::A2041GastrodonMuddyWater),
+            ("A2 042", 0) => Some(AttackId::A2042FinneonElegantSwim),
+            ("A2 044", 0) => Some(AttackId::A2044SnoverIceShard),
+            ("A2 046", 0) => Some(AttackId::A2046GlaceonIceBeam),
+            ("A2 048", 0) => Some(AttackId::A2048FrostRotomBlizzard),
+            ("A2 049", 1) => Some(AttackId::A2049PalkiaExDimensionalStorm),
+            ("A2 182", 1) => Some(AttackId::A2049PalkiaExDimensionalStorm),
+            ("A2 204", 1) => Some(AttackId::A2049PalkiaExDimensionalStorm),
+            ("A2 206", 1) => Some(AttackId::A2049PalkiaExDimensionalStorm),
+            ("A2 050", 0) => Some(AttackId::A2050ManaphyOceanic),
+            ("A2 162", 0) => Some(AttackId::A2050ManaphyOceanic),
+            ("P-A 048", 0) => Some(AttackId::A2050ManaphyOceanic),
+            ("A2 053", 0) => Some(AttackId::A2053MagnezoneThunderBlast),
+            ("A2 054", 0) => Some(AttackId::A2054VoltorbBigExplosion),
+            ("A3b 095", 0) => Some(AttackId::A2054VoltorbBigExplosion),
+            ("A2 056", 0) => Some(AttackId::A2056ElectabuzzUnknown),
+            ("A2 057", 0) => Some(AttackId::A2057ElectivireExcitingVoltage),
+            ("P-A 036", 0) => Some(AttackId::A2057ElectivireExcitingVoltage),
+            ("A2 058", 0) => Some(AttackId::A2058ShinxUnknown),
+            ("A2 163", 0) => Some(AttackId::A2058ShinxUnknown),
+            ("A2 060", 0) => Some(AttackId::A2060LuxrayVolt),
+            ("A2 061", 0) => Some(AttackId::A2061PachirisuExSparkingGadget),
+            ("A2 183", 0) => Some(AttackId::A2061PachirisuExSparkingGadget),
+            ("A2 198", 0) => Some(AttackId::A2061PachirisuExSparkingGadget),
+            ("A2 062", 0) => Some(AttackId::A2062RotomAssaultLaser),
+            ("A2 164", 0) => Some(AttackId::A2062RotomAssaultLaser),
+            ("A2 065", 0) => Some(AttackId::A2065TogekissOverdriveSmash),
+            ("A2 067", 0) => Some(AttackId::A2067MismagiusExMagicalDelusion),
+            ("A2 184", 0) => Some(AttackId::A2067MismagiusExMagicalDelusion),
+            ("A2 199", 0) => Some(AttackId::A2067MismagiusExMagicalDelusion),
+            ("A2 068", 0) => Some(AttackId::A2068RaltsUnknown),
+            ("A3b 097", 0) => Some(AttackId::A2068RaltsUnknown),
+            ("A2 073", 0) => Some(AttackId::A2073DrifloonExpand),
+            ("A2 165", 0) => Some(AttackId::A2073DrifloonExpand),
+            ("A2 075", 0) => Some(AttackId::A2075UxieMindBoost),
+            ("A2 076", 0) => Some(AttackId::A2076MespritSupremeBlast),
+            ("A2 166", 0) => Some(AttackId::A2076MespritSupremeBlast),
+            ("A2 077", 0) => Some(AttackId::A2077Azelfsychic),
+            ("A2 079", 0) => Some(AttackId::A2079CresseliaMoonlightGain),
+            ("A2 168", 0) => Some(AttackId::A2079CresseliaMoonlightGain),
+            ("A2 082", 0) => Some(AttackId::A2082RhyperiorMountainSwing),
+            ("A2 169", 0) => Some(AttackId::A2082RhyperiorMountainSwing),
+            ("A2 084", 0) => Some(AttackId::A2084GliscorAcrobatics),
+            ("A2 089", 0) => Some(AttackId::A2089RampardosHeadSmash),
+            ("A2 095", 0) => Some(AttackId::A2095GalladeExEnergizedBlade),
+            ("A2 185", 0) => Some(AttackId::A2095GalladeExEnergizedBlade),
+            ("A2 200", 0) => Some(AttackId::A2095GalladeExEnergizedBlade),
+            ("A3b 106", 0) => Some(AttackId::A2095GalladeExEnergizedBlade),
+            ("A2 097", 0) => Some(AttackId::A2097HonchkrowSkill),
+            ("A2 098", 0) => Some(AttackId::A2098SneaseloubleScratch),
+            ("A2 099", 0) => Some(AttackId::A2099WeavileExScratchingNails),
+            ("A2 186", 0) => Some(AttackId::A2099WeavileExScratchingNails),
+            ("A2 201", 0) => Some(AttackId::A2099WeavileExScratchingNails),
+            ("A2 103", 0) => Some(AttackId::A2103SkuntankPoisonGas),
+            ("A2 104", 0) => Some(AttackId::A2104SpiritombSwirling),
+            ("A2 172", 0) => Some(AttackId::A2104SpiritombSwirling),
+            ("A2 106", 0) => Some(AttackId::A2106DrapionCrossPoison),
+            ("A2 107", 0) => Some(AttackId::A2107CroagunkGroupBeatdown),
+            ("A2 173", 0) => Some(AttackId::A2107CroagunkGroupBeatdown),
+            ("A2 108", 0) => Some(AttackId::A2108ToxicroakGroupBeatdown),
+            ("A2 109", 0) => Some(AttackId::A2109DarkraiDarkVoid),
+            ("A2 111", 0) => Some(AttackId::A2111SkarmoryetalArms),
+            ("P-A 039", 0) => Some(AttackId::A2111SkarmoryetalArms),
+            ("A2 115", 0) => Some(AttackId::A2115WormadamIronHead),
+            ("A2 117", 0) => Some(AttackId::A2117BronzongGuardPress),
+            ("A2 118", 0) => Some(AttackId::A2118ProbopassTripleNose),
+            ("A2 119", 0) => Some(AttackId::A2119DialgaExMetallicTurbo),
+            ("A2 188", 0) => Some(AttackId::A2119DialgaExMetallicTurbo),
+            ("A2 205", 0) => Some(AttackId::A2119DialgaExMetallicTurbo),
+            ("A2 207", 0) => Some(AttackId::A2119DialgaExMetallicTurbo),
+            ("A2 120", 0) => Some(AttackId::A2120HeatranSteelTackle),
+            ("A2 174", 0) => Some(AttackId::A2120HeatranSteelTackle),
+            ("A2 125", 0) => Some(AttackId::A2125LickilickyExLickingFury),
+            ("A2 189", 0) => Some(AttackId::A2125LickilickyExLickingFury),
+            ("A2 203", 0) => Some(AttackId::A2125LickilickyExLickingFury),
+            ("A2 126", 0) => Some(AttackId::A2126EeveeQuickAttack),
+            ("A2 129", 0) => Some(AttackId::A2129PorygonZBuggyBeam),
+            ("A2 131", 0) => Some(AttackId::A2131AmbipomDoubleHit),
+            ("A2 132", 0) => Some(AttackId::A2132StarlyPluck),
+            ("A2 134", 0) => Some(AttackId::A2134StaraptorClutch),
+            ("A2 176", 0) => Some(AttackId::A2134StaraptorClutch),
+            ("A2 135", 0) => Some(AttackId::A2135BidoofSuper),
+            ("A2 177", 0) => Some(AttackId::A2135BidoofSuper),
+            ("A2 138", 0) => Some(AttackId::A2138LopunnyJumpKick),
+            ("A2 139", 0) => Some(AttackId::A2139GlameowPose),
+            ("A2 178", 0) => Some(AttackId::A2139GlameowPose),
+            ("A2 140", 0) => Some(AttackId::A2140PuruglyInterrupt),
+            ("A2 141", 0) => Some(AttackId::A2141ChatotFuryAttack),
+            ("A2 142", 0) => Some(AttackId::A2142FanRotomSpin),
+            ("A2 143", 0) => Some(AttackId::A2143RegigigasRagingHammer),
+            ("A2 179", 0) => Some(AttackId::A2143RegigigasRagingHammer),
+            ("A2a 001", 0) => Some(AttackId::A2a001HeracrossSingleHornThrow),
+            ("A2a 005", 0) => Some(AttackId::A2a005VespiquenRecklessCharge),
+            ("A2a 007", 0) => Some(AttackId::A2a007CherrimWorrySeed),
+            ("A2a 008", 0) => Some(AttackId::A2a008CherrimLeechSeed),
+            ("P-A 043", 0) => Some(AttackId::A2a008CherrimLeechSeed),
+            ("A2a 012", 0) => Some(AttackId::A2a012HoundoomCorner),
+            ("A2a 076", 0) => Some(AttackId::A2a012HoundoomCorner),
+            ("A2a 013", 0) => Some(AttackId::A2a013HeatranRaginMadStrike),
+            ("A2a 017", 0) => Some(AttackId::A2a017WhiscashThrash),
+            ("A2a 019", 0) => Some(AttackId::A2a019FroslassBlizzard),
+            ("A2a 023", 0) => Some(AttackId::A2a023OriginFormePalkiaZoneSmash),
+            ("A2a 024", 0) => Some(AttackId::A2a024PhioneWaterPulse),
+            ("A2a 025", 0) => Some(AttackId::A2a025PikachuSpark),
+            ("A2a 026", 0) => Some(AttackId::A2a026RaichuSpark),
+            ("P-A 044", 0) => Some(AttackId::A2a026RaichuSpark),
+            ("A2a 028", 0) => Some(AttackId::A2a028ManectricFlash),
+            ("A2a 030", 0) => Some(AttackId::A2a030ClefableMoonblast),
+            ("A2a 031", 0) => Some(AttackId::A2a031GastlyUnknown),
+            ("A3 220", 0) => Some(AttackId::A2a031GastlyUnknown),
+            ("A2a 033", 0) => Some(AttackId::A2a033GengarHypnoblast),
+            ("A2a 035", 0) => Some(AttackId::A2a035RotomDash),
+            ("A2a 036", 0) => Some(AttackId::A2a036SudowoodoightingHeadbutt),
+            ("A2a 079", 0) => Some(AttackId::A2a036SudowoodoightingHeadbutt),
+            ("A2a 038", 0) => Some(AttackId::A2a038DonphanRollingSpin),
+            ("A2a 044", 0) => Some(AttackId::A2a044MedichamKickShot),
+            ("A2a 047", 0) => Some(AttackId::A2a047GarchompExLinear),
+            ("A2a 084", 0) => Some(AttackId::A2a047GarchompExLinear),
+            ("A2a 093", 0) => Some(AttackId::A2a047GarchompExLinear),
+            ("A2a 052", 0) => Some(AttackId::A2a052ToxicroakUnknown),
+            ("A2a 056", 0) => Some(AttackId::A2a056MawileIronBeamBreaker),
+            ("A2a 057", 0) => Some(AttackId::A2a057ProbopassExDefensiveUnit),
+            ("A2a 085", 0) => Some(AttackId::A2a057ProbopassExDefensiveUnit),
+            ("A2a 094", 0) => Some(AttackId::A2a057ProbopassExDefensiveUnit),
+            ("A2a 059", 0) => Some(AttackId::A2a059BronzongPsychic),
+            ("A2a 060", 0) => Some(AttackId::A2a060OriginFormeDialgaTimeMash),
+            ("A2a 061", 0) => Some(AttackId::A2a061GiratinaCrisisDive),
+            ("A2a 063", 0) => Some(AttackId::A2a063SnorlaxCollapse),
+            ("P-A 049", 0) => Some(AttackId::A2a063SnorlaxCollapse),
+            ("A2a 065", 0) => Some(AttackId::A2a065NoctowlSilentWing),
+            ("A2a 068", 0) => Some(AttackId::A2a068StaraptorBraveBird),
+            ("A2a 070", 0) => Some(AttackId::A2a070ArceusPowerBlast),
+            ("A2a 071", 0) => Some(AttackId::A2a071ArceusExUltimateForce),
+            ("A2a 086", 0) => Some(AttackId::A2a071ArceusExUltimateForce),
+            ("A2a 095", 0) => Some(AttackId::A2a071ArceusExUltimateForce),
+            ("A2a 096", 0) => Some(AttackId::A2a071ArceusExUltimateForce),
+            ("A2b 001", 0) => Some(AttackId::A2b001WeedleUnknown),
+            ("A2b 097", 0) => Some(AttackId::A2b001WeedleUnknown),
+            ("A2b 002", 0) => Some(AttackId::A2b002KakunaStringShot),
+            ("A2b 098", 0) => Some(AttackId::A2b002KakunaStringShot),
+            ("A2b 003", 0) => Some(AttackId::A2b003BeedrillExCrushingSpear),
+            ("A2b 079", 0) => Some(AttackId::A2b003BeedrillExCrushingSpear),
+            ("A2b 107", 0) => Some(AttackId::A2b003BeedrillExCrushingSpear),
+            ("A2b 004", 0) => Some(AttackId::A2b004PinsirGuillotineRush),
+            ("A3b 093", 0) => Some(AttackId::A2b004PinsirGuillotineRush),
+            ("A2b 005", 0) => Some(AttackId::A2b005SprigatitoCryfor),
+            ("P-A 052", 0) => Some(AttackId::A2b005SprigatitoCryfor),
+            ("A2b 007", 0) => Some(AttackId::A2b007MeowscaradaFightingClaws),
+            ("A2b 073", 0) => Some(AttackId::A2b007MeowscaradaFightingClaws),
+            ("A2b 010", 0) => Some(AttackId::A2b010CharizardExUnknown),
+            ("A2b 080", 0) => Some(AttackId::A2b010CharizardExUnknown),
+            ("A2b 108", 0) => Some(AttackId::A2b010CharizardExUnknown),
+            ("A2b 012", 0) => Some(AttackId::A2b012MagmortarSmokeBomb),
+            ("A2b 013", 0) => Some(AttackId::A2b013PaldeanTaurosBlazeTackle),
+            ("A2b 017", 0) => Some(AttackId::A2b017Floatzelater),
+            ("A2b 018", 0) => Some(AttackId::A2b018WiglettSpring),
+            ("A2b 101", 0) => Some(AttackId::A2b018WiglettSpring),
+            ("A2b 019", 0) => Some(AttackId::A2b019WugtrioExPopOut),
+            ("A2b 081", 0) => Some(AttackId::A2b019WugtrioExPopOut),
+            ("A2b 109", 0) => Some(AttackId::A2b019WugtrioExPopOut),
+            ("A2b 020", 0) => Some(AttackId::A2b020DondozoOceanCyclone),
+            ("A2b 102", 0) => Some(AttackId::A2b020DondozoOceanCyclone),
+            ("A2b 022", 0) => Some(AttackId::A2b022PikachuExThunderbolt),
+            ("A2b 082", 0) => Some(AttackId::A2b022PikachuExThunderbolt),
+            ("A2b 092", 0) => Some(AttackId::A2b022PikachuExThunderbolt),
+            ("A2b 024", 0) => Some(AttackId::A2b024ElectrodeTumblingAttack),
+            ("A2b 025", 0) => Some(AttackId::A2b025PachirisuPlasma),
+            ("A2b 103", 0) => Some(AttackId::A2b025PachirisuPlasma),
+            ("P-A 058", 0) => Some(AttackId::A2b025PachirisuPlasma),
+            ("A2b 031", 0) => Some(AttackId::A2b031AlakazamPsychicSuppression),
+            ("A2b 032", 0) => Some(AttackId::A2b032MrMimeJuggling),
+            ("A2b 035", 0) => Some(AttackId::A2b035GiratinaExChaoticImpact),
+            ("A2b 083", 0) => Some(AttackId::A2b035GiratinaExChaoticImpact),
+            ("A2b 096", 0) => Some(AttackId::A2b035GiratinaExChaoticImpact),
+            ("A2b 036", 0) => Some(AttackId::A2b036GimmighoulUnknown),
+            ("A2b 038", 0) => Some(AttackId::A2b038MachokePummel),
+            ("A2b 039", 0) => Some(AttackId::A2b039MachampPowerPress),
+            ("A3 225", 0) => Some(AttackId::A2b039MachampPowerPress),
+            ("P-A 055", 0) => Some(AttackId::A2b039MachampPowerPress),
+            ("A2b 043", 0) => Some(AttackId::A2b043LucarioExAuraSphere),
+            ("A2b 084", 0) => Some(AttackId::A2b043LucarioExAuraSphere),
+            ("A2b 110", 0) => Some(AttackId::A2b043LucarioExAuraSphere),
+            ("A2b 044", 0) => Some(AttackId::A2b044FlamigoDoubleKick),
+            ("A2b 046", 0) => Some(AttackId::A2b046ArbokVenomousFang),
+            ("A2b 047", 0) => Some(AttackId::A2b047PaldeanWooperPoisonJab),
+            ("A2b 048", 0) => Some(AttackId::A2b048PaldeanClodsireExVenoshock),
+            ("A2b 085", 0) => Some(AttackId::A2b048PaldeanClodsireExVenoshock),
+            ("A2b 093", 0) => Some(AttackId::A2b048PaldeanClodsireExVenoshock),
+            ("A2b 049", 0) => Some(AttackId::A2b049SpiritombConfuseRay),
+            ("A2b 053", 0) => Some(AttackId::A2b053TinkatuffTenaciousHammer),
+            ("A2b 054", 0) => Some(AttackId::A2b054TinkatonExTerrificThumping),
+            ("A2b 086", 0) => Some(AttackId::A2b054TinkatonExTerrificThumping),
+            ("A2b 094", 0) => Some(AttackId::A2b054TinkatonExTerrificThumping),
+            ("A2b 056", 0) => Some(AttackId::A2b056RevavroomSpinningDrift),
+            ("A2b 106", 0) => Some(AttackId::A2b056RevavroomSpinningDrift),
+            ("A2b 057", 0) => Some(AttackId::A2b057GholdengoScintillatingSurfing),
+            ("A2b 077", 0) => Some(AttackId::A2b057GholdengoScintillatingSurfing),
+            ("A2b 065", 0) => Some(AttackId::A2b065BibarelExCarefreePress),
+            ("A2b 087", 0) => Some(AttackId::A2b065BibarelExCarefreePress),
+            ("A2b 095", 0) => Some(AttackId::A2b065BibarelExCarefreePress),
+            ("A2b 068", 0) => Some(AttackId::A2b068CyclizarOveracceleration),
+            ("P-A 051", 0) => Some(AttackId::A2b068CyclizarOveracceleration),
+            ("A3 002", 0) => Some(AttackId::A3002AlolanExeggutorTropicalHammer),
+            ("A3 156", 0) => Some(AttackId::A3002AlolanExeggutorTropicalHammer),
+            ("P-A 069", 0) => Some(AttackId::A3002AlolanExeggutorTropicalHammer),
+            ("A3 003", 0) => Some(AttackId::A3003SurskitQuickAttack),
+            ("A3 010", 0) => Some(AttackId::A3010RowletSkill),
+            ("A3 012", 0) => Some(AttackId::A3012DecidueyeExPiercethe),
+            ("A3 180", 0) => Some(AttackId::A3012DecidueyeExPiercethe),
+            ("A3 198", 0) => Some(AttackId::A3012DecidueyeExPiercethe),
+            ("A3 015", 0) => Some(AttackId::A3015LurantisPetal),
+            ("A3 017", 0) => Some(AttackId::A3017ShiinoticFlickeringLight),
+            ("A3 019", 0) => Some(AttackId::A3019SteeneeDoubleSpin),
+            ("A3 020", 0) => Some(AttackId::A3020TsareenaThreeKickCombo),
+            ("A3 158", 0) => Some(AttackId::A3020TsareenaThreeKickCombo),
+            ("A3 022", 0) => Some(AttackId::A3022GolisopodFirstImpression),
+            ("A3 023", 0) => Some(AttackId::A3023DhelmiseExAnchorShot),
+            ("A3 181", 0) => Some(AttackId::A3023DhelmiseExAnchorShot),
+            ("A3 199", 0) => Some(AttackId::A3023DhelmiseExAnchorShot),
+            ("A3 024", 0) => Some(AttackId::A3024TapuBuluStuckInTackle),
+            ("A3 159", 0) => Some(AttackId::A3024TapuBuluStuckInTackle),
+            ("A3 027", 0) => Some(AttackId::A3027AlolanMarowakBurningBonemerang),
+            ("A3 160", 0) => Some(AttackId::A3027AlolanMarowakBurningBonemerang),
+            ("A3 030", 0) => Some(AttackId::A3030LittenHeatTackle),
+            ("A3 032", 0) => Some(AttackId::A3032TorracatHeatTackle),
+            ("A3 033", 0) => Some(AttackId::A3033IncineroarExFireFang),
+            ("A3 182", 0) => Some(AttackId::A3033IncineroarExFireFang),
+            ("A3 200", 0) => Some(AttackId::A3033IncineroarExFireFang),
+            ("A3 033", 1) => Some(AttackId::A3033IncineroarExScarChargedSmash),
+            ("A3 182", 1) => Some(AttackId::A3033IncineroarExScarChargedSmash),
+            ("A3 200", 1) => Some(AttackId::A3033IncineroarExScarChargedSmash),
+            ("A3 034", 0) => Some(AttackId::A3034OricorioKindle),
+            ("A3 036", 0) => Some(AttackId::A3036SalazzleHeatedPoison),
+            ("A3 037", 0) => Some(AttackId::A3037TurtonatorFireSpin),
+            ("A3 161", 0) => Some(AttackId::A3037TurtonatorFireSpin),
+            ("A3 039", 0) => Some(AttackId::A3039AlolanSandslashSpikeArmor),
+            ("A3 040", 0) => Some(AttackId::A3040AlolanVulpixCallForth),
+            ("A3 162", 0) => Some(AttackId::A3040AlolanVulpixCallForth),
+            ("A3 041", 0) => Some(AttackId::A3041AlolanNinetalesBlizzard),
+            ("P-A 070", 0) => Some(AttackId::A3041AlolanNinetalesBlizzard),
+            ("A3 043", 0) => Some(AttackId::A3043CloysterGuardPress),
+            ("A3 045", 0) => Some(AttackId::A3045PopplioUnknown),
+            ("A3 049", 0) => Some(AttackId::A3049CrabominableExInsatiableStriking),
+            ("A3 183", 0) => Some(AttackId::A3049CrabominableExInsatiableStriking),
+            ("A3 201", 0) => Some(AttackId::A3049CrabominableExInsatiableStriking),
+            ("A3 050", 0) => Some(AttackId::A3050WishiwashiCallfor),
+            ("A3 051", 0) => Some(AttackId::A3051WishiwashiExSchoolStorm),
+            ("A3 184", 0) => Some(AttackId::A3051WishiwashiExSchoolStorm),
+            ("A3 202", 0) => Some(AttackId::A3051WishiwashiExSchoolStorm),
+            ("A3 053", 0) => Some(AttackId::A3053AraquanidDangerousClaws),
+            ("A3 056", 0) => Some(AttackId::A3056TapuFiniSpiralDrain),
+            ("A3 164", 0) => Some(AttackId::A3056TapuFiniSpiralDrain),
+            ("A3 058", 0) => Some(AttackId::A3058AlolanRaichuExPsychic),
+            ("A3 185", 0) => Some(AttackId::A3058AlolanRaichuExPsychic),
+            ("A3 203", 0) => Some(AttackId::A3058AlolanRaichuExPsychic),
+            ("A3 061", 0) => Some(AttackId::A3061AlolanGolemSuperZapCannon),
+            ("A3 065", 0) => Some(AttackId::A3065VikavoltDisconnect),
+            ("A3 067", 0) => Some(AttackId::A3067TogedemaruElectrosmash),
+            ("A3 068", 0) => Some(AttackId::A3068TapuKokoVoltSwitch),
+            ("A3 166", 0) => Some(AttackId::A3068TapuKokoVoltSwitch),
+            ("A3 069", 0) => Some(AttackId::A3069MrMimeBarrierShove),
+            ("A3 070", 0) => Some(AttackId::A3070SableyeCorner),
+            ("A3 071", 0) => Some(AttackId::A3071SpoinkUnknown),
+            ("A3 075", 0) => Some(AttackId::A3075BanetteNightBind),
+            ("A3 076", 0) => Some(AttackId::A3076OricorioDazzleDance),
+            ("A3 077", 0) => Some(AttackId::A3077OricorioSpitefulDance),
+            ("A3 083", 0) => Some(AttackId::A3083MimikyuShadowHit),
+            ("P-A 066", 0) => Some(AttackId::A3083MimikyuShadowHit),
+            ("A3 084", 0) => Some(AttackId::A3084TapuLeleEnergy),
+            ("A3 170", 0) => Some(AttackId::A3084TapuLeleEnergy),
+            ("A3 085", 0) => Some(AttackId::A3085CosmogUnknown),
+            ("A3 171", 0) => Some(AttackId::A3085CosmogUnknown),
+            ("P-A 067", 0) => Some(AttackId::A3085CosmogUnknown),
+            ("A3 086", 0) => Some(AttackId::A3086CosmoemUnknown),
+            ("A3 088", 0) => Some(AttackId::A3088NecrozmaPrismaticLaser),
+            ("A3 091", 0) => Some(AttackId::A3091HariyamaPushOut),
+            ("A3 098", 0) => Some(AttackId::A3098RockruffSignsof),
+            ("A3 172", 0) => Some(AttackId::A3098RockruffSignsof),
+            ("A3 100", 0) => Some(AttackId::A3100LycanrocCrunch),
+            ("P-A 068", 0) => Some(AttackId::A3100LycanrocCrunch),
+            ("A3 101", 0) => Some(AttackId::A3101LycanrocBloodFang),
+            ("A3 103", 0) => Some(AttackId::A3103MudsdaleHighHorsepower),
+            ("A3 173", 0) => Some(AttackId::A3103MudsdaleHighHorsepower),
+            ("A3 105", 0) => Some(AttackId::A3105MiniorStar),
+            ("A3 174", 0) => Some(AttackId::A3105MiniorStar),
+            ("A3 107", 0) => Some(AttackId::A3107AlolanRaticateScroungeandScarf),
+            ("A3 111", 0) => Some(AttackId::A3111AlolanMukExChemicalPanic),
+            ("A3 188", 0) => Some(AttackId::A3111AlolanMukExChemicalPanic),
+            ("A3 206", 0) => Some(AttackId::A3111AlolanMukExChemicalPanic),
+            ("A3 114", 0) => Some(AttackId::A3114GarbodorSuperPoisonBreath),
+            ("A3 116", 0) => Some(AttackId::A3116ToxapExSpikeCannon),
+            ("A3 118", 0) => Some(AttackId::A3118AlolanDugtrioIronHead),
+            ("A3 120", 0) => Some(AttackId::A3120EscavalierPike),
+            ("A3 121", 0) => Some(AttackId::A3121KlefkiUnlock),
+            ("A3 122", 0) => Some(AttackId::A3122SolgaleoExSolBreaker),
+            ("A3 189", 0) => Some(AttackId::A3122SolgaleoExSolBreaker),
+            ("A3 207", 0) => Some(AttackId::A3122SolgaleoExSolBreaker),
+            ("A3 239", 0) => Some(AttackId::A3122SolgaleoExSolBreaker),
+            ("A3 123", 0) => Some(AttackId::A3123MagearnaSilverCannon),
+            ("A3 175", 0) => Some(AttackId::A3123MagearnaSilverCannon),
+            ("A3 124", 0) => Some(AttackId::A3124DrampaBerserk),
+            ("A3 176", 0) => Some(AttackId::A3124DrampaBerserk),
+            ("A3 127", 0) => Some(AttackId::A3127KommooClangingScales),
+            ("A3 128", 0) => Some(AttackId::A3128TaurosRisingLunge),
+            ("A3 130", 0) => Some(AttackId::A3130DelcattyEnergyAssist),
+            ("A3 132", 0) => Some(AttackId::A3132HawluchaJustifiedPress),
+            ("A3 139", 0) => Some(AttackId::A3139BewearTantrum),
+            ("A3 178", 0) => Some(AttackId::A3139BewearTantrum),
+            ("A3 140", 0) => Some(AttackId::A3140OranguruPrimatesTrap),
+            ("A3a 003", 0) => Some(AttackId::A3a003RowletFuryAttack),
+            ("A3a 070", 0) => Some(AttackId::A3a003RowletFuryAttack),
+            ("A3a 004", 0) => Some(AttackId::A3a004DartrixSkill),
+            ("A3a 005", 0) => Some(AttackId::A3a005DecidueyeSniping),
+            ("A3a 006", 1) => Some(AttackId::A3a006BuzzwoleExBigBeat),
+            ("A3a 076", 1) => Some(AttackId::A3a006BuzzwoleExBigBeat),
+            ("A3a 088", 1) => Some(AttackId::A3a006BuzzwoleExBigBeat),
+            ("A3a 007", 0) => Some(AttackId::A3a007PheromosaJumpBlues),
+            ("A3a 071", 0) => Some(AttackId::A3a007PheromosaJumpBlues),
+            ("A3a 009", 0) => Some(AttackId::A3a009BlacephalonBeatPunk),
+            ("A3a 072", 0) => Some(AttackId::A3a009BlacephalonBeatPunk),
+            ("A3a 012", 0) => Some(AttackId::A3a012SharpedoCrunch),
+            ("A3a 019", 0) => Some(AttackId::A3a019TapuKokoExPlasmaHurricane),
+            ("A3a 077", 0) => Some(AttackId::A3a019TapuKokoExPlasmaHurricane),
+            ("A3a 084", 0) => Some(AttackId::A3a019TapuKokoExPlasmaHurricane),
+            ("A3a 020", 0) => Some(AttackId::A3a020XurkitreeElectronica),
+            ("A3a 028", 0) => Some(AttackId::A3a028MedititeTripOver),
+            ("A3a 033", 0) => Some(AttackId::A3a033LycanrocExLycanfang),
+            ("A3a 078", 0) => Some(AttackId::A3a033LycanrocExLycanfang),
+            ("A3a 085", 0) => Some(AttackId::A3a033LycanrocExLycanfang),
+            ("A3a 034", 0) => Some(AttackId::A3a034PassimianLetsThrow),
+            ("A3a 036", 0) => Some(AttackId::A3a036PalossandSandTomb),
+            ("A3a 037", 0) => Some(AttackId::A3a037AlolanMeowthUnknown),
+            ("A3a 073", 0) => Some(AttackId::A3a037AlolanMeowthUnknown),
+            ("A3a 038", 0) => Some(AttackId::A3a038AlolanPersianFakeOut),
+            ("A3a 041", 0) => Some(AttackId::A3a041KrookodilePoachingFangs),
+            ("A3a 042", 0) => Some(AttackId::A3a042NihilegoNewWave),
+            ("A3a 103", 0) => Some(AttackId::A3a042NihilegoNewWave),
+            ("A3a 043", 0) => Some(AttackId::A3a043GuzzlordExGrindcore),
+            ("A3a 079", 0) => Some(AttackId::A3a043GuzzlordExGrindcore),
+            ("A3a 086", 0) => Some(AttackId::A3a043GuzzlordExGrindcore),
+            ("A3a 044", 0) => Some(AttackId::A3a044Poipole2Step),
+            ("A3a 045", 0) => Some(AttackId::A3a045NaganadelElectroHouse),
+            ("A3a 046", 0) => Some(AttackId::A3a046AlolanDiglettHappenedtoHeadbutt),
+            ("A3a 047", 0) => Some(AttackId::A3a047AlolanDugtrioExTripletHeadbutt),
+            ("A3a 080", 0) => Some(AttackId::A3a047AlolanDugtrioExTripletHeadbutt),
+            ("A3a 087", 0) => Some(AttackId::A3a047AlolanDugtrioExTripletHeadbutt),
+            ("A3a 050", 0) => Some(AttackId::A3a050AggronGigaImpact),
+            ("A3a 053", 0) => Some(AttackId::A3a053StakatakaBrassRock),
+            ("A3a 058", 0) => Some(AttackId::A3a058BewearSuperpowered),
+            ("A3a 060", 0) => Some(AttackId::A3a060TypeNullQuickBlow),
+            ("A3a 061", 0) => Some(AttackId::A3a061SilvallyBraveBuddies),
+            ("A3a 074", 0) => Some(AttackId::A3a061SilvallyBraveBuddies),
+            ("A3a 062", 0) => Some(AttackId::A3a062CelesteelaMoombahton),
+            ("A3a 075", 0) => Some(AttackId::A3a062CelesteelaMoombahton),
+            ("A3b 002", 0) => Some(AttackId::A3b002LeafeonLeafBlast),
+            ("A3b 070", 0) => Some(AttackId::A3b002LeafeonLeafBlast),
+            ("A3b 005", 0) => Some(AttackId::A3b005TsareenaKickDown),
+            ("A3b 007", 0) => Some(AttackId::A3b007AppletunSweetsRelay),
+            ("A3b 008", 0) => Some(AttackId::A3b008FlareonAssistingHeater),
+            ("A3b 071", 0) => Some(AttackId::A3b008FlareonAssistingHeater),
+            ("A3b 009", 0) => Some(AttackId::A3b009FlareonExFireSpin),
+            ("A3b 079", 0) => Some(AttackId::A3b009FlareonExFireSpin),
+            ("A3b 087", 0) => Some(AttackId::A3b009FlareonExFireSpin),
+            ("A3b 010", 0) => Some(AttackId::A3b010TorkoalFlamethrower),
+            ("A3b 013", 0) => Some(AttackId::A3b013IncineroarDarkestLariat),
+            ("A3b 014", 0) => Some(AttackId::A3b014SalanditEmber),
+            ("A3b 016", 0) => Some(AttackId::A3b016VaporeonHyperWhirlpool),
+            ("A3b 072", 0) => Some(AttackId::A3b016VaporeonHyperWhirlpool),
+            ("A3b 017", 0) => Some(AttackId::A3b017GlaceonIce),
+            ("A3b 073", 0) => Some(AttackId::A3b017GlaceonIce),
+            ("A3b 018", 0) => Some(AttackId::A3b018VanilliteSweetsRelay),
+            ("A3b 019", 0) => Some(AttackId::A3b019VanillishSweetsRelay),
+            ("A3b 020", 0) => Some(AttackId::A3b020VanilluxeDoubleSpin),
+            ("A3b 021", 0) => Some(AttackId::A3b021AlomomolaWaterPulse),
+            ("A3b 024", 0) => Some(AttackId::A3b024PrimarinaExHydroPump),
+            ("A3b 080", 0) => Some(AttackId::A3b024PrimarinaExHydroPump),
+            ("A3b 088", 0) => Some(AttackId::A3b024PrimarinaExHydroPump),
+            ("A3b 024", 1) => Some(AttackId::A3b024PrimarinaExSparklingAria),
+            ("A3b 080", 1) => Some(AttackId::A3b024PrimarinaExSparklingAria),
+            ("A3b 088", 1) => Some(AttackId::A3b024PrimarinaExSparklingAria),
+            ("A3b 025", 0) => Some(AttackId::A3b025JolteonBeginningBolt),
+            ("A3b 074", 0) => Some(AttackId::A3b025JolteonBeginningBolt),
+            ("A3b 026", 0) => Some(AttackId::A3b026JoltikJolt),
+            ("A3b 027", 0) => Some(AttackId::A3b027GalvantulaElectricShock),
+            ("A3b 028", 0) => Some(AttackId::A3b028EspeonEnergyCrush),
+            ("A3b 075", 0) => Some(AttackId::A3b028EspeonEnergyCrush),
+            ("A3b 031", 0) => Some(AttackId::A3b031SwirlixSweetsRelay),
+            ("A3b 032", 0) => Some(AttackId::A3b032SlurpuffSweetsRelay),
+            ("A3b 033", 0) => Some(AttackId::A3b033SylveonEvoharmony),
+            ("A3b 076", 0) => Some(AttackId::A3b033SylveonEvoharmony),
+            ("A3b 035", 0) => Some(AttackId::A3b035MimikyuTryto),
+            ("A3b 036", 0) => Some(AttackId::A3b036MilcerySweetsRelay),
+            ("A3b 037", 0) => Some(AttackId::A3b037AlcremieSweetsOverload),
+            ("A3b 039", 0) => Some(AttackId::A3b039WhiscashEarthquake),
+            ("A3b 040", 0) => Some(AttackId::A3b040MienfooKickShot),
+            ("A3b 042", 0) => Some(AttackId::A3b042CarbinkHardRoll),
+            ("A3b 043", 0) => Some(AttackId::A3b043UmbreonarkBinding),
+            ("A3b 077", 0) => Some(AttackId::A3b043UmbreonarkBinding),
+            ("A3b 045", 0) => Some(AttackId::A3b045PurrloinPlayful),
+            ("A3b 047", 0) => Some(AttackId::A3b047MawilePowerfulVise),
+            ("A3b 048", 0) => Some(AttackId::A3b048TogedemaruBristlingSpikes),
+            ("A3b 053", 0) => Some(AttackId::A3b053DragoniteExGigaImpact),
+            ("A3b 082", 0) => Some(AttackId::A3b053DragoniteExGigaImpact),
+            ("A3b 090", 0) => Some(AttackId::A3b053DragoniteExGigaImpact),
+            ("A3b 054", 0) => Some(AttackId::A3b054DrampaDragonBreath),
+            ("A3b 055", 0) => Some(AttackId::A3b055EeveeUnknown),
+            ("A3b 078", 0) => Some(AttackId::A3b055EeveeUnknown),
+            ("A3b 057", 0) => Some(AttackId::A3b057SnorlaxExFlopDownPunch),
+            ("A3b 084", 0) => Some(AttackId::A3b057SnorlaxExFlopDownPunch),
+            ("A3b 091", 0) => Some(AttackId::A3b057SnorlaxExFlopDownPunch),
+            ("A3b 058", 0) => Some(AttackId::A3b058AipomDoubleHit),
+            ("A3b 060", 0) => Some(AttackId::A3b060ChatotToneDeaf),
+            ("A3b 061", 0) => Some(AttackId::A3b061AudinoDotheWave),
+            ("A3b 065", 0) => Some(AttackId::A3b065GreedentEnhancedFang),
+            ("P-A 014", 0) => Some(AttackId::PA014LaprasExBubbleDrain),
+            ("P-A 017", 0) => Some(AttackId::PA017MankeyRecklessCharge),
+            ("P-A 020", 0) => Some(AttackId::PA020HaunterSurpriseAttack),
+            ("P-A 022", 0) => Some(AttackId::PA022JigglypuffUnknown),
+            ("P-A 030", 0) => Some(AttackId::PA030EeveeUnknown),
+            ("P-A 038", 0) => Some(AttackId::PA038MisdreavusConfuse),
+            ("P-A 045", 0) => Some(AttackId::PA045NosepassIron),
+            ("P-A 053", 0) => Some(AttackId::PA053FloatzelAttacktheound),
+            ("P-A 056", 0) => Some(AttackId::PA056EkansPoison),
+            ("P-A 063", 0) => Some(AttackId::PA063RayquazaSpiralRush),
+            ("P-A 064", 0) => Some(AttackId::PA064RayquazaExDraco),
+            ("P-A 065", 0) => Some(AttackId::PA064RayquazaExDraco),
+            ("P-A 072", 0) => Some(AttackId::PA072AlolanGrimerPoison),
+            ("P-A 073", 0) => Some(AttackId::PA073ToucannonBeakBlast),
+            _ => None,
+        }
     }
 }
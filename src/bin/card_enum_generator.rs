@@ -1,29 +1,86 @@
-use std::{fs::File, io::Read};
+use std::{
+    fmt::Write as _,
+    fs,
+    io::Write as _,
+    process::{self, Command, Stdio},
+};
 
 use clap::Parser;
 
-use deckgym::types::{Ability, Attack, Card, EnergyType, TrainerCard};
+use deckgym::types::{Card, TrainerCard, TrainerType};
 use indexmap::IndexMap;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Flag to generate the database.rs instead. card_ids.rs is generated by default.
+    /// Flag to generate the attack_ids.rs instead. card_ids.rs is generated by default.
     #[arg(long)]
-    database: bool,
+    attacks: bool,
+
+    /// Flag to generate the ability_ids.rs instead.
+    #[arg(long)]
+    abilities: bool,
+
+    /// Flag to generate the tool_ids.rs instead.
+    #[arg(long)]
+    tools: bool,
+
+    /// Instead of printing, check that all the generated files in src/ are up-to-date with
+    /// database.json (after rustfmt). Exits with an error if any of them is stale.
+    #[arg(long)]
+    check: bool,
 }
 
-/// A CLI program to generate card_ids.rs and database.rs from the database.json file.
+// Appends a line to the generated code.
+macro_rules! emit {
+    ($out:expr) => {
+        writeln!($out).unwrap()
+    };
+    ($out:expr, $($arg:tt)*) => {
+        writeln!($out, $($arg)*).unwrap()
+    };
+}
+
+/// Cards (by enum name) plus the lookups that the generated files need.
+struct CardTables {
+    card_map: IndexMap<String, Card>,
+    id_to_enum: IndexMap<String, String>,
+    numeric_id_to_enum: IndexMap<u16, String>,
+}
+
+/// A CLI program to generate card_ids.rs, attack_ids.rs, ability_ids.rs and tool_ids.rs
+/// from the database.json file. The cards themselves are loaded from it at runtime.
 fn main() {
     let args = Args::parse();
 
     // Read the JSON file into Card structs
-    let mut file = File::open("./database.json").expect("database.json should be there");
-    let mut json_string = String::new();
-    file.read_to_string(&mut json_string)
-        .expect("Unable to read string");
+    let json_string = fs::read_to_string("./database.json").expect("database.json should be there");
     let deserialized_cards: Vec<Card> = serde_json::from_str(&json_string).unwrap();
+    let tables = build_tables(deserialized_cards);
 
+    if args.check {
+        check(&tables);
+        return;
+    }
+    let (_, code) = generated_files(&tables)
+        .into_iter()
+        .find(|(path, _)| {
+            let file = if args.attacks {
+                "attack_ids.rs"
+            } else if args.abilities {
+                "ability_ids.rs"
+            } else if args.tools {
+                "tool_ids.rs"
+            } else {
+                "card_ids.rs"
+            };
+            path.ends_with(file)
+        })
+        .expect("Should generate the requested file");
+    print!("{code}");
+}
+
+fn build_tables(deserialized_cards: Vec<Card>) -> CardTables {
     // Generate the Enum Names
     let mut card_map: IndexMap<String, Card> = IndexMap::new();
     let mut id_to_enum: IndexMap<String, String> = IndexMap::new();
@@ -49,185 +106,385 @@ fn main() {
             numeric_id_to_enum.insert(*numeric_id, enum_name.clone());
         }
     }
+    CardTables {
+        card_map,
+        id_to_enum,
+        numeric_id_to_enum,
+    }
+}
+
+/// All the generated files, as (path, unformatted code).
+fn generated_files(tables: &CardTables) -> Vec<(&'static str, String)> {
+    let mut card_ids = String::new();
+    write_card_ids(
+        &mut card_ids,
+        &tables.card_map,
+        &tables.id_to_enum,
+        &tables.numeric_id_to_enum,
+    );
+    let mut attack_ids = String::new();
+    write_attack_ids(&mut attack_ids, &tables.card_map);
+    let mut ability_ids = String::new();
+    write_ability_ids(&mut ability_ids, &tables.card_map);
+    let mut tool_ids = String::new();
+    write_tool_ids(&mut tool_ids, &tables.card_map);
+    vec![
+        ("src/card_ids.rs", card_ids),
+        ("src/attack_ids.rs", attack_ids),
+        ("src/ability_ids.rs", ability_ids),
+        ("src/tool_ids.rs", tool_ids),
+    ]
+}
 
-    if args.database {
-        print_database(&card_map);
+fn check(tables: &CardTables) {
+    let mut stale = vec![];
+    for (path, code) in generated_files(tables) {
+        let current = fs::read_to_string(path).unwrap_or_default();
+        if rustfmt(&code) != current {
+            stale.push(path);
+        }
+    }
+    if stale.is_empty() {
+        println!("Generated files are up-to-date with database.json");
     } else {
-        print_enums(&card_map, &id_to_enum, &numeric_id_to_enum);
+        eprintln!(
+            "Generated files are stale, re-generate them (see README.md): {}",
+            stale.join(", ")
+        );
+        process::exit(1);
     }
 }
 
-fn print_enums(
-    card_map: &IndexMap<String, Card>,
-    id_to_enum: &IndexMap<String, String>,
-    numeric_id_to_enum: &IndexMap<u16, String>,
-) {
-    println!("// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
-    println!();
-    println!("use serde::{{Deserialize, Serialize}};");
-    println!();
-    println!("#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]");
-    println!("pub enum CardId {{");
-    for (name, _) in card_map.iter() {
-        println!("    {name},");
-    }
-    println!("}}\n");
-    println!();
-    println!();
-    println!("impl CardId {{");
-    println!("    pub fn from_card_id(id: &str) -> Option<Self> {{");
-    println!("        match id {{");
-    for (id, enum_name) in id_to_enum.iter() {
-        println!("            \"{id}\" => Some(CardId::{enum_name}),");
-    }
-    println!("            _ => None,");
-    println!("        }}");
-    println!("    }}");
-    println!();
-    println!("    pub(crate) fn from_numeric_id(id: u16) -> Option<Self> {{");
-    println!("        match id {{");
-    for (numeric_id, enum_name) in numeric_id_to_enum.iter() {
-        println!("            {numeric_id} => Some(CardId::{enum_name}),");
-    }
-    println!("            _ => None,");
-    println!("        }}");
-    println!("    }}");
-    println!("}}");
+fn rustfmt(code: &str) -> String {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("rustfmt should be installed");
+    child
+        .stdin
+        .take()
+        .expect("Should have stdin")
+        .write_all(code.as_bytes())
+        .expect("Should write to rustfmt");
+    let output = child.wait_with_output().expect("rustfmt should run");
+    assert!(output.status.success(), "rustfmt failed on generated code");
+    String::from_utf8(output.stdout).expect("rustfmt output should be UTF-8")
 }
 
-fn print_database(card_map: &IndexMap<String, Card>) {
-    println!("// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
-    println!();
-    println!("use crate::{{");
-    println!("    card_ids::CardId,");
-    println!(
-        "    types::{{Ability, Attack, Card, EnergyType, PokemonCard, TrainerCard, TrainerType}},"
-    );
-    println!("}};");
-    println!();
-    println!("pub fn get_card_by_enum(id: CardId) -> Card {{");
-    println!("    match id {{");
-    for (enum_name, card) in card_map.iter() {
-        print_card(enum_name, card);
-    }
-    println!("    }}");
-    println!("}}");
+/// Turns a title (e.g. "K.O. Crab") into the end of an identifier (e.g. "KOCrab").
+fn to_identifier(title: &str) -> String {
+    title
+        .replace("♀", "F")
+        .replace("♂", "M")
+        .replace("é", "e")
+        .chars()
+        .filter(|x| x.is_ascii_alphanumeric())
+        .collect()
 }
 
-fn print_card(enum_name: &str, card: &Card) {
-    match card {
-        Card::Pokemon(pokemon_card) => {
-            println!("        CardId::{enum_name} => Card::Pokemon(PokemonCard {{");
-            println!("            id: \"{}\".to_string(),", pokemon_card.id);
-            println!("            name: \"{}\".to_string(),", pokemon_card.name);
-            println!("            stage: {},", pokemon_card.stage);
-            println!(
-                "            evolves_from: {},",
-                to_rust_string(&pokemon_card.evolves_from)
-            );
-            println!("            hp: {},", pokemon_card.hp);
-            println!(
-                "            energy_type: EnergyType::{},",
-                pokemon_card.energy_type
-            );
-            println!(
-                "            ability: {},",
-                to_rust_ability(&pokemon_card.ability)
-            );
-            print_attacks(&pokemon_card.attacks);
-            println!(
-                "            weakness: {},",
-                to_rust_energy(pokemon_card.weakness)
-            );
-            println!(
-                "            retreat_cost: {},",
-                to_rust_energy_vec(&pokemon_card.retreat_cost)
+/// Attacks with an effect, as (variant, [(pokemon id, attack index)]). Reprints of the
+/// same attack (same Pokémon name, title, cost, damage and effect) share the variant of
+/// the first card that has it, e.g. "A1003VenusaurMegaDrain".
+fn attack_variants(card_map: &IndexMap<String, Card>) -> IndexMap<String, Vec<(String, usize)>> {
+    let mut key_to_variant: IndexMap<String, String> = IndexMap::new();
+    let mut variants: IndexMap<String, Vec<(String, usize)>> = IndexMap::new();
+    for (enum_name, card) in card_map {
+        let Card::Pokemon(pokemon_card) = card else {
+            continue;
+        };
+        for (index, attack) in pokemon_card.attacks.iter().enumerate() {
+            let Some(effect) = &attack.effect else {
+                continue;
+            };
+            let key = format!(
+                "{}|{}|{:?}|{}|{}",
+                pokemon_card.name,
+                attack.title,
+                attack.energy_required,
+                attack.fixed_damage,
+                effect
             );
-            println!(
-                "            rarity: \"{}\".to_string(),",
-                pokemon_card.rarity
-            );
-            println!(
-                "            booster_pack: \"{}\".to_string(),",
-                pokemon_card.booster_pack
-            );
-            println!("        }}),");
-        }
-        Card::Trainer(trainer_card) => {
-            println!("        CardId::{enum_name} => Card::Trainer(TrainerCard {{");
-            println!("            id: \"{}\".to_string(),", trainer_card.id);
-            println!("            numeric_id: {},", trainer_card.numeric_id);
-            println!("            name: \"{}\".to_string(),", trainer_card.name);
-            println!(
-                "            effect: \"{}\".to_string(),",
-                trainer_card.effect
-            );
-            println!(
-                "            rarity: \"{}\".to_string(),",
-                trainer_card.rarity
-            );
-            println!(
-                "            booster_pack: \"{}\".to_string(),",
-                trainer_card.booster_pack
-            );
-            println!(
-                "            trainer_card_type: TrainerType::{:?},",
-                trainer_card.trainer_card_type
-            );
-            println!("        }}),");
+            let variant = key_to_variant
+                .entry(key)
+                .or_insert_with(|| format!("{enum_name}{}", to_identifier(&attack.title)));
+            variants
+                .entry(variant.clone())
+                .or_default()
+                .push((pokemon_card.id.clone(), index));
         }
     }
-    // TODO: AttackIds and AbilitiesIds
+    variants
 }
 
-fn print_attacks(attacks: &[Attack]) {
-    println!("            attacks: vec![");
-    for attack in attacks {
-        println!("                Attack {{");
-        println!(
-            "                    energy_required: {},",
-            to_rust_energy_vec(&attack.energy_required)
-        );
-        println!(
-            "                    title: \"{}\".to_string(),",
-            attack.title
-        );
-        println!("                    fixed_damage: {},", attack.fixed_damage);
-        println!(
-            "                    effect: {},",
-            to_rust_string(&attack.effect)
-        );
-        println!("                }},");
+/// Abilities, as (variant, [pokemon id]). Reprints share the variant of the first card.
+fn ability_variants(card_map: &IndexMap<String, Card>) -> IndexMap<String, Vec<String>> {
+    let mut key_to_variant: IndexMap<String, String> = IndexMap::new();
+    let mut variants: IndexMap<String, Vec<String>> = IndexMap::new();
+    for (enum_name, card) in card_map {
+        let Card::Pokemon(pokemon_card) = card else {
+            continue;
+        };
+        let Some(ability) = &pokemon_card.ability else {
+            continue;
+        };
+        let key = format!("{}|{}|{}", pokemon_card.name, ability.title, ability.effect);
+        let variant = key_to_variant
+            .entry(key)
+            .or_insert_with(|| enum_name.clone());
+        variants
+            .entry(variant.clone())
+            .or_default()
+            .push(pokemon_card.id.clone());
     }
-    println!("            ],");
+    variants
 }
 
-fn to_rust_string(string: &Option<String>) -> String {
-    match string {
-        Some(string) => format!("Some(\"{string}\".to_string())"),
-        None => "None".to_string(),
+fn write_attack_ids(out: &mut String, card_map: &IndexMap<String, Card>) {
+    let variants = attack_variants(card_map);
+    emit!(out, "// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
+    emit!(out);
+    emit!(
+        out,
+        "/// Attacks with an effect. Reprints share the variant of the first card with the attack,"
+    );
+    emit!(
+        out,
+        "/// named like its `CardId` followed by the attack title."
+    );
+    emit!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+    emit!(out, "pub enum AttackId {{");
+    for variant in variants.keys() {
+        emit!(out, "    {variant},");
     }
+    emit!(out, "}}");
+    emit!(out);
+    emit!(out, "impl AttackId {{");
+    emit!(
+        out,
+        "    /// None if the attack has no effect (it just does its fixed damage) or is not found."
+    );
+    emit!(
+        out,
+        "    pub fn from_pokemon_index(pokemon_id: &str, index: usize) -> Option<Self> {{"
+    );
+    emit!(out, "        match (pokemon_id, index) {{");
+    for (variant, keys) in variants.iter() {
+        for (pokemon_id, index) in keys {
+            emit!(
+                out,
+                "            (\"{pokemon_id}\", {index}) => Some(AttackId::{variant}),"
+            );
+        }
+    }
+    emit!(out, "            _ => None,");
+    emit!(out, "        }}");
+    emit!(out, "    }}");
+    emit!(out, "}}");
 }
-fn to_rust_ability(ability: &Option<Ability>) -> String {
-    match ability {
-        Some(ability) => format!(
-            "Some(Ability {{ title: \"{}\".to_string(), effect: \"{}\".to_string() }})",
-            ability.title, ability.effect
-        ),
-        None => "None".to_string(),
+
+fn write_ability_ids(out: &mut String, card_map: &IndexMap<String, Card>) {
+    let variants = ability_variants(card_map);
+    emit!(out, "// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
+    emit!(out);
+    emit!(
+        out,
+        "/// Pokémon abilities. Reprints share the variant of the first card with the ability,"
+    );
+    emit!(out, "/// named like its `CardId`.");
+    emit!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+    emit!(out, "pub enum AbilityId {{");
+    for variant in variants.keys() {
+        emit!(out, "    {variant},");
     }
+    emit!(out, "}}");
+    emit!(out);
+    emit!(out, "impl AbilityId {{");
+    emit!(
+        out,
+        "    /// None if the Pokémon has no ability or is not found."
+    );
+    emit!(
+        out,
+        "    pub fn from_pokemon_id(pokemon_id: &str) -> Option<Self> {{"
+    );
+    emit!(out, "        match pokemon_id {{");
+    for (variant, pokemon_ids) in variants.iter() {
+        for pokemon_id in pokemon_ids {
+            emit!(
+                out,
+                "            \"{pokemon_id}\" => Some(AbilityId::{variant}),"
+            );
+        }
+    }
+    emit!(out, "            _ => None,");
+    emit!(out, "        }}");
+    emit!(out, "    }}");
+    emit!(out, "}}");
 }
-fn to_rust_energy(energy_type: Option<EnergyType>) -> String {
-    match energy_type {
-        Some(energy_type) => format!("Some(EnergyType::{energy_type})"),
-        None => "None".to_string(),
+
+fn write_card_ids(
+    out: &mut String,
+    card_map: &IndexMap<String, Card>,
+    id_to_enum: &IndexMap<String, String>,
+    numeric_id_to_enum: &IndexMap<u16, String>,
+) {
+    emit!(out, "// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
+    emit!(out);
+    emit!(out, "use serde::{{Deserialize, Serialize}};");
+    emit!(out);
+    emit!(
+        out,
+        "#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]"
+    );
+    emit!(out, "pub enum CardId {{");
+    for (name, _) in card_map.iter() {
+        emit!(out, "    {name},");
     }
+    emit!(out, "}}\n");
+    emit!(out);
+    emit!(out);
+    emit!(out, "impl CardId {{");
+    emit!(
+        out,
+        "    /// Every card in the database, in database order."
+    );
+    emit!(out, "    pub const ALL: &[CardId] = &[");
+    for (name, _) in card_map.iter() {
+        emit!(out, "        CardId::{name},");
+    }
+    emit!(out, "    ];");
+    emit!(out);
+    // Binary search over the ids packed into integers, which is much faster than a string
+    // match with this many arms. Packing keeps the order of the ids.
+    let mut sorted_ids: Vec<(&String, &String)> = id_to_enum.iter().collect();
+    sorted_ids.sort();
+    assert!(
+        sorted_ids.iter().all(|(id, _)| id.len() <= 8),
+        "Card ids should fit in a u64"
+    );
+    emit!(
+        out,
+        "    /// Card ids (as `id_key`s) sorted, for `from_card_id`."
+    );
+    emit!(out, "    const BY_CARD_ID: &[(u64, CardId)] = &[");
+    for (id, enum_name) in sorted_ids {
+        emit!(out, "        (id_key(\"{id}\"), CardId::{enum_name}),");
+    }
+    emit!(out, "    ];");
+    emit!(out);
+    emit!(out, "    pub fn from_card_id(id: &str) -> Option<Self> {{");
+    emit!(out, "        if id.len() > 8 {{");
+    emit!(out, "            return None;");
+    emit!(out, "        }}");
+    emit!(out, "        let key = id_key(id);");
+    emit!(out, "        Self::BY_CARD_ID");
+    emit!(
+        out,
+        "            .binary_search_by_key(&key, |(card_key, _)| *card_key)"
+    );
+    emit!(out, "            .ok()");
+    emit!(out, "            .map(|index| Self::BY_CARD_ID[index].1)");
+    emit!(out, "    }}");
+    emit!(out);
+    emit!(
+        out,
+        "    pub(crate) fn from_numeric_id(id: u16) -> Option<Self> {{"
+    );
+    emit!(out, "        match id {{");
+    for (numeric_id, enum_name) in numeric_id_to_enum.iter() {
+        emit!(
+            out,
+            "            {numeric_id} => Some(CardId::{enum_name}),"
+        );
+    }
+    emit!(out, "            _ => None,");
+    emit!(out, "        }}");
+    emit!(out, "    }}");
+    emit!(out, "}}");
+    emit!(out);
+    emit!(
+        out,
+        "/// The (up to 8) bytes of a card id as a big-endian integer, so that keys sort like ids."
+    );
+    emit!(out, "const fn id_key(id: &str) -> u64 {{");
+    emit!(out, "    let bytes = id.as_bytes();");
+    emit!(out, "    let mut key = 0;");
+    emit!(out, "    let mut i = 0;");
+    emit!(out, "    while i < 8 {{");
+    emit!(out, "        key <<= 8;");
+    emit!(out, "        if i < bytes.len() {{");
+    emit!(out, "            key |= bytes[i] as u64;");
+    emit!(out, "        }}");
+    emit!(out, "        i += 1;");
+    emit!(out, "    }}");
+    emit!(out, "    key");
+    emit!(out, "}}");
 }
-fn to_rust_energy_vec(energy_types: &Vec<EnergyType>) -> String {
-    let mut result = "vec![".to_string();
-    for energy_type in energy_types {
-        result.push_str(&format!("EnergyType::{energy_type},"));
+
+fn write_tool_ids(out: &mut String, card_map: &IndexMap<String, Card>) {
+    let tools: Vec<(&String, &TrainerCard)> = card_map
+        .iter()
+        .filter_map(|(name, card)| match card {
+            Card::Trainer(trainer_card) if trainer_card.trainer_card_type == TrainerType::Tool => {
+                Some((name, trainer_card))
+            }
+            _ => None,
+        })
+        .collect();
+
+    emit!(out, "// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
+    emit!(out);
+    emit!(out, "use serde::{{Deserialize, Serialize}};");
+    emit!(out);
+    emit!(out, "use crate::{{card_ids::CardId, types::TrainerCard}};");
+    emit!(out);
+    emit!(
+        out,
+        "/// Pokémon Tools in the database. Variants are named like their `CardId`."
+    );
+    emit!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]"
+    );
+    emit!(out, "pub enum ToolId {{");
+    for (name, _) in tools.iter() {
+        emit!(out, "    {name},");
+    }
+    emit!(out, "}}");
+    emit!(out);
+    emit!(out, "impl ToolId {{");
+    emit!(
+        out,
+        "    pub fn from_trainer_card(trainer_card: &TrainerCard) -> Option<Self> {{"
+    );
+    emit!(out, "        Self::from_card_id(&trainer_card.id)");
+    emit!(out, "    }}");
+    emit!(out);
+    emit!(out, "    pub fn from_card_id(id: &str) -> Option<Self> {{");
+    emit!(out, "        match id {{");
+    for (name, trainer_card) in tools.iter() {
+        emit!(
+            out,
+            "            \"{}\" => Some(ToolId::{name}),",
+            trainer_card.id
+        );
+    }
+    emit!(out, "            _ => None,");
+    emit!(out, "        }}");
+    emit!(out, "    }}");
+    emit!(out);
+    emit!(
+        out,
+        "    /// The trainer card of this tool (e.g. to discard it)."
+    );
+    emit!(out, "    pub fn card_id(&self) -> CardId {{");
+    emit!(out, "        match self {{");
+    for (name, _) in tools.iter() {
+        emit!(out, "            ToolId::{name} => CardId::{name},");
     }
-    result.push(']');
-    result
+    emit!(out, "        }}");
+    emit!(out, "    }}");
+    emit!(out, "}}");
 }
@@ -0,0 +1,266 @@
+use indexmap::IndexMap;
+use log::warn;
+use serde::Serialize;
+
+use crate::{
+    ability_ids::AbilityId,
+    actions::{forecast_attack, forecast_trainer_action},
+    database::card_database,
+    hooks::{ability_trigger, to_playable_card},
+    types::{Card, CardRef},
+    Deck, State,
+};
+
+/// How the engine resolves an attack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AttackStatus {
+    /// The attack has no effect text, so dealing `fixed_damage` is all there is to it.
+    FixedDamage,
+    /// The attack's `AttackId` has its own behavior.
+    Implemented,
+    /// The attack has effect text but its `AttackId` has no behavior yet.
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AttackCoverage {
+    pub title: String,
+    pub status: AttackStatus,
+}
+
+/// Implementation status of a single card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardCoverage {
+    pub id: String,
+    pub name: String,
+    pub set: String,
+    pub attacks: Vec<AttackCoverage>,
+    /// `None` if the card has no ability, otherwise whether its `AbilityId` is implemented.
+    pub ability: Option<bool>,
+    /// `None` for Pokémon, otherwise whether `forecast_trainer_action` handles the card.
+    pub trainer: Option<bool>,
+}
+
+impl CardCoverage {
+    pub fn is_implemented(&self) -> bool {
+        self.attacks
+            .iter()
+            .all(|x| x.status != AttackStatus::Missing)
+            && self.ability != Some(false)
+            && self.trainer != Some(false)
+    }
+
+    /// Describes what is missing, e.g. "A1a 064 Pokémon Flute: trainer".
+    pub fn describe_missing(&self) -> String {
+        let mut missing: Vec<String> = self
+            .attacks
+            .iter()
+            .filter(|x| x.status == AttackStatus::Missing)
+            .map(|x| format!("attack {}", x.title))
+            .collect();
+        if self.ability == Some(false) {
+            missing.push("ability".to_string());
+        }
+        if self.trainer == Some(false) {
+            missing.push("trainer".to_string());
+        }
+        format!("{} {}: {}", self.id, self.name, missing.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SetCoverage {
+    pub set: String,
+    pub implemented: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CoverageReport {
+    pub implemented: usize,
+    pub total: usize,
+    /// Totals per set (booster pack), in database order.
+    pub sets: Vec<SetCoverage>,
+    pub cards: Vec<CardCoverage>,
+}
+
+impl CoverageReport {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|err| format!("Failed to serialize: {err}"))
+    }
+}
+
+pub fn card_coverage(card: &Card) -> CardCoverage {
+    match card {
+        Card::Pokemon(pokemon_card) => {
+            // Both players have the card in the Active Spot, so that attacks can be forecasted.
+            let mut state = State::default();
+            state.in_play_pokemon[0][0] = Some(to_playable_card(card, false));
+            state.in_play_pokemon[1][0] = Some(to_playable_card(card, false));
+            let attacks = pokemon_card
+                .attacks
+                .iter()
+                .enumerate()
+                .map(|(index, attack)| {
+                    let status = if attack.effect.is_none() {
+                        AttackStatus::FixedDamage
+                    } else if forecast_attack(0, &state, index).is_ok() {
+                        AttackStatus::Implemented
+                    } else {
+                        AttackStatus::Missing
+                    };
+                    AttackCoverage {
+                        title: attack.title.clone(),
+                        status,
+                    }
+                })
+                .collect();
+            let ability = pokemon_card.ability.as_ref().map(|_| {
+                AbilityId::from_pokemon_id(&pokemon_card.id)
+                    .and_then(ability_trigger)
+                    .is_some()
+            });
+            CardCoverage {
+                id: pokemon_card.id.clone(),
+                name: pokemon_card.name.clone(),
+                set: pokemon_card.booster_pack.clone(),
+                attacks,
+                ability,
+                trainer: None,
+            }
+        }
+        Card::Trainer(trainer_card) => {
+            let implemented = forecast_trainer_action(0, &State::default(), trainer_card).is_ok();
+            CardCoverage {
+                id: trainer_card.id.clone(),
+                name: trainer_card.name.clone(),
+                set: trainer_card.booster_pack.clone(),
+                attacks: vec![],
+                ability: None,
+                trainer: Some(implemented),
+            }
+        }
+    }
+}
+
+/// Returns the cards of `deck` (once each) that the engine can't fully play.
+pub fn unimplemented_cards(deck: &Deck) -> Vec<CardCoverage> {
+    let mut cards: Vec<&CardRef> = vec![];
+    for card in &deck.cards {
+        if !cards.contains(&card) {
+            cards.push(card);
+        }
+    }
+    cards
+        .into_iter()
+        .map(|card| card_coverage(card))
+        .filter(|x| !x.is_implemented())
+        .collect()
+}
+
+/// Pre-flight check of a deck before simulating it. Fails listing every card that is not
+/// implemented, unless `allow_partial` is set (then they are only logged as warnings).
+pub fn check_deck(deck: &Deck, deck_name: &str, allow_partial: bool) -> Result<(), String> {
+    let unimplemented = unimplemented_cards(deck);
+    if unimplemented.is_empty() {
+        return Ok(());
+    }
+    let lines = unimplemented
+        .iter()
+        .map(|x| format!("  {}", x.describe_missing()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if allow_partial {
+        warn!("Deck {deck_name} has unimplemented cards (playing them partially):\n{lines}");
+        Ok(())
+    } else {
+        Err(format!(
+            "Deck {deck_name} has unimplemented cards (use --allow-partial to play anyway):\n{lines}"
+        ))
+    }
+}
+
+/// Reports which cards in the card database (including overlays) the engine can fully play.
+pub fn coverage_report() -> CoverageReport {
+    let cards: Vec<CardCoverage> = card_database().cards().map(card_coverage).collect();
+    let mut sets: IndexMap<String, SetCoverage> = IndexMap::new();
+    for card in &cards {
+        let set = sets.entry(card.set.clone()).or_insert(SetCoverage {
+            set: card.set.clone(),
+            implemented: 0,
+            total: 0,
+        });
+        set.total += 1;
+        if card.is_implemented() {
+            set.implemented += 1;
+        }
+    }
+    CoverageReport {
+        implemented: cards.iter().filter(|x| x.is_implemented()).count(),
+        total: cards.len(),
+        sets: sets.into_values().collect(),
+        cards,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{card_ids::CardId, database::get_card_by_enum};
+
+    use super::*;
+
+    #[test]
+    fn test_card_coverage() {
+        let bulbasaur = card_coverage(&get_card_by_enum(CardId::A1001Bulbasaur));
+        assert_eq!(bulbasaur.attacks[0].status, AttackStatus::FixedDamage);
+        assert!(bulbasaur.is_implemented());
+
+        let venusaur = card_coverage(&get_card_by_enum(CardId::A1003Venusaur));
+        assert_eq!(venusaur.attacks[0].status, AttackStatus::Implemented);
+        // Reprints share the attack of the first print
+        let jynx = card_coverage(&get_card_by_enum(CardId::A3a094Jynx));
+        assert_eq!(jynx.attacks[0].status, AttackStatus::Implemented);
+        let absol = card_coverage(&get_card_by_enum(CardId::A3112Absol));
+        assert_eq!(absol.attacks[0].status, AttackStatus::Missing);
+
+        let butterfree = card_coverage(&get_card_by_enum(CardId::A1007Butterfree));
+        assert_eq!(butterfree.ability, Some(true));
+
+        let potion = card_coverage(&get_card_by_enum(CardId::PA001Potion));
+        assert_eq!(potion.trainer, Some(true));
+        let flute = card_coverage(&get_card_by_enum(CardId::A1a064PokemonFlute));
+        assert_eq!(flute.trainer, Some(false));
+        assert!(!flute.is_implemented());
+    }
+
+    #[test]
+    fn test_coverage_report_totals() {
+        let report = coverage_report();
+        assert_eq!(report.total, card_database().len());
+        assert_eq!(report.sets[0].set, "Genetic Apex (A1)");
+        assert_eq!(
+            report.sets.iter().map(|x| x.total).sum::<usize>(),
+            report.total
+        );
+        assert_eq!(
+            report.sets.iter().map(|x| x.implemented).sum::<usize>(),
+            report.implemented
+        );
+    }
+
+    #[test]
+    fn test_check_deck() {
+        let deck = Deck::from_string("2 Bulbasaur A1 001\n2 Caterpie A1 005").unwrap();
+
+        let unimplemented = unimplemented_cards(&deck);
+        assert_eq!(unimplemented.len(), 1);
+        assert_eq!(
+            unimplemented[0].describe_missing(),
+            "A1 005 Caterpie: attack Find a"
+        );
+        assert!(check_deck(&deck, "test", false)
+            .unwrap_err()
+            .contains("Caterpie"));
+        assert!(check_deck(&deck, "test", true).is_ok());
+    }
+}
@@ -1,18 +1,113 @@
+use log::warn;
 use rand::seq::SliceRandom;
 use rand::Rng;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 use std::fs;
 use std::hash::{Hash, Hasher};
 
-use crate::card_ids::CardId;
-use crate::database::get_card_by_enum;
-use crate::types::{Card, EnergyType};
+use crate::database::card_database;
+use crate::types::{Card, CardRef, EnergyType};
 
 /// Represents a deck of cards.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Deck {
-    pub cards: Vec<Card>,
+    pub cards: Vec<CardRef>,
     pub(crate) energy_types: Vec<EnergyType>,
+    // Energy: lines that didn't parse, kept so that `validate` can report them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) unknown_energy_types: Vec<String>,
+}
+
+/// A problem that makes a deck illegal (see `is_error`) or likely a mistake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckIssue {
+    WrongCardCount(usize),
+    NoBasicPokemon,
+    /// The only Basics are fossils, which can't be the starting Active Pokémon.
+    FossilOnly,
+    /// More than 2 copies of cards with the same name (across different prints).
+    TooManyCopies {
+        name: String,
+        count: usize,
+    },
+    UnknownEnergyType(String),
+    /// An Energy line that no Pokémon in the deck is or uses.
+    UnusedEnergyType(EnergyType),
+    /// An evolution without a Basic (or fossil) of its line in the deck.
+    OrphanEvolution(String),
+}
+
+/// The DeckGym "Copy as Text" layout: Energy lines (in the declared order), then the
+/// Pokémon and Trainer sections with copies of each card grouped on a single line.
+impl fmt::Display for Deck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for energy in &self.energy_types {
+            writeln!(f, "Energy: {energy:?}")?;
+        }
+        for energy in &self.unknown_energy_types {
+            writeln!(f, "Energy: {energy}")?;
+        }
+
+        let mut pokemon: Vec<(&CardRef, usize)> = vec![];
+        let mut trainers: Vec<(&CardRef, usize)> = vec![];
+        for card in &self.cards {
+            let section = match &**card {
+                Card::Pokemon(_) => &mut pokemon,
+                Card::Trainer(_) => &mut trainers,
+            };
+            match section.iter_mut().find(|(x, _)| *x == card) {
+                Some((_, count)) => *count += 1,
+                None => section.push((card, 1)),
+            }
+        }
+
+        let sections = [("Pokémon", pokemon), ("Trainer", trainers)];
+        let mut first = true;
+        for (title, cards) in sections.iter().filter(|(_, x)| !x.is_empty()) {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            let total: usize = cards.iter().map(|(_, count)| count).sum();
+            writeln!(f, "{title}: {total}")?;
+            for (card, count) in cards {
+                writeln!(f, "{} {} {}", count, card.get_name(), card.get_id())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DeckIssue {
+    /// Whether the deck can't be played at all (the rest are only warnings).
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self,
+            DeckIssue::UnusedEnergyType(_) | DeckIssue::OrphanEvolution(_)
+        )
+    }
+}
+
+impl fmt::Display for DeckIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckIssue::WrongCardCount(count) => write!(f, "Deck has {count} cards instead of 20"),
+            DeckIssue::NoBasicPokemon => write!(f, "Deck has no Basic Pokémon"),
+            DeckIssue::FossilOnly => write!(f, "Deck has only fossils as Basics"),
+            DeckIssue::TooManyCopies { name, count } => {
+                write!(f, "Deck has {count} copies of {name} (max 2)")
+            }
+            DeckIssue::UnknownEnergyType(energy) => write!(f, "Unknown energy type: {energy}"),
+            DeckIssue::UnusedEnergyType(energy) => {
+                write!(f, "No Pokémon in the deck is or uses {energy:?} energy")
+            }
+            DeckIssue::OrphanEvolution(name) => {
+                write!(f, "{name} has no Basic of its evolution line in the deck")
+            }
+        }
+    }
 }
 
 impl Hash for Deck {
@@ -34,8 +129,37 @@ impl Deck {
         Self::from_string(&contents)
     }
 
+    /// Writes the deck in the DeckGym "Copy as Text" format (see `Display`), which
+    /// `from_file` reads back.
+    pub fn to_file(&self, file_path: &str) -> Result<(), String> {
+        fs::write(file_path, self.to_string())
+            .map_err(|err| format!("Failed to write file {file_path}: {err}"))
+    }
+
+    /// Like `from_file`, but fails if the deck isn't legal (see `validate`).
+    pub fn from_legal_file(file_path: &str) -> Result<Self, String> {
+        let deck = Self::from_file(file_path)?;
+        let errors = deck
+            .validate()
+            .into_iter()
+            .filter(DeckIssue::is_error)
+            .map(|x| format!("  {x}"))
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(deck)
+        } else {
+            Err(format!(
+                "Deck {file_path} is not legal:\n{}",
+                errors.join("\n")
+            ))
+        }
+    }
+
     pub fn from_string(contents: &str) -> Result<Self, String> {
-        let mut energy_types = HashSet::new();
+        // Energy types keep the order of the Energy: lines (so that seeded Energy Zone rolls
+        // are reproducible and the deck can be written back as it was).
+        let mut energy_types: Vec<EnergyType> = Vec::new();
+        let mut unknown_energy_types = Vec::new();
         let mut cards = Vec::new();
         for line in contents.lines() {
             // if line is empty or starts with "Pokemon:" or "Trainer:, skip it
@@ -51,28 +175,47 @@ impl Deck {
                     .split_whitespace()
                     .last()
                     .expect("Energy: line should have an energy type");
-                energy_types
-                    .insert(EnergyType::from_str(energy_type).expect("Invalid energy type"));
+                match EnergyType::from_str(energy_type) {
+                    Some(energy) => {
+                        if !energy_types.contains(&energy) {
+                            energy_types.push(energy);
+                        }
+                    }
+                    None => unknown_energy_types.push(energy_type.to_string()),
+                }
                 continue;
             }
 
             let (count, card) = Card::from_str_with_count(trimmed)?;
-            cards.extend(vec![card; count as usize]);
+            cards.extend(vec![CardRef::from(card); count as usize]);
         }
 
         // If empty energy types set, populate it with the energy types from the cards
+        // (but not Colorless or Dragon, which the Energy Zone can't generate).
         if energy_types.is_empty() {
+            let mut from_cards = BTreeSet::new();
             cards.iter().for_each(|x| {
-                if let Card::Pokemon(pokemon_card) = x {
-                    energy_types.insert(pokemon_card.energy_type);
+                if let Card::Pokemon(pokemon_card) = &**x {
+                    if !matches!(
+                        pokemon_card.energy_type,
+                        EnergyType::Colorless | EnergyType::Dragon
+                    ) {
+                        from_cards.insert(pokemon_card.energy_type);
+                    }
                 }
             });
+            energy_types = from_cards.into_iter().collect();
         }
 
-        Ok(Self {
+        let deck = Self {
             cards,
-            energy_types: energy_types.into_iter().collect(),
-        })
+            energy_types,
+            unknown_energy_types,
+        };
+        for issue in deck.validate() {
+            warn!("{issue}");
+        }
+        Ok(deck)
     }
 
     pub fn is_valid(&self) -> bool {
@@ -80,9 +223,88 @@ impl Deck {
         self.cards.len() == 20 && basic >= 1
     }
 
+    /// Checks the deck building rules. Returns every issue found (empty if legal).
+    pub fn validate(&self) -> Vec<DeckIssue> {
+        let mut issues = vec![];
+        if self.cards.len() != 20 {
+            issues.push(DeckIssue::WrongCardCount(self.cards.len()));
+        }
+        if !self.cards.iter().any(|x| x.is_basic()) {
+            if self.cards.iter().any(|card| is_fossil(card)) {
+                issues.push(DeckIssue::FossilOnly);
+            } else {
+                issues.push(DeckIssue::NoBasicPokemon);
+            }
+        }
+
+        // The copy limit is by name, e.g. A1 223 and A1 270 Giovanni count together.
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for card in &self.cards {
+            *counts.entry(card.get_name()).or_default() += 1;
+        }
+        for (name, count) in counts {
+            if count > 2 {
+                issues.push(DeckIssue::TooManyCopies { name, count });
+            }
+        }
+
+        // Colorless and Dragon are never generated by the Energy Zone.
+        for energy in &self.unknown_energy_types {
+            issues.push(DeckIssue::UnknownEnergyType(energy.clone()));
+        }
+        for energy in &self.energy_types {
+            if matches!(energy, EnergyType::Colorless | EnergyType::Dragon) {
+                issues.push(DeckIssue::UnknownEnergyType(format!("{energy:?}")));
+                continue;
+            }
+            let is_used = self.cards.iter().any(|card| match &**card {
+                Card::Pokemon(pokemon_card) => {
+                    pokemon_card.energy_type == *energy
+                        || pokemon_card
+                            .attacks
+                            .iter()
+                            .any(|x| x.energy_required.contains(energy))
+                }
+                Card::Trainer(_) => false,
+            });
+            if !is_used {
+                issues.push(DeckIssue::UnusedEnergyType(*energy));
+            }
+        }
+
+        let mut orphans = BTreeSet::new();
+        for card in &self.cards {
+            if let Card::Pokemon(pokemon_card) = &**card {
+                if pokemon_card.stage > 0 && !self.has_basic_of_line(pokemon_card.name.as_str()) {
+                    orphans.insert(pokemon_card.name.clone());
+                }
+            }
+        }
+        issues.extend(orphans.into_iter().map(DeckIssue::OrphanEvolution));
+        issues
+    }
+
+    // Walks down the evolution line of `name` (using the database for stages missing in
+    // the deck) looking for a Basic or fossil in the deck.
+    fn has_basic_of_line(&self, name: &str) -> bool {
+        let mut name = name.to_string();
+        loop {
+            let in_deck = self.cards.iter().find(|x| x.get_name() == name);
+            if let Some(card) = in_deck {
+                if card.is_basic() || is_fossil(card) {
+                    return true;
+                }
+            }
+            match evolves_from(&name) {
+                Some(previous) => name = previous,
+                None => return false,
+            }
+        }
+    }
+
     /// Draws a card from the deck.
-    /// Returns `Some(Card)` if the deck is not empty, otherwise returns `None`.
-    pub fn draw(&mut self) -> Option<Card> {
+    /// Returns `Some(CardRef)` if the deck is not empty, otherwise returns `None`.
+    pub fn draw(&mut self) -> Option<CardRef> {
         if self.cards.is_empty() {
             None
         } else {
@@ -95,11 +317,11 @@ impl Deck {
         if initial_shuffle {
             // Ensure there is at least 1 basic pokemon in the initial 5 cards
             let (mut matching, mut non_matching): (Vec<_>, Vec<_>) =
-                self.cards.clone().into_iter().partition(is_basic);
+                self.cards.iter().partition(|card| card.is_basic());
             matching.shuffle(rng);
             non_matching.shuffle(rng);
 
-            let shuffled_cards: Vec<Card> =
+            let shuffled_cards: Vec<CardRef> =
                 vec![matching.pop().expect("Decks must have at least 1 basic")];
 
             let mut remaining = [matching, non_matching].concat();
@@ -129,9 +351,9 @@ impl Card {
         let padded_number = format!("{number:0>3}");
         let id = format!("{set} {padded_number}");
 
-        let card_id =
-            CardId::from_card_id(&id).ok_or_else(|| format!("Card ID not found for id: {id}"))?;
-        let card = get_card_by_enum(card_id);
+        let card = card_database()
+            .get(&id)
+            .ok_or_else(|| format!("Card ID not found for id: {id}"))?;
 
         Ok((count, card.clone()))
     }
@@ -141,6 +363,19 @@ pub fn is_basic(card: &Card) -> bool {
     card.is_basic()
 }
 
+fn is_fossil(card: &Card) -> bool {
+    matches!(card, Card::Trainer(trainer_card) if trainer_card.name.ends_with("Fossil") || trainer_card.name == "Old Amber")
+}
+
+fn evolves_from(name: &str) -> Option<String> {
+    card_database().cards().find_map(|card| match card {
+        Card::Pokemon(pokemon_card) if pokemon_card.name == name => {
+            pokemon_card.evolves_from.clone()
+        }
+        _ => None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use rand::thread_rng;
@@ -157,7 +392,7 @@ mod tests {
 
         // Example assertion: Check if the first card is Bulbasaur
         let first_card = &deck.cards[0];
-        if let Card::Pokemon(card) = first_card {
+        if let Card::Pokemon(card) = &**first_card {
             assert_eq!(card.name, "Bulbasaur");
         } else {
             panic!("Expected first card to be Bulbasaur");
@@ -190,7 +425,7 @@ mod tests {
         // Ensure there is at least 1 basic pokemon in the initial 5 cards
         let initial_five_cards = &deck.cards[..5];
         assert!(
-            initial_five_cards.iter().any(is_basic),
+            initial_five_cards.iter().any(|card| card.is_basic()),
             "There should be at least 1 basic pokemon in the initial 5 cards"
         );
     }
@@ -257,4 +492,71 @@ Trainer: 12
         let deck = Deck::from_string(string).expect("Failed to parse deck from string");
         assert_eq!(deck.cards.len(), 20);
     }
+
+    #[test]
+    fn test_validate_legal_deck() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        assert_eq!(deck.validate(), vec![]);
+    }
+
+    #[test]
+    fn test_validate_copy_limit_is_by_name() {
+        let string = r#"Energy: Grass
+2 Bulbasaur A1 001
+2 Giovanni A1 223
+1 Giovanni A1 270"#;
+        let deck = Deck::from_string(string).unwrap();
+        assert_eq!(
+            deck.validate(),
+            vec![
+                DeckIssue::WrongCardCount(5),
+                DeckIssue::TooManyCopies {
+                    name: "Giovanni".to_string(),
+                    count: 3
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_energy_and_evolutions() {
+        let string = r#"Energy: Fire
+Energy: Sparkly
+2 Bulbasaur A1 001
+2 Venusaur A1 003
+2 Omastar A1 082
+2 Helix Fossil A1 216"#;
+        let deck = Deck::from_string(string).unwrap();
+        let issues = deck.validate();
+        assert!(issues.contains(&DeckIssue::UnknownEnergyType("Sparkly".to_string())));
+        assert!(issues.contains(&DeckIssue::UnusedEnergyType(EnergyType::Fire)));
+        // Ivysaur is missing, but the line still has its Basic (and Omastar its fossil).
+        assert!(!issues.contains(&DeckIssue::OrphanEvolution("Venusaur".to_string())));
+        assert!(!issues.contains(&DeckIssue::OrphanEvolution("Omastar".to_string())));
+
+        let deck = Deck::from_string("2 Ivysaur A1 002\n2 Helix Fossil A1 216").unwrap();
+        let issues = deck.validate();
+        assert!(issues.contains(&DeckIssue::FossilOnly));
+        assert!(issues.contains(&DeckIssue::OrphanEvolution("Ivysaur".to_string())));
+    }
+
+    #[test]
+    fn test_to_string_round_trip() {
+        let string = r#"Energy: Fire
+Energy: Grass
+Pokémon: 4
+2 Bulbasaur A1 001
+2 Charmander A1 033
+
+Trainer: 3
+2 Giovanni A1 223
+1 Poké Ball P-A 005
+"#;
+        let deck = Deck::from_string(string).unwrap();
+        assert_eq!(deck.energy_types, vec![EnergyType::Fire, EnergyType::Grass]);
+        assert_eq!(deck.to_string(), string);
+
+        let file = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        assert_eq!(Deck::from_string(&file.to_string()).unwrap(), file);
+    }
 }
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{card_ids::CardId, types::Card};
+
+/// What an effect does while it is active.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EffectKind {
+    /// Attacks do +N damage to the opponent's Active Pokémon (e.g. Giovanni).
+    AttackDamageBonus(u32),
+    /// Retreat Cost is N Colorless less (e.g. X Speed, Leaf).
+    RetreatCostReduction(usize),
+    /// The Pokémon can't retreat (e.g. Arbok's Corner).
+    CantRetreat,
+    /// Supporter cards can't be played (e.g. Psyduck's Headache).
+    CantPlaySupporter,
+}
+
+/// Who an effect applies to.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EffectTarget {
+    /// The given player (and all of their Pokémon).
+    Player(usize),
+    /// The given player's Active Pokémon. The effect is removed when that Pokémon leaves
+    /// the Active Spot (retreat, switch or K.O.) or evolves.
+    Active(usize),
+}
+
+impl EffectTarget {
+    pub fn player(&self) -> usize {
+        match self {
+            EffectTarget::Player(player) | EffectTarget::Active(player) => *player,
+        }
+    }
+}
+
+/// An effect created by a card (trainer or attack) that lasts for some turns.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Effect {
+    pub kind: EffectKind,
+    /// The card that created the effect.
+    pub source: CardId,
+    /// The player that played the source card.
+    pub owner: usize,
+    pub target: EffectTarget,
+    /// Last (global) turn in which the effect is active. The effect is dropped when it ends.
+    pub expires_after_turn: u8,
+}
+
+impl Effect {
+    pub fn is_active(&self, turn_count: u8) -> bool {
+        turn_count <= self.expires_after_turn
+    }
+}
+
+/// Converts the `turn_effects` of a version 1 state snapshot (effect cards keyed by turn) into
+/// typed effects. Owners are inferred: trainers only last for the turn they were played, and
+/// attack effects that aren't scheduled for the next turn were created by the previous player.
+pub(crate) fn migrate_turn_effects(
+    turn_effects: &BTreeMap<u8, Vec<Card>>,
+    turn_count: u8,
+    current_player: usize,
+) -> Vec<Effect> {
+    let this_turn = turn_effects.get(&turn_count).cloned().unwrap_or_default();
+    let next_turn = turn_effects
+        .get(&(turn_count + 1))
+        .cloned()
+        .unwrap_or_default();
+    let opponent = (current_player + 1) % 2;
+    let mut effects = vec![];
+    for card in this_turn {
+        let source = card.get_card_id();
+        let (owner, expires_after_turn) = if next_turn.contains(&card) {
+            (current_player, turn_count + 1)
+        } else if matches!(card, Card::Pokemon(_)) {
+            (opponent, turn_count)
+        } else {
+            (current_player, turn_count)
+        };
+        let target_player = (owner + 1) % 2;
+        let (kind, target) = match source {
+            CardId::A1223Giovanni | CardId::A1270Giovanni => (
+                EffectKind::AttackDamageBonus(10),
+                EffectTarget::Player(owner),
+            ),
+            CardId::PA002XSpeed => (
+                EffectKind::RetreatCostReduction(1),
+                EffectTarget::Player(owner),
+            ),
+            CardId::A1a068Leaf | CardId::A1a082Leaf => (
+                EffectKind::RetreatCostReduction(2),
+                EffectTarget::Player(owner),
+            ),
+            CardId::A1057Psyduck => (
+                EffectKind::CantPlaySupporter,
+                EffectTarget::Player(target_player),
+            ),
+            _ if card.get_name() == "Arbok" => {
+                (EffectKind::CantRetreat, EffectTarget::Active(target_player))
+            }
+            _ => continue,
+        };
+        effects.push(Effect {
+            kind,
+            source,
+            owner,
+            target,
+            expires_after_turn,
+        });
+    }
+    effects
+}
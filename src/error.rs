@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// Errors raised when the engine runs into a card or action it can't handle, or is set up
+/// with rules it can't play by. Returned by the `try_*` variants of `apply_action`,
+/// `generate_possible_actions` and `Game::play`, so that a batch of games can skip a broken
+/// game instead of aborting, and by `Game::new_with_rules`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckgymError {
+    /// A trainer card (by card id) without an implementation.
+    UnsupportedTrainer(String),
+    /// A Pokémon (by card id) whose ability is not implemented.
+    UnsupportedAbility(String),
+    /// A Pokémon (by card id) whose ability can't be used, because it triggers on its own.
+    UnusableAbility(String),
+    /// A Pokémon (by card id) whose attack at `index` is not implemented.
+    UnsupportedAttack { pokemon: String, index: usize },
+    /// A card (by card id) that can't be put into play (e.g. a non-fossil trainer).
+    UnplayableCard(String),
+    /// A `RuleSet` that games can't be played with (see `RuleSet::validate`).
+    InvalidRules(String),
+}
+
+impl fmt::Display for DeckgymError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckgymError::UnsupportedTrainer(id) => write!(f, "Unsupported Trainer Card: {id}"),
+            DeckgymError::UnsupportedAbility(id) => write!(f, "Ability not implemented: {id}"),
+            DeckgymError::UnusableAbility(id) => write!(f, "Ability can't be used: {id}"),
+            DeckgymError::UnsupportedAttack { pokemon, index } => {
+                write!(f, "Attack {index} not implemented for Pokemon: {pokemon}")
+            }
+            DeckgymError::UnplayableCard(id) => write!(f, "Unplayable Card: {id}"),
+            DeckgymError::InvalidRules(reason) => write!(f, "Invalid rules: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DeckgymError {}
+
+impl From<DeckgymError> for String {
+    fn from(err: DeckgymError) -> Self {
+        err.to_string()
+    }
+}
@@ -3,10 +3,13 @@ use log::{debug, info, trace};
 use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
-    actions::{apply_action, Action},
-    generate_possible_actions,
-    players::Player,
+    actions::{apply_action, try_apply_action, Action},
+    error::DeckgymError,
+    game_record::GameRecord,
+    players::{create_players, Player, PlayerCode},
+    rules::RuleSet,
     state::GameOutcome,
+    try_generate_possible_actions,
     types::EnergyType,
     State,
 };
@@ -20,12 +23,15 @@ pub struct Game {
 
     // keeping statistics for Game analysis here (outside of "State")
     degrees_per_ply: Vec<u32>,
+    // actions applied via play_tick, in order (see GameRecord)
+    actions: Vec<Action>,
 
     debug: bool,
 }
 
 impl Game {
-    pub fn from_state(state: State, players: Vec<Box<dyn Player>>, seed: u64) -> Self {
+    pub fn from_state(mut state: State, players: Vec<Box<dyn Player>>, seed: u64) -> Self {
+        state.rehash();
         let rng = StdRng::seed_from_u64(seed);
         Game {
             seed,
@@ -33,44 +39,75 @@ impl Game {
             players,
             state,
             degrees_per_ply: vec![],
+            actions: vec![],
             debug: false,
         }
     }
 
     pub fn new(players: Vec<Box<dyn Player>>, seed: u64) -> Self {
+        Self::new_with_rules(players, seed, RuleSet::default())
+            .expect("Standard rules should be valid")
+    }
+
+    /// Like `new`, but plays with the given (non-standard) rules. Fails if they are invalid
+    /// (see `RuleSet::validate`).
+    pub fn new_with_rules(
+        players: Vec<Box<dyn Player>>,
+        seed: u64,
+        rules: RuleSet,
+    ) -> Result<Self, DeckgymError> {
         let mut rng = StdRng::seed_from_u64(seed);
         let deck_a = players[0].get_deck();
         let deck_b = players[1].get_deck();
-        let state = State::initialize(&deck_a, &deck_b, &mut rng);
-        Game {
+        let state = State::initialize_with_rules(&deck_a, &deck_b, rules, &mut rng)?;
+        Ok(Game {
             seed,
             rng,
             players,
             state,
             degrees_per_ply: vec![],
+            actions: vec![],
             debug: true,
-        }
+        })
     }
 
     // Returns None if the game times out
     pub fn play(&mut self) -> Option<GameOutcome> {
+        self.try_play().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like `play`, but stops with an error if the game reaches a card that is not
+    /// implemented (instead of panicking).
+    pub fn try_play(&mut self) -> Result<Option<GameOutcome>, DeckgymError> {
         if self.debug {
             info!("Playing game with seed: {}", self.seed);
         }
         while !self.state.is_game_over() {
-            self.play_tick();
+            self.try_play_tick()?;
         }
-        self.state.winner
+        Ok(self.state.winner)
     }
 
     pub fn play_tick(&mut self) -> Action {
-        let (actor, actions) = generate_possible_actions(&self.state);
+        self.try_play_tick().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_play_tick(&mut self) -> Result<Action, DeckgymError> {
+        self.play_tick_with(None)
+    }
+
+    // If `forced` is given, it is applied instead of asking the player (used when replaying
+    // decisions that can't be re-computed, like the ones of a HumanPlayer).
+    fn play_tick_with(&mut self, forced: Option<&Action>) -> Result<Action, DeckgymError> {
+        let (actor, actions) = try_generate_possible_actions(&self.state)?;
         self.degrees_per_ply.push(actions.len() as u32);
 
         let player = &self.players[actor];
         let color = self.get_color(actor);
         self.print_turn_header(actor, player.as_ref(), &color);
-        let action = if actions.len() == 1 {
+        let action = if let Some(forced) = forced {
+            forced.clone()
+        } else if actions.len() == 1 {
             debug!("Only one possible action, selecting it.");
             actions[0].clone()
         } else {
@@ -83,9 +120,78 @@ impl Game {
         };
         let player = &self.players[actor];
         self.print_action(&action, actor, player.as_ref(), &color);
-        self.apply_action(&action);
+        self.try_apply_action(&action)?;
         self.print_state();
-        action
+        self.actions.push(action.clone());
+        Ok(action)
+    }
+
+    /// Builds a `GameRecord` of the actions played so far. `players` should be the codes
+    /// used to create this game's players, so that the record can be replayed.
+    pub fn to_record(&self, players: [PlayerCode; 2]) -> GameRecord {
+        GameRecord {
+            seed: self.seed,
+            decks: [self.players[0].get_deck(), self.players[1].get_deck()],
+            players,
+            rules: self.state.rules,
+            allow_partial: self.state.allow_partial,
+            actions: self.actions.clone(),
+            outcome: self.state.winner,
+        }
+    }
+
+    /// Re-plays a recorded game from its seed, checking that every action (and the final
+    /// outcome) matches the record. Returns the outcome, or a description of the first
+    /// divergence.
+    pub fn replay(record: &GameRecord) -> Result<Option<GameOutcome>, String> {
+        let [deck_a, deck_b] = record.decks.clone();
+        let players = create_players(deck_a, deck_b, record.players.to_vec());
+        let mut game = Game::new_with_rules(players, record.seed, record.rules)?;
+        game.set_allow_partial(record.allow_partial);
+        for (i, expected) in record.actions.iter().enumerate() {
+            if game.state.is_game_over() {
+                return Err(format!(
+                    "Game ended after {} actions, but record has {}",
+                    i,
+                    record.actions.len()
+                ));
+            }
+
+            // Human decisions can't be re-computed, so we take them from the record.
+            let (actor, possible_actions) = try_generate_possible_actions(&game.state)?;
+            let forced = if record.players[actor] == PlayerCode::H {
+                if !possible_actions.contains(expected) {
+                    return Err(format!(
+                        "Action {i} ({:?}) is not a legal action when replaying",
+                        expected.action
+                    ));
+                }
+                Some(expected)
+            } else {
+                None
+            };
+
+            let action = game.play_tick_with(forced)?;
+            if action != *expected {
+                return Err(format!(
+                    "Action {i} diverged: recorded {:?} but replay played {:?}",
+                    expected, action
+                ));
+            }
+        }
+        if !game.state.is_game_over() {
+            return Err(format!(
+                "Game did not finish after replaying all {} actions",
+                record.actions.len()
+            ));
+        }
+        if game.state.winner != record.outcome {
+            return Err(format!(
+                "Outcome diverged: recorded {:?} but replay got {:?}",
+                record.outcome, game.state.winner
+            ));
+        }
+        Ok(game.state.winner)
     }
 
     pub fn get_state_clone(&self) -> State {
@@ -97,10 +203,21 @@ impl Game {
         apply_action(&mut self.rng, &mut self.state, action);
     }
 
-    pub fn set_state(&mut self, state: State) {
+    pub fn try_apply_action(&mut self, action: &Action) -> Result<(), DeckgymError> {
+        try_apply_action(&mut self.rng, &mut self.state, action)
+    }
+
+    pub fn set_state(&mut self, mut state: State) {
+        state.rehash();
         self.state = state;
     }
 
+    /// Lets the game go on with unimplemented cards: their attacks deal the printed fixed
+    /// damage and their trainers can't be played.
+    pub fn set_allow_partial(&mut self, allow_partial: bool) {
+        self.state.allow_partial = allow_partial;
+    }
+
     fn print_turn_header(&self, actor: usize, player: &dyn Player, color: &str) {
         if self.debug {
             debug!(
@@ -156,7 +273,9 @@ impl Game {
 #[cfg(test)]
 mod tests {
     use crate::{
+        error::DeckgymError,
         players::{AttachAttackPlayer, EndTurnPlayer, Player},
+        rules::RuleSet,
         state::GameOutcome,
         test_helpers::load_test_decks,
         Game,
@@ -233,6 +352,55 @@ mod tests {
         assert_eq!(game.get_state_clone().turn_count, 5);
     }
 
+    #[test]
+    fn test_turn_cap_follows_rules() {
+        let (deck_a, deck_b) = load_test_decks();
+        let player_a = Box::new(EndTurnPlayer { deck: deck_a });
+        let player_b = Box::new(EndTurnPlayer { deck: deck_b });
+        let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+        let rules = RuleSet {
+            max_turns: 10,
+            opening_hand_size: 7,
+            ..Default::default()
+        };
+        let mut game = Game::new_with_rules(players, 0, rules).unwrap();
+        assert_eq!(game.get_state_clone().hands[0].len(), 7);
+
+        let outcome = game.play();
+        assert_eq!(outcome, None);
+        assert_eq!(game.get_state_clone().turn_count, 10);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let invalid_rules = [
+            RuleSet {
+                bench_size: 4,
+                ..Default::default()
+            },
+            RuleSet {
+                max_turns: 0,
+                ..Default::default()
+            },
+            RuleSet {
+                opening_hand_size: 21,
+                ..Default::default()
+            },
+        ];
+        for rules in invalid_rules {
+            let (deck_a, deck_b) = load_test_decks();
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(EndTurnPlayer { deck: deck_a }),
+                Box::new(EndTurnPlayer { deck: deck_b }),
+            ];
+            let result = Game::new_with_rules(players, 0, rules);
+            assert!(
+                matches!(result, Err(DeckgymError::InvalidRules(_))),
+                "{rules:?} should be rejected"
+            );
+        }
+    }
+
     // TODO: Look for a game that has bench, and pokemon can die from attack + poison
     //   to launche the complicated sequence of Poison K.O. then user having
     //   to select one pokemon to promote to active.
@@ -0,0 +1,71 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{actions::Action, players::PlayerCode, rules::RuleSet, state::GameOutcome, Deck};
+
+/// Everything needed to reproduce a game: the decks, the players' strategies, the seed,
+/// and the ordered list of actions that `Game::play_tick` applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub seed: u64,
+    pub decks: [Deck; 2],
+    pub players: [PlayerCode; 2],
+    // Records made before rules were configurable use the standard ones.
+    #[serde(default)]
+    pub rules: RuleSet,
+    /// Whether unimplemented cards were allowed (see `State::allow_partial`).
+    #[serde(default)]
+    pub allow_partial: bool,
+    pub actions: Vec<Action>,
+    pub outcome: Option<GameOutcome>,
+}
+
+impl GameRecord {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|err| format!("Failed to serialize: {err}"))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|err| format!("Failed to parse game record: {err}"))
+    }
+
+    pub fn to_file(&self, file_path: &str) -> Result<(), String> {
+        fs::write(file_path, self.to_json()?)
+            .map_err(|err| format!("Failed to write file {file_path}: {err}"))
+    }
+
+    pub fn from_file(file_path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(file_path)
+            .map_err(|err| format!("Failed to read file {file_path}: {err}"))?;
+        Self::from_json(&contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{actions::SimpleAction, test_helpers::load_test_decks};
+
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let (deck_a, deck_b) = load_test_decks();
+        let record = GameRecord {
+            seed: 7,
+            decks: [deck_a, deck_b],
+            players: [PlayerCode::R, PlayerCode::E],
+            rules: RuleSet::default(),
+            allow_partial: true,
+            actions: vec![Action {
+                actor: 1,
+                action: SimpleAction::EndTurn,
+                is_stack: false,
+            }],
+            outcome: Some(GameOutcome::Win(1)),
+        };
+
+        let json = record.to_json().unwrap();
+        assert_eq!(GameRecord::from_json(&json).unwrap(), record);
+    }
+}
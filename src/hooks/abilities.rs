@@ -0,0 +1,175 @@
+use log::debug;
+
+use crate::{
+    ability_ids::AbilityId,
+    actions::SimpleAction,
+    types::{Card, PlayedCard},
+    State,
+};
+
+/// When (and how) an ability takes effect. Abilities that are not `Activated` are never used
+/// through `SimpleAction::UseAbility`; the engine applies them at their trigger instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AbilityTrigger {
+    /// The player chooses to use it (see `apply_abilities_action`).
+    Activated,
+    /// Always on while the Pokémon is in play.
+    Passive(PassiveEffect),
+    /// When this Pokémon is in the Active Spot and is damaged by an attack, do damage to the
+    /// Attacking Pokémon.
+    OnAttacked { counter_damage: u32 },
+    /// When this Pokémon is in the Active Spot and is Knocked Out by damage from an attack, do
+    /// damage to the Attacking Pokémon.
+    OnKnockout { counter_damage: u32 },
+    /// During the Pokémon Checkup, if this Pokémon is in the Active Spot, do damage to the
+    /// opponent's Active Pokémon.
+    BetweenTurns { opponent_active_damage: u32 },
+    /// When this Pokémon is played from the hand to evolve, the player may use the ability
+    /// (see `apply_abilities_action`).
+    OnEvolve,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PassiveEffect {
+    /// Can't be affected by any Special Conditions.
+    StatusImmunity,
+    /// Takes -N damage from attacks.
+    DamageReduction(u32),
+}
+
+/// `None` if the ability is not implemented yet.
+pub(crate) fn ability_trigger(ability_id: AbilityId) -> Option<AbilityTrigger> {
+    let trigger = match ability_id {
+        AbilityId::A1007Butterfree | AbilityId::A1177Weezing | AbilityId::A1132Gardevoir => {
+            AbilityTrigger::Activated
+        }
+        AbilityId::A2a071ArceusEx => AbilityTrigger::Passive(PassiveEffect::StatusImmunity),
+        AbilityId::A1067Cloyster => AbilityTrigger::Passive(PassiveEffect::DamageReduction(10)),
+        AbilityId::A1182Melmetal | AbilityId::A2087Regirock | AbilityId::A3109AlolanPersian => {
+            AbilityTrigger::Passive(PassiveEffect::DamageReduction(20))
+        }
+        AbilityId::A1061Poliwrath
+        | AbilityId::A1a056Druddigon
+        | AbilityId::A2b028Pawmot
+        | AbilityId::A3a052Ferrothorn => AbilityTrigger::OnAttacked { counter_damage: 20 },
+        AbilityId::A3054Pyukumuku => AbilityTrigger::OnKnockout { counter_damage: 50 },
+        AbilityId::A2a022GlaceonEx => AbilityTrigger::BetweenTurns {
+            opponent_active_damage: 10,
+        },
+        AbilityId::A3b034SylveonEx => AbilityTrigger::OnEvolve,
+        _ => return None,
+    };
+    Some(trigger)
+}
+
+fn card_ability_trigger(card: &Card) -> Option<AbilityTrigger> {
+    AbilityId::from_pokemon_id(card.get_id_str()).and_then(ability_trigger)
+}
+
+pub(crate) fn can_be_affected_by_status(pokemon: &PlayedCard) -> bool {
+    card_ability_trigger(&pokemon.card)
+        != Some(AbilityTrigger::Passive(PassiveEffect::StatusImmunity))
+}
+
+/// Damage less that the Pokémon takes from attacks.
+pub(crate) fn ability_damage_reduction(pokemon: &PlayedCard) -> u32 {
+    match card_ability_trigger(&pokemon.card) {
+        Some(AbilityTrigger::Passive(PassiveEffect::DamageReduction(amount))) => amount,
+        _ => 0,
+    }
+}
+
+/// Damage that the defending Active Pokémon does back to the Attacking Pokémon because of
+/// its ability, given whether the attack Knocked it Out.
+pub(crate) fn get_counterattack_damage(pokemon: &PlayedCard, is_knocked_out: bool) -> u32 {
+    match card_ability_trigger(&pokemon.card) {
+        Some(AbilityTrigger::OnAttacked { counter_damage }) => counter_damage,
+        Some(AbilityTrigger::OnKnockout { counter_damage }) if is_knocked_out => counter_damage,
+        _ => 0,
+    }
+}
+
+/// Damage done by abilities during the Pokémon Checkup, as (player, in_play_idx, damage).
+pub(crate) fn get_between_turns_damage(state: &State) -> Vec<(usize, usize, u32)> {
+    let mut damage = vec![];
+    for player in 0..2 {
+        let Some(active) = &state.in_play_pokemon[player][0] else {
+            continue;
+        };
+        if let Some(AbilityTrigger::BetweenTurns {
+            opponent_active_damage,
+        }) = card_ability_trigger(&active.card)
+        {
+            let opponent = (player + 1) % 2;
+            if state.in_play_pokemon[opponent][0].is_some() {
+                debug!(
+                    "{}'s ability damages the opponent's Active",
+                    active.get_name()
+                );
+                damage.push((opponent, 0, opponent_active_damage));
+            }
+        }
+    }
+    damage
+}
+
+/// Called after `player` evolves the Pokémon at `in_play_idx` from their hand.
+pub(crate) fn on_evolve(state: &mut State, player: usize, in_play_idx: usize) {
+    let evolved = state.in_play_pokemon[player][in_play_idx]
+        .as_ref()
+        .expect("Pokemon should be there if just evolved");
+    if card_ability_trigger(&evolved.card) == Some(AbilityTrigger::OnEvolve) {
+        // The ability is optional, so the player chooses whether to use it
+        state.push_choices(
+            player,
+            vec![SimpleAction::UseAbility(in_play_idx), SimpleAction::Noop],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card};
+
+    use super::*;
+
+    fn played(card_id: CardId) -> PlayedCard {
+        to_playable_card(get_card_by_enum(card_id), false)
+    }
+
+    #[test]
+    fn test_passive_abilities() {
+        assert!(!can_be_affected_by_status(&played(CardId::A2a096ArceusEx)));
+        assert!(can_be_affected_by_status(&played(CardId::A1001Bulbasaur)));
+        assert_eq!(ability_damage_reduction(&played(CardId::A1067Cloyster)), 10);
+        assert_eq!(
+            ability_damage_reduction(&played(CardId::A3109AlolanPersian)),
+            20
+        );
+        assert_eq!(ability_damage_reduction(&played(CardId::A1001Bulbasaur)), 0);
+    }
+
+    #[test]
+    fn test_counterattack_abilities() {
+        let poliwrath = played(CardId::A1061Poliwrath);
+        assert_eq!(get_counterattack_damage(&poliwrath, false), 20);
+        let pyukumuku = played(CardId::A3054Pyukumuku);
+        assert_eq!(get_counterattack_damage(&pyukumuku, false), 0);
+        assert_eq!(get_counterattack_damage(&pyukumuku, true), 50);
+    }
+
+    #[test]
+    fn test_between_turns_and_on_evolve_abilities() {
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(played(CardId::A2a022GlaceonEx));
+        state.in_play_pokemon[1][0] = Some(played(CardId::A1001Bulbasaur));
+        assert_eq!(get_between_turns_damage(&state), vec![(1, 0, 10)]);
+
+        state.in_play_pokemon[1][1] = Some(played(CardId::A3b034SylveonEx));
+        on_evolve(&mut state, 1, 1);
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(1, vec![SimpleAction::UseAbility(1), SimpleAction::Noop])]
+        );
+    }
+}
@@ -1,30 +1,38 @@
-use core::panic;
 use std::vec;
 
 use log::debug;
 
 use crate::{
-    card_ids::CardId,
-    tool_ids::ToolId,
-    types::{Card, EnergyType, PlayedCard},
+    effects::EffectKind,
+    error::DeckgymError,
+    hooks::tools::tool_damage_dealt_bonus,
+    types::{Card, CardRef, EnergyType, PlayedCard},
     State,
 };
 
 const PLAYABLE_TRAINER_CARD_NAMES: [&str; 3] = ["Helix Fossil", "Dome Fossil", "Old Amber"];
 
-pub(crate) fn to_playable_card(card: &crate::types::Card, played_this_turn: bool) -> PlayedCard {
-    let total_hp = match card {
+pub(crate) fn to_playable_card(card: impl Into<CardRef>, played_this_turn: bool) -> PlayedCard {
+    try_to_playable_card(card, played_this_turn).unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub(crate) fn try_to_playable_card(
+    card: impl Into<CardRef>,
+    played_this_turn: bool,
+) -> Result<PlayedCard, DeckgymError> {
+    let card = card.into();
+    let total_hp = match card.card() {
         Card::Pokemon(pokemon_card) => pokemon_card.hp,
         Card::Trainer(trainer_card) => {
             if PLAYABLE_TRAINER_CARD_NAMES.contains(&trainer_card.name.as_str()) {
                 40
             } else {
-                panic!("Unplayable Trainer Card: {:?}", trainer_card);
+                return Err(DeckgymError::UnplayableCard(trainer_card.id.clone()));
             }
         }
     };
-    PlayedCard {
-        card: card.clone(),
+    Ok(PlayedCard {
+        card,
         remaining_hp: total_hp,
         total_hp,
         attached_energy: vec![],
@@ -34,33 +42,18 @@ pub(crate) fn to_playable_card(card: &crate::types::Card, played_this_turn: bool
         poisoned: false,
         paralyzed: false,
         asleep: false,
+        burned: false,
+        confused: false,
         cards_behind: vec![],
         damage_prevention: None,
-    }
-}
-
-pub(crate) fn on_attach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
-    match tool_id {
-        ToolId::A2147GiantCape => {
-            // Add +20 to remaining_hp and total_hp
-            let card = state.in_play_pokemon[actor][in_play_idx]
-                .as_mut()
-                .expect("Active Pokemon should be there");
-            card.remaining_hp += 20;
-            card.total_hp += 20;
-        }
-        // Many tools do nothing on attach
-        ToolId::A2148RockyHelmet => {}
-    }
+    })
 }
 
 // TODO: Implement Gengars ability that disallow playing support cards.
 pub(crate) fn can_play_support(state: &State) -> bool {
-    let psyduck_headache = state
-        .get_current_turn_effects()
-        .iter()
-        .any(|x| CardId::from_card_id(&x.get_id()) == Some(CardId::A1057Psyduck));
-    !state.has_played_support && !psyduck_headache
+    let cant_play_supporter =
+        state.has_effect_on(state.current_player, EffectKind::CantPlaySupporter);
+    !state.has_played_support && !cant_play_supporter
 }
 
 pub(crate) fn get_damage_from_attack(
@@ -84,22 +77,21 @@ pub(crate) fn get_damage_from_attack(
         return attack.fixed_damage;
     }
 
-    // Giovanni's Modifier
-    let mut giovanni_modifier = 0;
-    let giovanni_ids = [CardId::A1223Giovanni, CardId::A1270Giovanni];
-    if state
-        .get_current_turn_effects()
-        .iter()
-        .any(|x| giovanni_ids.contains(&CardId::from_card_id(&x.get_id()).unwrap()))
-    {
-        giovanni_modifier = 10;
-    }
+    // Modifiers from effects (e.g. Giovanni) and tools (e.g. Beastite)
+    let effects_modifier: u32 = state
+        .get_effects_on(player)
+        .map(|x| match x {
+            EffectKind::AttackDamageBonus(amount) => *amount,
+            _ => 0,
+        })
+        .sum::<u32>()
+        + tool_damage_dealt_bonus(state, player, active);
 
     // Weakness Modifier
     let opponent = (player + 1) % 2;
     let mut weakness_modifier = 0;
     let receiving = state.get_active(opponent);
-    if let Card::Pokemon(pokemon_card) = &receiving.card {
+    if let Card::Pokemon(pokemon_card) = &*receiving.card {
         if pokemon_card.weakness == active.card.get_type() {
             debug!(
                 "Weakness! {:?} is weak to {:?}",
@@ -111,10 +103,10 @@ pub(crate) fn get_damage_from_attack(
     }
 
     debug!(
-        "Attack: {:?}, Weakness: {}, Giovanni: {}",
-        attack.fixed_damage, weakness_modifier, giovanni_modifier
+        "Attack: {:?}, Weakness: {}, Effects: {}",
+        attack.fixed_damage, weakness_modifier, effects_modifier
     );
-    attack.fixed_damage + weakness_modifier + giovanni_modifier
+    attack.fixed_damage + weakness_modifier + effects_modifier
 }
 
 // Check if attached satisfies cost (considering Colorless)
@@ -137,10 +129,72 @@ pub(crate) fn contains_energy(attached: &[EnergyType], cost: &[EnergyType]) -> b
     attached_copy.len() >= colorless_cost.count()
 }
 
+/// Returns the distinct ways (each sorted) of paying `cost` with `attached` energy, considering
+/// Colorless as a wildcard. Identical energy sets are listed once, to keep branching small.
+pub(crate) fn energy_discard_options(
+    attached: &[EnergyType],
+    cost: &[EnergyType],
+) -> Vec<Vec<EnergyType>> {
+    // Typed energy can only be paid one way
+    let mut remaining: Vec<EnergyType> = attached.to_vec();
+    let mut typed = vec![];
+    for energy in cost.iter().filter(|x| **x != EnergyType::Colorless) {
+        match remaining.iter().position(|x| x == energy) {
+            Some(i) => typed.push(remaining.remove(i)),
+            None => return vec![],
+        }
+    }
+    let colorless_count = cost.len() - typed.len();
+    if remaining.len() < colorless_count {
+        return vec![];
+    }
+
+    // Colorless can be paid with any combination of counts of the remaining energy types
+    remaining.sort();
+    let mut counts: Vec<(EnergyType, usize)> = vec![];
+    for energy in remaining {
+        match counts.last_mut() {
+            Some((last, count)) if *last == energy => *count += 1,
+            _ => counts.push((energy, 1)),
+        }
+    }
+    let mut options = vec![];
+    collect_colorless_choices(&counts, colorless_count, &mut typed, &mut options);
+    options.sort();
+    options.dedup();
+    options
+}
+
+fn collect_colorless_choices(
+    counts: &[(EnergyType, usize)],
+    to_choose: usize,
+    current: &mut Vec<EnergyType>,
+    options: &mut Vec<Vec<EnergyType>>,
+) {
+    if to_choose == 0 {
+        let mut option = current.clone();
+        option.sort();
+        options.push(option);
+        return;
+    }
+    let Some(((energy, count), rest)) = counts.split_first() else {
+        return;
+    };
+    for taken in 0..=(*count).min(to_choose) {
+        current.extend(std::iter::repeat_n(*energy, taken));
+        collect_colorless_choices(rest, to_choose - taken, current, options);
+        current.truncate(current.len() - taken);
+    }
+}
+
 // Test Colorless is wildcard when counting energy
 #[cfg(test)]
 mod tests {
-    use crate::database::get_card_by_enum;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        effects::{Effect, EffectTarget},
+    };
 
     use super::*;
 
@@ -172,6 +226,32 @@ mod tests {
         assert!(contains_energy(&slice_a, &slice_b));
     }
 
+    #[test]
+    fn test_energy_discard_options() {
+        let fire = EnergyType::Fire;
+        let water = EnergyType::Water;
+        let colorless = EnergyType::Colorless;
+
+        // Identical energies give a single option
+        let options = energy_discard_options(&[fire, fire, fire], &[colorless, colorless]);
+        assert_eq!(options, vec![vec![fire, fire]]);
+
+        // Mixed energies give one option per distinct set
+        let options = energy_discard_options(&[fire, water, fire, water], &[colorless, colorless]);
+        assert_eq!(
+            options,
+            vec![vec![fire, fire], vec![fire, water], vec![water, water]]
+        );
+
+        // Typed costs are always paid with their type
+        let options = energy_discard_options(&[fire, water, fire], &[fire, colorless]);
+        assert_eq!(options, vec![vec![fire, fire], vec![fire, water]]);
+
+        // Costs that can't be paid have no options
+        assert!(energy_discard_options(&[water], &[fire]).is_empty());
+        assert!(energy_discard_options(&[water], &[colorless, colorless]).is_empty());
+    }
+
     #[test]
     fn test_can_play_support() {
         // Normal state should allow support cards
@@ -186,8 +266,23 @@ mod tests {
         state.has_played_support = false;
         assert!(can_play_support(&state));
 
-        // With Psyduck headache effect, it should disallow
-        state.add_turn_effect(get_card_by_enum(CardId::A1057Psyduck), 1);
+        // Psyduck's Headache only affects the opponent of the attacker
+        let headache = Effect {
+            kind: EffectKind::CantPlaySupporter,
+            source: CardId::A1057Psyduck,
+            owner: 0,
+            target: EffectTarget::Player(1),
+            expires_after_turn: 1,
+        };
+        state.add_effect(headache);
+        assert!(can_play_support(&state));
+
+        // With Psyduck headache effect on us, it should disallow
+        state.add_effect(Effect {
+            owner: 1,
+            target: EffectTarget::Player(0),
+            ..headache
+        });
         assert!(!can_play_support(&state));
     }
 
@@ -210,7 +305,13 @@ mod tests {
         let base_damage = get_damage_from_attack(&state, 0, 0, 0);
 
         // Add Giovanni effect
-        state.add_turn_effect(get_card_by_enum(CardId::A1223Giovanni), 0);
+        state.add_effect(Effect {
+            kind: EffectKind::AttackDamageBonus(10),
+            source: CardId::A1223Giovanni,
+            owner: 0,
+            target: EffectTarget::Player(0),
+            expires_after_turn: 0,
+        });
 
         // Get damage with Giovanni effect
         let damage_with_giovanni = get_damage_from_attack(&state, 0, 0, 0);
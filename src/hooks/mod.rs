@@ -1,15 +1,28 @@
 /// These are the places/functions in the framework that custom logic is to be implemented per card.
 /// That is those special "if Psyduck, do this", "if Darkrai, do that" kind of logic.
 /// We call these "hooks" (like on_attach_tool, on_attach_energy, on_play, on_knockout, etc...).
+mod abilities;
 mod core;
-mod counterattack;
 mod retreat;
+mod tools;
 
+pub(crate) use abilities::ability_damage_reduction;
+pub(crate) use abilities::ability_trigger;
+pub(crate) use abilities::can_be_affected_by_status;
+pub(crate) use abilities::get_between_turns_damage;
+pub(crate) use abilities::get_counterattack_damage;
+pub(crate) use abilities::on_evolve;
+pub(crate) use abilities::AbilityTrigger;
 pub(crate) use core::can_play_support;
 pub(crate) use core::contains_energy;
+pub(crate) use core::energy_discard_options;
 pub(crate) use core::get_damage_from_attack;
-pub(crate) use core::on_attach_tool;
 pub(crate) use core::to_playable_card;
-pub(crate) use counterattack::get_counterattack_damage;
+pub(crate) use core::try_to_playable_card;
 pub(crate) use retreat::can_retreat;
 pub(crate) use retreat::get_retreat_cost;
+pub(crate) use tools::on_attach_tool;
+pub(crate) use tools::on_end_turn_tools;
+pub(crate) use tools::on_tool_damage_received;
+pub(crate) use tools::on_tool_knockout;
+pub(crate) use tools::tool_hp_bonus;
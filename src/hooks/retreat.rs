@@ -1,37 +1,28 @@
 use crate::{
-    card_ids::CardId,
-    database::get_card_by_enum,
+    effects::EffectKind,
     types::{Card, EnergyType, PlayedCard},
     State,
 };
 
 pub(crate) fn can_retreat(state: &State) -> bool {
-    let no_arbok_corner = !state
-        .get_current_turn_effects()
-        .iter()
-        .any(|x| matches!(x, Card::Pokemon(pokemon_card) if pokemon_card.name == "Arbok"));
-    !state.has_retreated && no_arbok_corner
+    let no_arbok_corner = !state.has_effect_on(state.current_player, EffectKind::CantRetreat);
+    let can_move = state.in_play_pokemon[state.current_player][0]
+        .as_ref()
+        .is_some_and(|x| !x.is_asleep_or_paralyzed());
+    !state.has_retreated && no_arbok_corner && can_move
 }
 
 pub(crate) fn get_retreat_cost(state: &State, card: &PlayedCard) -> Vec<EnergyType> {
-    if let Card::Pokemon(pokemon_card) = &card.card {
+    if let Card::Pokemon(pokemon_card) = &*card.card {
         let mut normal_cost = pokemon_card.retreat_cost.clone();
-        // Implement Retreat Cost Modifiers here
-        let x_speed = state
-            .get_current_turn_effects()
-            .iter()
-            .filter(|x| **x == get_card_by_enum(CardId::PA002XSpeed))
-            .count();
-        let leafs = state
-            .get_current_turn_effects()
-            .iter()
-            .filter(|x| {
-                **x == get_card_by_enum(CardId::A1a068Leaf)
-                    || **x == get_card_by_enum(CardId::A1a082Leaf)
+        // Retreat Cost Modifiers (e.g. X Speed, Leaf) accumulate so we add them.
+        let to_subtract: usize = state
+            .get_effects_on(state.current_player)
+            .map(|x| match x {
+                EffectKind::RetreatCostReduction(amount) => *amount,
+                _ => 0,
             })
-            .count();
-        // Retreat Effects accumulate so we add them.
-        let to_subtract = leafs * 2 + x_speed;
+            .sum();
         for _ in 0..to_subtract {
             normal_cost.pop(); // Remove one colorless energy from retreat cost
         }
@@ -44,10 +35,25 @@ pub(crate) fn get_retreat_cost(state: &State, card: &PlayedCard) -> Vec<EnergyTy
 // Test Colorless is wildcard when counting energy
 #[cfg(test)]
 mod tests {
-    use crate::hooks::core::to_playable_card;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        effects::{Effect, EffectTarget},
+        hooks::core::to_playable_card,
+    };
 
     use super::*;
 
+    fn retreat_cost_reduction(source: CardId, amount: usize) -> Effect {
+        Effect {
+            kind: EffectKind::RetreatCostReduction(amount),
+            source,
+            owner: 0,
+            target: EffectTarget::Player(0),
+            expires_after_turn: 0,
+        }
+    }
+
     #[test]
     fn test_retreat_costs() {
         let state = State::default();
@@ -67,7 +73,7 @@ mod tests {
     #[test]
     fn test_retreat_costs_with_xspeed() {
         let mut state = State::default();
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
+        state.add_effect(retreat_cost_reduction(CardId::PA002XSpeed, 1));
         let card = get_card_by_enum(CardId::A1055Blastoise);
         let playable_card = to_playable_card(&card, false);
         let retreat_cost = get_retreat_cost(&state, &playable_card);
@@ -80,9 +86,9 @@ mod tests {
     #[test]
     fn test_retreat_costs_with_two_xspeed_and_two_leafs() {
         let mut state = State::default();
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
-        state.add_turn_effect(get_card_by_enum(CardId::A1a068Leaf), 0);
+        state.add_effect(retreat_cost_reduction(CardId::PA002XSpeed, 1));
+        state.add_effect(retreat_cost_reduction(CardId::PA002XSpeed, 1));
+        state.add_effect(retreat_cost_reduction(CardId::A1a068Leaf, 2));
         let card = get_card_by_enum(CardId::A1211Snorlax);
         let playable_card = to_playable_card(&card, false);
         let retreat_cost = get_retreat_cost(&state, &playable_card);
@@ -0,0 +1,265 @@
+use log::debug;
+
+use crate::{
+    actions::SimpleAction,
+    hooks::abilities::can_be_affected_by_status,
+    state::Zone,
+    tool_ids::ToolId,
+    types::{Card, EnergyType, PlayedCard},
+    State,
+};
+
+const ULTRA_BEAST_NAMES: [&str; 11] = [
+    "Nihilego",
+    "Buzzwole ex",
+    "Pheromosa",
+    "Xurkitree",
+    "Celesteela",
+    "Kartana",
+    "Guzzlord ex",
+    "Poipole",
+    "Naganadel",
+    "Stakataka",
+    "Blacephalon",
+];
+
+fn is_ultra_beast(card: &Card) -> bool {
+    ULTRA_BEAST_NAMES.contains(&card.get_name().as_str())
+}
+
+fn is_type(card: &Card, energy_type: EnergyType) -> bool {
+    matches!(card, Card::Pokemon(pokemon_card) if pokemon_card.energy_type == energy_type)
+}
+
+/// Extra HP that the tool gives to the Pokémon it is attached to.
+pub(crate) fn tool_hp_bonus(tool_id: ToolId, card: &Card) -> u32 {
+    match tool_id {
+        ToolId::A2147GiantCape => 20,
+        ToolId::A3147LeafCape if is_type(card, EnergyType::Grass) => 30,
+        _ => 0,
+    }
+}
+
+pub(crate) fn on_attach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
+    state.update_pokemon(actor, in_play_idx, |pokemon| {
+        let hp_bonus = tool_hp_bonus(tool_id, &pokemon.card);
+        pokemon.remaining_hp += hp_bonus;
+        pokemon.total_hp += hp_bonus;
+    });
+}
+
+/// Extra damage that attacks from `attacker` (of `player`) do to the opponent's Active Pokémon.
+pub(crate) fn tool_damage_dealt_bonus(state: &State, player: usize, attacker: &PlayedCard) -> u32 {
+    match attacker.attached_tool {
+        Some(ToolId::A3a066Beastite) if is_ultra_beast(&attacker.card) => {
+            10 * state.points[player] as u32
+        }
+        _ => 0,
+    }
+}
+
+/// Called when the defending Active Pokémon (with `tool_id` attached) is damaged by an attack.
+/// Applies the tool effects on the attacking Active Pokémon, and returns the damage to do to it.
+pub(crate) fn on_tool_damage_received(
+    state: &mut State,
+    attacking_player: usize,
+    tool_id: ToolId,
+) -> u32 {
+    match tool_id {
+        ToolId::A2148RockyHelmet => 20,
+        ToolId::A3146PoisonBarb => {
+            let attacker = state.get_active(attacking_player);
+            if can_be_affected_by_status(attacker) {
+                debug!("Poison Barb poisons the attacking Pokemon");
+                state.update_pokemon(attacking_player, 0, |x| x.poisoned = true);
+            }
+            0
+        }
+        _ => 0,
+    }
+}
+
+/// Tool effects at the end of the current player's turn (before the Pokémon Checkup).
+pub(crate) fn on_end_turn_tools(state: &mut State) {
+    for player in [state.current_player, (state.current_player + 1) % 2] {
+        for in_play_idx in 0..state.in_play_pokemon[player].len() {
+            let is_own_active = in_play_idx == 0 && player == state.current_player;
+            let Some(pokemon) = state.in_play_pokemon[player][in_play_idx].as_ref() else {
+                continue;
+            };
+            match pokemon.attached_tool {
+                Some(ToolId::A2149LumBerry) if pokemon.has_status_condition() => {
+                    debug!("Lum Berry cures {}'s Pokemon {}", player, in_play_idx);
+                    state.update_pokemon(player, in_play_idx, |pokemon| {
+                        pokemon.poisoned = false;
+                        pokemon.paralyzed = false;
+                        pokemon.asleep = false;
+                        pokemon.burned = false;
+                        pokemon.confused = false;
+                    });
+                    discard_tool(state, player, in_play_idx);
+                }
+                Some(ToolId::A3b067Leftovers) if is_own_active => {
+                    state.update_pokemon(player, in_play_idx, |pokemon| pokemon.heal(10));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Called when `player`'s Pokémon at `in_play_idx` is Knocked Out by damage from an attack,
+/// before it leaves play. Returns the choices that the player has to make because of its tool.
+pub(crate) fn on_tool_knockout(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+) -> Option<Vec<SimpleAction>> {
+    let bench: Vec<usize> = state
+        .enumerate_bench_pokemon(player)
+        .map(|(i, _)| i)
+        .collect();
+    let pokemon = state.in_play_pokemon[player][in_play_idx]
+        .as_ref()
+        .expect("Pokemon should be there if knocked out");
+    match pokemon.attached_tool {
+        Some(ToolId::A3a065ElectricalCord)
+            if in_play_idx == 0 && is_type(&pokemon.card, EnergyType::Lightning) =>
+        {
+            // Move 2 Lightning energy, 1 each to 2 Benched Pokemon
+            let lightning_count = pokemon
+                .attached_energy
+                .iter()
+                .filter(|x| **x == EnergyType::Lightning)
+                .count();
+            let to_move = lightning_count.min(2).min(bench.len());
+            if to_move == 0 {
+                return None;
+            }
+            for _ in 0..to_move {
+                state.discard_energy(player, in_play_idx, EnergyType::Lightning);
+            }
+            let targets: Vec<Vec<usize>> = if to_move == 1 {
+                bench.iter().map(|&i| vec![i]).collect()
+            } else {
+                let mut pairs = vec![];
+                for (n, &i) in bench.iter().enumerate() {
+                    for &j in &bench[n + 1..] {
+                        pairs.push(vec![i, j]);
+                    }
+                }
+                pairs
+            };
+            let choices = targets
+                .into_iter()
+                .map(|targets| SimpleAction::Attach {
+                    attachments: targets
+                        .into_iter()
+                        .map(|i| (1, EnergyType::Lightning, i))
+                        .collect(),
+                    is_turn_energy: false,
+                })
+                .collect();
+            Some(choices)
+        }
+        _ => None,
+    }
+}
+
+/// Removes the tool attached to the Pokémon and puts it in the discard pile.
+fn discard_tool(state: &mut State, player: usize, in_play_idx: usize) {
+    let tool_id = state.update_pokemon(player, in_play_idx, |pokemon| {
+        let tool_id = pokemon.attached_tool.take()?;
+        let hp_bonus = tool_hp_bonus(tool_id, &pokemon.card);
+        pokemon.total_hp -= hp_bonus;
+        pokemon.remaining_hp = pokemon.remaining_hp.min(pokemon.total_hp);
+        Some(tool_id)
+    });
+    if let Some(tool_id) = tool_id {
+        state.push_card(Zone::DiscardPile, player, tool_id.card_id().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card};
+
+    use super::*;
+
+    fn played_card_with_tool(card_id: CardId, tool_id: ToolId) -> PlayedCard {
+        PlayedCard {
+            attached_tool: Some(tool_id),
+            ..to_playable_card(get_card_by_enum(card_id), false)
+        }
+    }
+
+    #[test]
+    fn test_leaf_cape_only_helps_grass_pokemon() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let charmander = get_card_by_enum(CardId::A1033Charmander);
+        assert_eq!(tool_hp_bonus(ToolId::A3147LeafCape, &bulbasaur), 30);
+        assert_eq!(tool_hp_bonus(ToolId::A3147LeafCape, &charmander), 0);
+        assert_eq!(tool_hp_bonus(ToolId::A2147GiantCape, &charmander), 20);
+    }
+
+    #[test]
+    fn test_lum_berry_cures_and_is_discarded() {
+        let mut state = State::default();
+        let mut pokemon = played_card_with_tool(CardId::A1001Bulbasaur, ToolId::A2149LumBerry);
+        pokemon.poisoned = true;
+        pokemon.asleep = true;
+        state.in_play_pokemon[1][1] = Some(pokemon);
+
+        on_end_turn_tools(&mut state);
+
+        let pokemon = state.in_play_pokemon[1][1].as_ref().unwrap();
+        assert!(!pokemon.poisoned && !pokemon.asleep);
+        assert!(!pokemon.has_tool_attached());
+        assert_eq!(
+            state.discard_piles[1],
+            vec![get_card_by_enum(CardId::A2149LumBerry)]
+        );
+    }
+
+    #[test]
+    fn test_leftovers_heal_only_active_at_end_of_own_turn() {
+        let mut state = State::default();
+        for player in 0..2 {
+            for in_play_idx in 0..2 {
+                let mut pokemon =
+                    played_card_with_tool(CardId::A1001Bulbasaur, ToolId::A3b067Leftovers);
+                pokemon.apply_damage(30);
+                state.in_play_pokemon[player][in_play_idx] = Some(pokemon);
+            }
+        }
+
+        on_end_turn_tools(&mut state);
+
+        assert_eq!(state.get_remaining_hp(0, 0), 50);
+        assert_eq!(state.get_remaining_hp(0, 1), 40);
+        assert_eq!(state.get_remaining_hp(1, 0), 40);
+    }
+
+    #[test]
+    fn test_electrical_cord_moves_lightning_energy_to_bench() {
+        let mut state = State::default();
+        let mut pikachu = played_card_with_tool(CardId::A1094Pikachu, ToolId::A3a065ElectricalCord);
+        pikachu.attached_energy = vec![EnergyType::Lightning; 3];
+        state.in_play_pokemon[0][0] = Some(pikachu);
+        for in_play_idx in 1..4 {
+            let bench = to_playable_card(get_card_by_enum(CardId::A1094Pikachu), false);
+            state.in_play_pokemon[0][in_play_idx] = Some(bench);
+        }
+
+        let choices = on_tool_knockout(&mut state, 0, 0).expect("Should move energy");
+        assert_eq!(choices.len(), 3); // one per pair of Benched Pokemon
+        assert_eq!(
+            choices[0],
+            SimpleAction::Attach {
+                attachments: vec![(1, EnergyType::Lightning, 1), (1, EnergyType::Lightning, 2)],
+                is_turn_energy: false,
+            }
+        );
+        assert_eq!(state.get_active(0).attached_energy.len(), 1);
+    }
+}
@@ -2,25 +2,37 @@ mod ability_ids;
 pub mod actions;
 mod attack_ids;
 pub mod card_ids;
+pub mod coverage;
 pub mod database;
 pub mod deck;
+pub mod effects;
+mod error;
 pub mod game;
+pub mod game_record;
 mod hooks;
 pub mod move_generation;
+pub mod observation;
 mod optimize;
 pub mod players;
+pub mod rules;
 pub mod simulate;
 pub mod state;
 pub mod test_helpers; // TODO: Compile/Expose only in test mode?
 pub mod tool_ids;
 pub mod types;
+mod zobrist;
 
 pub use ability_ids::AbilityId;
 pub use attack_ids::AttackId;
 pub use deck::Deck;
+pub use error::DeckgymError;
 pub use game::Game;
+pub use game_record::GameRecord;
 pub use move_generation::generate_possible_actions;
 pub use move_generation::generate_possible_trainer_actions;
+pub use move_generation::try_generate_possible_actions;
+pub use observation::Observation;
 pub use optimize::optimize;
-pub use simulate::simulate;
+pub use rules::RuleSet;
+pub use simulate::{simulate, SimulationOptions, SimulationResults};
 pub use state::State;
@@ -1,9 +1,11 @@
 use clap::{ArgAction, Parser, Subcommand};
 use colored::Colorize;
-use deckgym::players::{parse_player_code, PlayerCode};
-use deckgym::{optimize, simulate};
-use env_logger::{Builder, Env};
-use log::warn;
+use deckgym::coverage::coverage_report;
+use deckgym::database::{set_card_database, CardDatabase};
+use deckgym::players::{is_searching, parse_player_code, PlayerCode};
+use deckgym::{optimize, simulate, Game, GameRecord, SimulationOptions};
+use env_logger::{Builder, Env, Logger};
+use log::{error, warn, Log, Metadata, Record};
 use std::io::Write;
 
 #[derive(Parser, Debug)]
@@ -11,6 +13,15 @@ use std::io::Write;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Card database (in the database.json format) to use instead of the built-in one
+    #[arg(long, global = true)]
+    database: Option<String>,
+
+    /// Extra cards (in the database.json format) that add to or replace cards of the database.
+    /// Attacks without an implementation deal their fixed damage (with --allow-partial)
+    #[arg(long, global = true)]
+    overlay: Vec<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -31,10 +42,22 @@ enum Commands {
         #[arg(short, long)]
         num: u32,
 
-        /// Seed for random number generation
+        /// Seed for random number generation (each game gets its own seed derived from it)
         #[arg(short, long)]
         seed: Option<u64>,
 
+        /// Number of threads to run the games on (results don't depend on it)
+        #[arg(long, default_value_t = 1)]
+        threads: usize,
+
+        /// Folder to write a JSON game record of each game into
+        #[arg(long)]
+        record: Option<String>,
+
+        /// Play decks with unimplemented cards (attacks deal fixed damage, trainers are skipped)
+        #[arg(long)]
+        allow_partial: bool,
+
         /// Increase verbosity (-v, -vv, -vvv, etc.)
         #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
         verbose: u8,
@@ -62,14 +85,45 @@ enum Commands {
         #[arg(short, long)]
         seed: Option<u64>,
 
+        /// Play decks with unimplemented cards (attacks deal fixed damage, trainers are skipped)
+        #[arg(long)]
+        allow_partial: bool,
+
+        /// File to save the best completed deck into (in the same text format as the inputs)
+        #[arg(short, long)]
+        output: Option<String>,
+
         /// Increase verbosity (-v, -vv, -vvv, etc.)
         #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
         verbose: u8,
     },
+    /// Replay a recorded game and check that it reproduces
+    Replay {
+        /// Path to the JSON game record
+        file: String,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Report which cards of the database are implemented
+    Coverage {
+        /// Print the full report as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// List the attacks, abilities and trainers that are not implemented
+        #[arg(long)]
+        missing: bool,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
+    if let Err(err) = load_card_database(cli.database.as_deref(), &cli.overlay) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
 
     // Branch depending on the chosen subcommand.
     match cli.command {
@@ -79,13 +133,27 @@ fn main() {
             players,
             num,
             seed,
+            threads,
+            record,
+            allow_partial,
             verbose,
         } => {
             initialize_logger(verbose);
 
             warn!("Welcome to {} simulation!", "deckgym".blue().bold());
 
-            simulate(&deck_a, &deck_b, players, num, seed);
+            let options = SimulationOptions {
+                players,
+                num_simulations: num,
+                seed,
+                record_folder: record,
+                allow_partial,
+                threads,
+            };
+            if let Err(err) = simulate(&deck_a, &deck_b, &options) {
+                error!("{}", err);
+                std::process::exit(1);
+            }
         }
         Commands::Optimize {
             incomplete_deck,
@@ -94,24 +162,99 @@ fn main() {
             num,
             players,
             seed,
+            allow_partial,
+            output,
             verbose,
         } => {
             initialize_logger(verbose);
 
             warn!("Welcome to {} optimizer!", "deckgym".blue().bold());
 
-            optimize(
+            let best_deck = optimize(
                 &incomplete_deck,
                 &candidate_cards,
                 &enemy_decks_folder,
                 num,
                 players,
                 seed,
+                allow_partial,
+            )
+            .and_then(|best_deck| match (best_deck, output) {
+                (Some(deck), Some(path)) => deck.to_file(&path).map(|_| Some(path)),
+                _ => Ok(None),
+            });
+            match best_deck {
+                Ok(Some(path)) => warn!("Saved best deck to {}", path),
+                Ok(None) => {}
+                Err(err) => {
+                    error!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Replay { file, verbose } => {
+            initialize_logger(verbose);
+
+            let record = GameRecord::from_file(&file).expect("Failed to load game record");
+            match Game::replay(&record) {
+                Ok(outcome) => warn!(
+                    "Replayed {} actions. Outcome matches record: {:?}",
+                    record.actions.len(),
+                    outcome
+                ),
+                Err(err) => {
+                    error!("Replay does not match record: {}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Coverage { json, missing } => {
+            let report = coverage_report();
+            if json {
+                println!("{}", report.to_json().expect("Failed to serialize report"));
+                return;
+            }
+
+            if missing {
+                for card in report.cards.iter().filter(|x| !x.is_implemented()) {
+                    println!("{}", card.describe_missing());
+                }
+                println!();
+            }
+            for set in &report.sets {
+                println!(
+                    "{:<32} {:>4} / {:<4} ({:.2}%)",
+                    set.set,
+                    set.implemented,
+                    set.total,
+                    set.implemented as f32 / set.total as f32 * 100.0
+                );
+            }
+            println!(
+                "{:<32} {:>4} / {:<4} ({:.2}%)",
+                "Total",
+                report.implemented,
+                report.total,
+                report.implemented as f32 / report.total as f32 * 100.0
             );
         }
     }
 }
 
+fn load_card_database(database: Option<&str>, overlays: &[String]) -> Result<(), String> {
+    if database.is_none() && overlays.is_empty() {
+        return Ok(());
+    }
+    let mut card_database = match database {
+        Some(path) => CardDatabase::from_file(path)?,
+        None => CardDatabase::default(),
+    };
+    for path in overlays {
+        card_database.extend_from_file(path)?;
+    }
+    set_card_database(card_database)
+}
+
 // Set up the logger according to the given verbosity.
 fn initialize_logger(verbose: u8) {
     let level = match verbose {
@@ -120,7 +263,29 @@ fn initialize_logger(verbose: u8) {
         3 => "debug",
         _ => "trace",
     };
-    Builder::from_env(Env::default().default_filter_or(level))
+    let logger = Builder::from_env(Env::default().default_filter_or(level))
         .format(|buf, record| writeln!(buf, "{}", record.args()))
-        .init();
+        .build();
+    log::set_max_level(logger.filter());
+    log::set_boxed_logger(Box::new(GameLogger(logger))).expect("Logger should be set once");
+}
+
+// Leaves out what is logged while a search player looks ahead, so that the moves it only
+// tries don't read as played.
+struct GameLogger(Logger);
+
+impl Log for GameLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        !is_searching() && self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !is_searching() {
+            self.0.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.0.flush();
+    }
 }
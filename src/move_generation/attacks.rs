@@ -8,6 +8,9 @@ pub(crate) fn generate_attack_actions(state: &State) -> Vec<SimpleAction> {
     let current_player = state.current_player;
     let mut actions = Vec::new();
     if let Some(active_pokemon) = &state.in_play_pokemon[current_player][0] {
+        if active_pokemon.is_asleep_or_paralyzed() {
+            return actions;
+        }
         active_pokemon
             .get_attacks()
             .iter()
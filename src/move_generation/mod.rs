@@ -3,9 +3,10 @@ mod move_generation_abilities;
 mod move_generation_trainer;
 
 use crate::actions::{Action, SimpleAction};
+use crate::error::DeckgymError;
 use crate::hooks::{can_retreat, contains_energy, get_retreat_cost};
 use crate::state::State;
-use crate::types::Card;
+use crate::types::{Card, CardRef};
 
 use attacks::generate_attack_actions;
 use move_generation_abilities::generate_ability_actions;
@@ -19,9 +20,15 @@ pub use move_generation_trainer::generate_possible_trainer_actions;
 /// # Returns
 /// * A tuple containing the current player and a list of possible actions
 pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
+    try_generate_possible_actions(state).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Like `generate_possible_actions`, but returns an error if a card in hand or in play
+/// is not implemented.
+pub fn try_generate_possible_actions(state: &State) -> Result<(usize, Vec<Action>), DeckgymError> {
     let in_initial_setup_phase = state.turn_count == 0;
     if in_initial_setup_phase {
-        let possible_actions = generate_initial_setup_actions(state)
+        let possible_actions = generate_initial_setup_actions(state)?
             .iter()
             .map(|action| Action {
                 actor: state.current_player,
@@ -29,7 +36,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
                 is_stack: false,
             })
             .collect();
-        return (state.current_player, possible_actions);
+        return Ok((state.current_player, possible_actions));
     }
 
     // If there are moves in the generation stack, short-circuit to that
@@ -42,7 +49,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
                 is_stack: true,
             })
             .collect();
-        return (*actor, actions);
+        return Ok((*actor, actions));
     }
 
     // Free play actions. User can always end turn.
@@ -50,7 +57,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     let mut actions = vec![SimpleAction::EndTurn];
 
     // Hand actions (Play Support Cards, Trainer, or Place Pokemons in mat)
-    let hand_actions = generate_hand_actions(state);
+    let hand_actions = generate_hand_actions(state)?;
     actions.extend(hand_actions);
 
     // Maybe attach energy to in play cards
@@ -89,7 +96,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     actions.extend(attack_actions);
 
     // Add actions given by abilities
-    let ability_actions = generate_ability_actions(state);
+    let ability_actions = generate_ability_actions(state)?;
     actions.extend(ability_actions);
 
     let possible_actions = actions
@@ -100,19 +107,19 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
             is_stack: false,
         })
         .collect();
-    (current_player, possible_actions)
+    Ok((current_player, possible_actions))
 }
 
-fn generate_initial_setup_actions(state: &State) -> Vec<SimpleAction> {
+fn generate_initial_setup_actions(state: &State) -> Result<Vec<SimpleAction>, DeckgymError> {
     let current_player = state.current_player;
-    let hand_actions = generate_hand_actions(state);
+    let hand_actions = generate_hand_actions(state)?;
     if state.in_play_pokemon[current_player][0].is_none() {
         let place_active_actions: Vec<SimpleAction> = hand_actions
             .iter()
             .filter(|x| matches!(x, SimpleAction::Place(_, 0)))
             .cloned()
             .collect();
-        place_active_actions
+        Ok(place_active_actions)
     } else {
         let mut actions = Vec::new();
         let place_bench_actions: Vec<SimpleAction> = hand_actions
@@ -128,33 +135,33 @@ fn generate_initial_setup_actions(state: &State) -> Vec<SimpleAction> {
             .collect();
         actions.extend(place_bench_actions);
         actions.push(SimpleAction::EndTurn);
-        actions
+        Ok(actions)
     }
 }
 
-fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
+fn generate_hand_actions(state: &State) -> Result<Vec<SimpleAction>, DeckgymError> {
     let current_player = state.current_player;
     let mut actions = Vec::new();
 
-    get_current_hand(state)
-        .iter()
-        .for_each(|hand_card| match hand_card {
+    for hand_card in get_current_hand(state) {
+        match hand_card.card() {
             Card::Pokemon(pokemon_card) => {
                 // Basic pokemons can be placed in empty Active or Bench slots
                 if pokemon_card.stage == 0 {
                     state.in_play_pokemon[current_player]
                         .iter()
                         .enumerate()
+                        .take(state.rules.bench_size + 1)
                         .for_each(|(i, x)| {
                             if x.is_none() {
-                                actions.push(SimpleAction::Place(hand_card.clone(), i));
+                                actions.push(SimpleAction::Place(*hand_card, i));
                             }
                         });
                 } else {
                     // Evolutions can only be played if previous stage
                     // is there, and wasn't played this turn, and isn't the first 2 turns.
-                    if state.turn_count <= 2 {
-                        return;
+                    if state.turn_count < state.rules.first_evolution_turn {
+                        continue;
                     }
                     // For each non-zero stage pokemon in hand, check if the evolves_from is in play
                     // if so, add evolve action
@@ -167,7 +174,7 @@ fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
                                     if !pokemon.played_this_turn
                                         && pokemon.get_name() == *evolves_from
                                     {
-                                        actions.push(SimpleAction::Evolve(hand_card.clone(), i));
+                                        actions.push(SimpleAction::Evolve(*hand_card, i));
                                     }
                                 }
                             });
@@ -175,14 +182,145 @@ fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
                 }
             }
             Card::Trainer(trainer_card) => {
-                let trainer_actions = generate_possible_trainer_actions(state, trainer_card)
-                    .expect("Trainer card not implemented");
-                actions.extend(trainer_actions);
+                match generate_possible_trainer_actions(state, trainer_card) {
+                    Some(trainer_actions) => actions.extend(trainer_actions),
+                    None if state.allow_partial => {} // Unimplemented trainers can't be played
+                    None => return Err(DeckgymError::UnsupportedTrainer(trainer_card.id.clone())),
+                }
             }
-        });
-    actions
+        }
+    }
+    Ok(actions)
 }
 
-fn get_current_hand(state: &State) -> &Vec<Card> {
+fn get_current_hand(state: &State) -> &Vec<CardRef> {
     &state.hands[state.current_player]
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        hooks::to_playable_card,
+        rules::RuleSet,
+        tool_ids::ToolId,
+        types::{Card, EnergyType, PlayedCard},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_hand_actions_follow_rules() {
+        let bulbasaur: CardRef = get_card_by_enum(CardId::A1001Bulbasaur).into();
+        let ivysaur: CardRef = get_card_by_enum(CardId::A1002Ivysaur).into();
+        let mut state = State {
+            turn_count: 3,
+            ..Default::default()
+        };
+        state.in_play_pokemon[0][0] = Some(to_playable_card(bulbasaur, false));
+        state.hands[0] = vec![bulbasaur, ivysaur];
+
+        let actions = generate_hand_actions(&state).unwrap();
+        assert!(actions.contains(&SimpleAction::Place(bulbasaur, 3)));
+        assert!(actions.contains(&SimpleAction::Evolve(ivysaur, 0)));
+
+        // Smaller bench and evolutions only from turn 5 onwards
+        state.rules = RuleSet {
+            bench_size: 1,
+            first_evolution_turn: 5,
+            ..Default::default()
+        };
+        let actions = generate_hand_actions(&state).unwrap();
+        assert_eq!(actions, vec![SimpleAction::Place(bulbasaur, 1)]);
+    }
+
+    #[test]
+    fn test_tools_are_offered_only_with_a_pokemon_without_tool() {
+        let Card::Trainer(giant_cape) = get_card_by_enum(CardId::A2147GiantCape) else {
+            panic!("Giant Cape should be a trainer");
+        };
+        let bulbasaur = to_playable_card(get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        let play = SimpleAction::Play {
+            trainer_card: giant_cape.clone(),
+        };
+        assert_eq!(
+            generate_possible_trainer_actions(&state, &giant_cape),
+            Some(vec![play])
+        );
+
+        state.in_play_pokemon[0][0] = Some(PlayedCard {
+            attached_tool: Some(ToolId::A2148RockyHelmet),
+            ..bulbasaur
+        });
+        assert_eq!(
+            generate_possible_trainer_actions(&state, &giant_cape),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_koga_needs_weezing_or_muk_in_the_active_spot() {
+        let Card::Trainer(koga) = get_card_by_enum(CardId::A1222Koga) else {
+            panic!("Koga should be a trainer");
+        };
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(to_playable_card(
+            get_card_by_enum(CardId::A1177Weezing),
+            false,
+        ));
+        assert_eq!(
+            generate_possible_trainer_actions(&state, &koga).map(|x| x.len()),
+            Some(1)
+        );
+
+        // A card outside the database (e.g. from an overlay) has no CardId
+        let Card::Pokemon(mut protomon) = get_card_by_enum(CardId::A1001Bulbasaur) else {
+            panic!("Bulbasaur should be a Pokemon");
+        };
+        protomon.id = "X1 001".into();
+        let protomon: CardRef = Card::Pokemon(protomon).into();
+        state.in_play_pokemon[0][0] = Some(to_playable_card(protomon, false));
+        assert_eq!(
+            generate_possible_trainer_actions(&state, &koga),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_asleep_or_paralyzed_active_cant_attack_or_retreat() {
+        // Bulbasaur attacks with two energies (one Grass) and retreats with one
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut state = State {
+            turn_count: 3,
+            ..Default::default()
+        };
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&bulbasaur, false));
+        let can_attack_or_retreat = |state: &State| {
+            let (_, actions) = generate_possible_actions(state);
+            let actions: Vec<_> = actions.into_iter().map(|x| x.action).collect();
+            (
+                actions.contains(&SimpleAction::Attack(0)),
+                actions.contains(&SimpleAction::Retreat(1)),
+            )
+        };
+        assert_eq!(can_attack_or_retreat(&state), (true, true));
+
+        state.get_active_mut(0).asleep = true;
+        assert_eq!(can_attack_or_retreat(&state), (false, false));
+
+        state.get_active_mut(0).asleep = false;
+        state.get_active_mut(0).paralyzed = true;
+        assert_eq!(can_attack_or_retreat(&state), (false, false));
+
+        // Poison doesn't restrict actions
+        state.get_active_mut(0).paralyzed = false;
+        state.get_active_mut(0).poisoned = true;
+        assert_eq!(can_attack_or_retreat(&state), (true, true));
+    }
+}
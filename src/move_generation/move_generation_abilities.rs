@@ -1,27 +1,42 @@
-use crate::{ability_ids::AbilityId, actions::SimpleAction, types::PlayedCard, State};
+use crate::{
+    ability_ids::AbilityId, actions::SimpleAction, error::DeckgymError, hooks::ability_trigger,
+    types::PlayedCard, State,
+};
 
-// Use the new function in the filter method
-pub(crate) fn generate_ability_actions(state: &State) -> Vec<SimpleAction> {
+pub(crate) fn generate_ability_actions(state: &State) -> Result<Vec<SimpleAction>, DeckgymError> {
     let current_player = state.current_player;
-    state
-        .enumerate_in_play_pokemon(current_player)
-        .filter(can_use_ability)
-        .map(|(i, _)| SimpleAction::UseAbility(i))
-        .collect()
+    let mut actions = vec![];
+    for in_play_pokemon in state.enumerate_in_play_pokemon(current_player) {
+        if can_use_ability(state, &in_play_pokemon)? {
+            actions.push(SimpleAction::UseAbility(in_play_pokemon.0));
+        }
+    }
+    Ok(actions)
 }
 
-fn can_use_ability((in_play_index, card): &(usize, &PlayedCard)) -> bool {
+fn can_use_ability(
+    state: &State,
+    (in_play_index, card): &(usize, &PlayedCard),
+) -> Result<bool, DeckgymError> {
     if card.card.get_ability().is_none() {
-        return false;
+        return Ok(false);
     }
 
     let is_active = *in_play_index == 0;
-    let ability = AbilityId::from_pokemon_id(&card.card.get_id()[..]);
-    match ability {
-        Some(AbilityId::A1007Butterfree) => !card.ability_used,
-        Some(AbilityId::A1177Weezing) => is_active && !card.ability_used,
-        Some(AbilityId::A1132Gardevoir) => !card.ability_used,
-        Some(AbilityId::A2a071Arceus) => false,
-        None => panic!("Ability not implemented"),
+    let Some(ability_id) = AbilityId::from_pokemon_id(card.card.get_id_str())
+        .filter(|&ability_id| ability_trigger(ability_id).is_some())
+    else {
+        return if state.allow_partial {
+            Ok(false)
+        } else {
+            Err(DeckgymError::UnsupportedAbility(card.get_id()))
+        };
+    };
+    match ability_id {
+        AbilityId::A1007Butterfree => Ok(!card.ability_used),
+        AbilityId::A1177Weezing => Ok(is_active && !card.ability_used),
+        AbilityId::A1132Gardevoir => Ok(!card.ability_used),
+        // The rest trigger on their own (see `AbilityTrigger`)
+        _ => Ok(false),
     }
 }
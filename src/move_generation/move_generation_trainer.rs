@@ -1,8 +1,8 @@
 use crate::{
     actions::SimpleAction,
     card_ids::CardId,
-    database::get_card_by_enum,
     hooks::can_play_support,
+    tool_ids::ToolId,
     types::{EnergyType, TrainerCard, TrainerType},
     State,
 };
@@ -21,9 +21,10 @@ pub fn generate_possible_trainer_actions(
 
     // Pokemon tools can be played if there is a space in the mat for them.
     if trainer_card.trainer_card_type == TrainerType::Tool {
+        ToolId::from_trainer_card(trainer_card)?;
         let in_play_without_tools = state
             .enumerate_in_play_pokemon(state.current_player)
-            .filter(|(_, x)| x.has_tool_attached())
+            .filter(|(_, x)| !x.has_tool_attached())
             .count();
         if in_play_without_tools > 0 {
             return Some(vec![SimpleAction::Play {
@@ -80,12 +81,10 @@ pub fn generate_possible_trainer_actions(
             // If Koga, confirm that Active pokemon is Weezing or Muk
             let active_pokemon = &state.in_play_pokemon[state.current_player][0];
             if let Some(played_card) = active_pokemon {
-                let kogable_cards = vec![
-                    get_card_by_enum(CardId::A1177Weezing),
-                    get_card_by_enum(CardId::A1243Weezing),
-                    get_card_by_enum(CardId::A1175Muk),
-                ];
-                if kogable_cards.contains(&played_card.card) {
+                let kogable_cards = [CardId::A1177Weezing, CardId::A1243Weezing, CardId::A1175Muk];
+                // Cards outside the built-in database (e.g. from an overlay) have no CardId
+                let card_id = CardId::from_card_id(played_card.card.get_id_str());
+                if card_id.is_some_and(|x| kogable_cards.contains(&x)) {
                     return Some(vec![SimpleAction::Play {
                         trainer_card: trainer_card.clone(),
                     }]);
@@ -0,0 +1,320 @@
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::SimpleAction,
+    effects::Effect,
+    rules::RuleSet,
+    state::GameOutcome,
+    types::{CardRef, EnergyType, PlayedCard},
+    Deck, State,
+};
+
+/// What a single player can see of the game. Keeps public information only (board,
+/// discard piles, sizes of hands and decks, revealed cards) plus the player's own hand.
+/// The opponent's hand, the deck order and the prize cards are hidden.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    /// The player this observation belongs to.
+    pub player: usize,
+
+    pub winner: Option<GameOutcome>,
+    pub points: [u8; 2],
+    pub turn_count: u8,
+    pub current_player: usize,
+    pub move_generation_stack: Vec<(usize, Vec<SimpleAction>)>,
+
+    pub current_energy: Option<EnergyType>,
+    pub next_energy: [Option<EnergyType>; 2],
+    pub energy_types: [Vec<EnergyType>; 2],
+
+    pub hand: Vec<CardRef>,
+    /// Cards left in the player's own deck, sorted by id (the order is hidden).
+    pub deck: Vec<CardRef>,
+    pub hand_sizes: [usize; 2],
+    pub deck_sizes: [usize; 2],
+    pub prize_card_counts: [usize; 2],
+    pub discard_piles: [Vec<CardRef>; 2],
+    pub in_play_pokemon: [[Option<PlayedCard>; 4]; 2],
+    /// Cards known to be in each player's hand (e.g. searched with Poké Ball).
+    pub revealed_cards: [Vec<CardRef>; 2],
+
+    pub has_played_support: bool,
+    pub has_retreated: bool,
+    pub effects: Vec<Effect>,
+    pub rules: RuleSet,
+    pub allow_partial: bool,
+}
+
+impl State {
+    /// Returns the view of the game that `player` is allowed to see.
+    pub fn observation(&self, player: usize) -> Observation {
+        Observation {
+            player,
+            winner: self.winner,
+            points: self.points,
+            turn_count: self.turn_count,
+            current_player: self.current_player,
+            move_generation_stack: self.move_generation_stack.clone(),
+            current_energy: self.current_energy,
+            next_energy: self.next_energy,
+            energy_types: [
+                self.decks[0].energy_types.clone(),
+                self.decks[1].energy_types.clone(),
+            ],
+            hand: self.hands[player].clone(),
+            deck: sorted_by_id(&self.decks[player].cards),
+            hand_sizes: [self.hands[0].len(), self.hands[1].len()],
+            deck_sizes: [self.decks[0].cards.len(), self.decks[1].cards.len()],
+            prize_card_counts: [self.prize_cards[0].len(), self.prize_cards[1].len()],
+            discard_piles: self.discard_piles.clone(),
+            in_play_pokemon: self.in_play_pokemon.clone(),
+            revealed_cards: self.revealed_cards.clone(),
+            has_played_support: self.has_played_support,
+            has_retreated: self.has_retreated,
+            effects: self.effects.clone(),
+            rules: self.rules,
+            allow_partial: self.allow_partial,
+        }
+    }
+}
+
+impl Observation {
+    pub fn opponent(&self) -> usize {
+        (self.player + 1) % 2
+    }
+
+    pub fn enumerate_in_play_pokemon(
+        &self,
+        player: usize,
+    ) -> impl Iterator<Item = (usize, &PlayedCard)> {
+        self.in_play_pokemon[player]
+            .iter()
+            .enumerate()
+            .filter_map(|(i, x)| x.as_ref().map(|x| (i, x)))
+    }
+
+    /// Samples a full `State` consistent with this observation, so that search players can
+    /// run the engine forward without peeking at hidden information.
+    ///
+    /// The opponent's hidden cards (hand, deck and prizes) are drawn from `opponent_deck`
+    /// after removing the cards already public: discard pile, in-play cards (including
+    /// evolution chains and attached tools) and revealed hand cards. If the belief runs short (or is `None`),
+    /// the remaining slots are filled with copies of the cards the opponent has shown.
+    /// Our own deck is shuffled, as its order is unknown to us too.
+    pub fn determinize(
+        &self,
+        opponent_deck: Option<&Deck>,
+        rng: &mut impl Rng,
+    ) -> Result<State, String> {
+        let me = self.player;
+        let opponent = self.opponent();
+
+        // Cards of the opponent everyone can see
+        let mut public_cards = self.discard_piles[opponent].clone();
+        for (_, pokemon) in self.enumerate_in_play_pokemon(opponent) {
+            public_cards.extend(pokemon.cards_behind.iter().cloned());
+            public_cards.push(pokemon.card);
+            public_cards.extend(pokemon.attached_tool.map(|x| CardRef::from(x.card_id())));
+        }
+        public_cards.extend(self.revealed_cards[opponent].iter().cloned());
+
+        let mut pool = opponent_deck.map(|x| x.cards.clone()).unwrap_or_default();
+        for card in &public_cards {
+            if let Some(pos) = pool.iter().position(|x| x == card) {
+                pool.swap_remove(pos);
+            }
+        }
+        pool.shuffle(rng);
+
+        let num_hidden_in_hand = self.hand_sizes[opponent]
+            .checked_sub(self.revealed_cards[opponent].len())
+            .ok_or("Opponent has more revealed cards than cards in hand")?;
+        let num_hidden =
+            num_hidden_in_hand + self.deck_sizes[opponent] + self.prize_card_counts[opponent];
+        if pool.len() < num_hidden {
+            let mut candidates: Vec<CardRef> = opponent_deck
+                .map(|x| x.cards.clone())
+                .unwrap_or_default()
+                .into_iter()
+                .chain(public_cards)
+                .collect();
+            candidates.sort_by_key(|x| x.get_id());
+            candidates.dedup();
+            if candidates.is_empty() {
+                return Err("No information about the opponent's cards to sample from".into());
+            }
+            while pool.len() < num_hidden {
+                pool.push(*candidates.choose(rng).unwrap());
+            }
+        }
+        pool.truncate(num_hidden);
+
+        let mut own_deck = self.deck.clone();
+        own_deck.shuffle(rng);
+
+        let mut decks = [Deck::default(), Deck::default()];
+        decks[me] = Deck {
+            cards: own_deck,
+            energy_types: self.energy_types[me].clone(),
+            ..Default::default()
+        };
+        let mut opponent_hand = self.revealed_cards[opponent].clone();
+        opponent_hand.extend(pool.drain(..num_hidden_in_hand));
+        let opponent_prizes = pool.split_off(self.deck_sizes[opponent]);
+        decks[opponent] = Deck {
+            cards: pool,
+            energy_types: self.energy_types[opponent].clone(),
+            ..Default::default()
+        };
+
+        let mut state = State::new(&decks[0], &decks[1]);
+        state.winner = self.winner;
+        state.points = self.points;
+        state.turn_count = self.turn_count;
+        state.current_player = self.current_player;
+        state.move_generation_stack = self.move_generation_stack.clone();
+        state.current_energy = self.current_energy;
+        state.next_energy = self.next_energy;
+        state.hands[me] = self.hand.clone();
+        state.hands[opponent] = opponent_hand;
+        state.prize_cards[opponent] = opponent_prizes;
+        state.discard_piles = self.discard_piles.clone();
+        state.in_play_pokemon = self.in_play_pokemon.clone();
+        state.revealed_cards = self.revealed_cards.clone();
+        state.has_played_support = self.has_played_support;
+        state.has_retreated = self.has_retreated;
+        state.effects = self.effects.clone();
+        state.rules = self.rules;
+        state.allow_partial = self.allow_partial;
+        state.rehash();
+        Ok(state)
+    }
+}
+
+fn sorted_by_id(cards: &[CardRef]) -> Vec<CardRef> {
+    let mut cards = cards.to_vec();
+    cards.sort_by_key(|x| x.get_id());
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        actions::apply_action, card_ids::CardId, generate_possible_actions,
+        hooks::to_playable_card, test_helpers::load_test_decks, tool_ids::ToolId,
+    };
+
+    use super::*;
+
+    fn play_some_turns(rng: &mut StdRng, num_actions: usize) -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, rng);
+        for _ in 0..num_actions {
+            if state.is_game_over() {
+                break;
+            }
+            let (_, actions) = generate_possible_actions(&state);
+            let action = actions.choose(rng).unwrap().clone();
+            apply_action(rng, &mut state, &action);
+        }
+        state
+    }
+
+    #[test]
+    fn test_observation_hides_opponent_hand_and_deck() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rand::thread_rng());
+        let revealed = state.hands[1][0];
+        state.reveal_card_in_hand(1, revealed);
+
+        let observation = state.observation(0);
+
+        assert_eq!(observation.hand, state.hands[0]);
+        assert_eq!(observation.hand_sizes, [5, 5]);
+        assert_eq!(observation.deck_sizes, [15, 15]);
+        assert_eq!(observation.revealed_cards[1], vec![revealed]);
+        assert_eq!(observation.prize_card_counts, [0, 0]);
+    }
+
+    #[test]
+    fn test_revealed_cards_are_forgotten_when_leaving_hand() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rand::thread_rng());
+        let card = state.hands[0][0];
+        state.reveal_card_in_hand(0, card);
+
+        state.discard_card_from_hand(0, card);
+
+        let still_in_hand = state.hands[0].iter().filter(|x| **x == card).count();
+        assert_eq!(
+            state.observation(1).revealed_cards[0].len(),
+            still_in_hand.min(1)
+        );
+    }
+
+    #[test]
+    fn test_determinized_state_is_consistent_with_observation() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut state = play_some_turns(&mut rng, 20);
+        let revealed = state.hands[1][0];
+        state.reveal_card_in_hand(1, revealed);
+        let observation = state.observation(0);
+        let (_, decklist) = load_test_decks();
+
+        let determinized = observation.determinize(Some(&decklist), &mut rng).unwrap();
+
+        assert_eq!(determinized.observation(0), observation);
+        assert!(determinized.hands[1].contains(&revealed));
+        // Hidden cards of the opponent only come from the part of the decklist not yet public
+        let mut hidden: Vec<CardRef> = determinized.hands[1].clone();
+        hidden.extend(determinized.decks[1].cards.clone());
+        let mut expected: Vec<CardRef> = state.hands[1].clone();
+        expected.extend(state.decks[1].cards.clone());
+        assert_eq!(sorted_by_id(&hidden), sorted_by_id(&expected));
+    }
+
+    #[test]
+    fn test_determinize_counts_attached_tools_as_public() {
+        let decklist =
+            Deck::from_string("Energy: Grass\n2 Bulbasaur A1 001\n2 Giant Cape A2 147").unwrap();
+        let bulbasaur: CardRef = CardId::A1001Bulbasaur.into();
+        let giant_cape: CardRef = CardId::A2147GiantCape.into();
+        let (deck_a, _) = load_test_decks();
+        let mut state = State::new(&deck_a, &decklist);
+        state.in_play_pokemon[1][0] = Some(PlayedCard {
+            attached_tool: Some(ToolId::A2147GiantCape),
+            ..to_playable_card(bulbasaur, false)
+        });
+        state.decks[1].cards = vec![bulbasaur, giant_cape];
+        let observation = state.observation(0);
+
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let determinized = observation.determinize(Some(&decklist), &mut rng).unwrap();
+            // The other Giant Cape is attached, so only one can be hidden
+            assert_eq!(
+                sorted_by_id(&determinized.decks[1].cards),
+                vec![bulbasaur, giant_cape]
+            );
+        }
+    }
+
+    #[test]
+    fn test_determinize_without_belief_samples_shown_cards() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let state = play_some_turns(&mut rng, 20);
+        let observation = state.observation(1);
+
+        let determinized = observation.determinize(None, &mut rng).unwrap();
+
+        assert_eq!(determinized.observation(1), observation);
+        assert_eq!(determinized.hands[0].len(), state.hands[0].len());
+        assert_eq!(
+            determinized.decks[0].cards.len(),
+            state.decks[0].cards.len()
+        );
+    }
+}
@@ -5,13 +5,17 @@ use num_format::{Locale, ToFormattedString};
 
 use crate::{
     card_ids::CardId,
+    coverage::check_deck,
     database::get_card_by_enum,
+    deck::DeckIssue,
     players::{create_players, fill_code_array, PlayerCode},
+    simulate::game_seed,
     state::GameOutcome,
     Deck, Game,
 };
 
 /// Optimizes a deck by simulating games with different combinations of candidate cards.
+/// Returns the best completed deck (if any combination was valid).
 pub fn optimize(
     incomplete_deck_path: &str,
     candidate_cards_str: &str,
@@ -19,7 +23,8 @@ pub fn optimize(
     num: u32,
     players: Option<Vec<PlayerCode>>,
     seed: Option<u64>,
-) {
+    allow_partial: bool,
+) -> Result<Option<Deck>, String> {
     // Parse the candidate cards list.
     let candidate_cards: Vec<CardId> = candidate_cards_str
         .split(',')
@@ -47,17 +52,27 @@ pub fn optimize(
     );
     if missing_count == 0 {
         warn!("Deck is already complete (20 cards). No optimization needed.");
-        return;
+        return Ok(None);
     }
+    check_deck(&incomplete_deck, incomplete_deck_path, allow_partial)?;
+    let candidates_deck = Deck {
+        cards: candidate_cards
+            .iter()
+            .map(|x| get_card_by_enum(*x).into())
+            .collect(),
+        ..Default::default()
+    };
+    check_deck(&candidates_deck, "of candidate cards", allow_partial)?;
 
     // For each candidate card, determine how many additional copies are allowed.
-    // A card cannot appear more than twice in the deck.
+    // A card name cannot appear more than twice in the deck (across different prints).
     let mut allowed_map: HashMap<CardId, u32> = HashMap::new();
     for card in &candidate_cards {
+        let name = get_card_by_enum(*card).get_name();
         let count = incomplete_deck
             .cards
             .iter()
-            .filter(|c| c.get_card_id() == *card)
+            .filter(|c| c.get_name() == name)
             .count();
         let allowed = 2_usize.saturating_sub(count);
         allowed_map.insert(*card, allowed as u32);
@@ -78,12 +93,18 @@ pub fn optimize(
     let enemy_valid_decks: Vec<Deck> = enemy_deck_paths
         .iter()
         .filter_map(|path| {
-            let deck = Deck::from_file(path).ok()?;
-            if deck.cards.len() == 20 {
-                Some(deck)
-            } else {
-                warn!("Skipping enemy deck {} since not valid", path);
+            let deck = match Deck::from_legal_file(path) {
+                Ok(deck) => deck,
+                Err(err) => {
+                    warn!("Skipping enemy deck: {}", err);
+                    return None;
+                }
+            };
+            if let Err(err) = check_deck(&deck, path, allow_partial) {
+                warn!("Skipping enemy deck: {}", err);
                 None
+            } else {
+                Some(deck)
             }
         })
         .collect();
@@ -125,7 +146,10 @@ pub fn optimize(
         count_player_types(&player_codes, true)
     );
 
-    // For every valid combination, complete the deck and simulate games.
+    // For every valid combination, complete the deck and simulate games. Every combination
+    // plays the same seeds, so that they are compared on the same games.
+    let base_seed = seed.unwrap_or_else(rand::random);
+    warn!("Playing games with seed {}", base_seed);
     let mut best_win_percent = 0.0;
     let mut best_combination = None;
     let mut results = Vec::new();
@@ -134,30 +158,38 @@ pub fn optimize(
         let mut completed_deck = incomplete_deck.clone();
         for card_id in &comb {
             let card = get_card_by_enum(*card_id);
-            completed_deck.cards.push(card);
+            completed_deck.cards.push(card.into());
         }
-        if !completed_deck.is_valid() {
-            warn!(
-                "Completed deck is invalid. Num cards: {}, num basics: {}",
-                completed_deck.cards.len(),
-                completed_deck.cards.iter().filter(|x| x.is_basic()).count()
-            );
+        let errors: Vec<_> = completed_deck
+            .validate()
+            .into_iter()
+            .filter(DeckIssue::is_error)
+            .collect();
+        if !errors.is_empty() {
+            warn!("Completed deck {:?} is invalid: {:?}", comb, errors);
             continue;
         }
 
         // Simulate games for each enemy deck.
         let mut total_wins = 0;
         let mut total_games = 0;
-        for enemy_deck in &enemy_valid_decks {
-            for _ in 0..num {
+        for (enemy_index, enemy_deck) in enemy_valid_decks.iter().enumerate() {
+            for game_index in 0..num {
                 let players = create_players(
                     completed_deck.clone(),
                     enemy_deck.clone(),
                     fill_code_array(players.clone()),
                 );
-                let seed = seed.unwrap_or(rand::random::<u64>());
+                let seed = game_seed(base_seed, enemy_index as u32 * num + game_index);
                 let mut game = Game::new(players, seed);
-                let outcome = game.play();
+                game.set_allow_partial(allow_partial);
+                let outcome = match game.try_play() {
+                    Ok(outcome) => outcome,
+                    Err(err) => {
+                        warn!("Game (seed {}) failed and was skipped: {}", seed, err);
+                        continue;
+                    }
+                };
 
                 // Assume that if outcome is a win and the first player (our deck) wins, it counts as a win.
                 if let Some(GameOutcome::Win(winner)) = outcome {
@@ -174,20 +206,23 @@ pub fn optimize(
         warn!("Combination {:?} win percentage: {:.2}%", comb, win_percent);
         if win_percent > best_win_percent {
             best_win_percent = win_percent;
-            best_combination = Some(comb.clone());
+            best_combination = Some((comb.clone(), completed_deck));
         }
     }
 
     // Report the best combination found.
     match best_combination {
-        Some(comb) => {
+        Some((comb, deck)) => {
             warn!(
                 "Best combination: {:?} with win percentage: {:.2}%",
                 comb, best_win_percent
             );
+            warn!("Best deck:\n{}", deck);
+            Ok(Some(deck))
         }
         None => {
             warn!("No valid combination found.");
+            Ok(None)
         }
     }
 }
@@ -1,11 +1,11 @@
-use log::{trace, LevelFilter};
+use log::trace;
 use rand::rngs::StdRng;
 use std::fmt::Debug;
 
-use crate::actions::{forecast_action, Action};
+use crate::actions::{apply_mutation_with_undo, forecast_action, undo, Action};
 use crate::{generate_possible_actions, Deck, State};
 
-use super::Player;
+use super::{searching, Player};
 
 pub struct ExpectiMiniMaxPlayer {
     pub deck: Deck,
@@ -20,15 +20,18 @@ impl Player for ExpectiMiniMaxPlayer {
         possible_actions: Vec<Action>,
     ) -> Action {
         let myself = possible_actions[0].actor;
+        // Search walks the outcomes on this copy, undoing each one after scoring it
+        let mut state = state.clone();
 
         // Get value for each possible action
-        let original_level = log::max_level();
-        log::set_max_level(LevelFilter::Error); // Temporarily silence debug and trace logs
-        let scores: Vec<f64> = possible_actions
-            .iter()
-            .map(|action| expected_value_function(rng, state, action, self.max_depth - 1, myself))
-            .collect();
-        log::set_max_level(original_level); // Restore the original logging level
+        let scores: Vec<f64> = searching(|| {
+            possible_actions
+                .iter()
+                .map(|action| {
+                    expected_value_function(rng, &mut state, action, self.max_depth - 1, myself)
+                })
+                .collect()
+        });
 
         trace!("Scores: {:?}", scores);
         // Select the one with best score
@@ -48,29 +51,24 @@ impl Player for ExpectiMiniMaxPlayer {
 
 fn expected_value_function(
     rng: &mut StdRng,
-    state: &State,
+    state: &mut State,
     action: &Action,
     depth: usize,
     myself: usize,
 ) -> f64 {
     trace!("E({}) depth left: {} action: {:?}", myself, depth, action);
     let (probabilities, mutations) = forecast_action(state, action);
-    let mut outcomes: Vec<State> = vec![];
-    for mutation in mutations {
-        let mut state = state.clone();
-        mutation(rng, &mut state, action);
-        outcomes.push(state);
+    let mut score = 0.0;
+    for (mutation, prob) in mutations.into_iter().zip(probabilities) {
+        let record = apply_mutation_with_undo(rng, state, action, mutation);
+        score += expectiminimax(rng, state, depth, myself) * prob;
+        undo(state, record);
     }
-    let score = outcomes
-        .iter()
-        .zip(probabilities.iter())
-        .map(|(outcome, prob)| expectiminimax(rng, outcome, depth, myself) * prob)
-        .sum();
     trace!("E({}) action: {:?} score: {}", myself, action, score);
     score
 }
 
-fn expectiminimax(rng: &mut StdRng, state: &State, depth: usize, myself: usize) -> f64 {
+fn expectiminimax(rng: &mut StdRng, state: &mut State, depth: usize, myself: usize) -> f64 {
     if state.is_game_over() || depth == 0 {
         return value_function(state, myself);
     }
@@ -85,8 +83,8 @@ fn expectiminimax(rng: &mut StdRng, state: &State, depth: usize, myself: usize)
         scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
     } else {
         // TODO: If minimizing, we can't just generate_possible_actions since
-        //  not everything is public information. So we would have to have
-        //  our own version of it that only returns the actions that are
+        //  not everything is public information. Searching from a state sampled
+        //  with `Observation::determinize` keeps the opponent's moves honest.
         let scores: Vec<f64> = actions
             .iter()
             .map(|action| expected_value_function(rng, state, action, depth - 1, myself))
@@ -26,6 +26,11 @@ impl Player for HumanPlayer {
         println!("=== {}|{:?}", state.turn_count, state.points);
         println!();
         println!("{}", state.debug_string());
+        println!(
+            "Energy: {:?} (next: {:?})",
+            state.get_current_energy(),
+            state.get_next_energy(state.current_player)
+        );
         println!();
         println!("Select an action:");
         for (i, action) in possible_actions.iter().enumerate() {
@@ -13,7 +13,9 @@ use crate::{
 pub struct MctsPlayer {
     pub deck: Deck,
     pub iterations: u64, // Number of iterations for MCTS
-    node_lookup: HashMap<State, MctsNode>,
+    // Keyed by State::information_set_hash, so positions that only differ in the hidden
+    // order of the decks share a node.
+    node_lookup: HashMap<u64, MctsNode>,
 }
 impl MctsPlayer {
     pub fn new(deck: Deck, iterations: u64) -> Self {
@@ -37,7 +39,7 @@ impl Player for MctsPlayer {
         let investigator = possible_actions[0].actor; // myself
         let mut root = self
             .node_lookup
-            .entry(state.clone())
+            .entry(state.information_set_hash())
             .or_insert_with(|| MctsNode::new(state.clone(), possible_actions.clone()))
             .clone();
 
@@ -99,12 +101,12 @@ impl MctsNode {
         self.state.winner.is_some()
     }
 
-    fn expand(&mut self, rng: &mut StdRng, node_lookup: &mut HashMap<State, MctsNode>) {
+    fn expand(&mut self, rng: &mut StdRng, node_lookup: &mut HashMap<u64, MctsNode>) {
         for action in &self.actions {
             let mut new_state = self.state.clone();
             apply_action(rng, &mut new_state, action);
 
-            let node = node_lookup.get(&new_state).cloned();
+            let node = node_lookup.get(&new_state.information_set_hash());
             if node.is_none() {
                 debug!("Missed a node in the lookup table");
             } else {
@@ -17,10 +17,30 @@ pub use random_player::RandomPlayer;
 pub use value_function_player::ValueFunctionPlayer;
 pub use weighted_random_player::WeightedRandomPlayer;
 
-use crate::{actions::Action, Deck, State};
+use crate::{actions::Action, observation::Observation, Deck, State};
 use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
 use std::fmt::Debug;
 
+thread_local! {
+    static SEARCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether a search player is looking ahead on the current thread. Loggers can use it to
+/// leave out the moves it only imagines (the CLI does).
+pub fn is_searching() -> bool {
+    SEARCHING.with(Cell::get)
+}
+
+// Runs `search` with `is_searching` set on the current thread.
+pub(crate) fn searching<R>(search: impl FnOnce() -> R) -> R {
+    let was_searching = SEARCHING.replace(true);
+    let result = search();
+    SEARCHING.set(was_searching);
+    result
+}
+
 pub trait Player: Debug {
     fn get_deck(&self) -> Deck;
     fn decision_fn(
@@ -31,8 +51,36 @@ pub trait Player: Debug {
     ) -> Action;
 }
 
+/// A player that only gets to see what its seat can see (see `State::observation`),
+/// instead of the full game state. Every `ObservingPlayer` is also a `Player`.
+pub trait ObservingPlayer: Debug {
+    fn get_deck(&self) -> Deck;
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action;
+}
+
+impl<T: ObservingPlayer> Player for T {
+    fn get_deck(&self) -> Deck {
+        ObservingPlayer::get_deck(self)
+    }
+
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let observation = state.observation(possible_actions[0].actor);
+        ObservingPlayer::decision_fn(self, rng, &observation, possible_actions)
+    }
+}
+
 /// Enum for allowed player strategies
-#[derive(Debug, ValueEnum, Clone)]
+#[derive(Debug, ValueEnum, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PlayerCode {
     AA,
     ET,
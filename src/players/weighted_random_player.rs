@@ -58,5 +58,7 @@ fn get_weight(action: &SimpleAction) -> u32 {
         SimpleAction::Heal { .. } => 5,
         SimpleAction::Activate { .. } => 1,
         SimpleAction::PreventDamage { .. } => 5,
+        SimpleAction::DiscardEnergy { .. } => 1,
+        SimpleAction::Noop => 1,
     }
 }
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Deck, DeckgymError};
+
+/// Bench slots available in `State::in_play_pokemon` (slot 0 is the Active Pokémon).
+pub const MAX_BENCH_SIZE: usize = 3;
+
+/// The rules of a match. `RuleSet::default()` is the standard TCG Pocket format; other
+/// values allow simulating variants (e.g. a 4-point match or a shorter turn cap).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleSet {
+    /// Points needed to win the game (knocking out an ex is worth 2).
+    pub points_to_win: u8,
+    /// The game ends in a draw when this (global) turn count is reached.
+    pub max_turns: u8,
+    /// Number of Pokémon allowed on the Bench. Can only be lowered from the standard 3, as
+    /// `State::in_play_pokemon` has room for `MAX_BENCH_SIZE` Bench Pokémon.
+    pub bench_size: usize,
+    /// Cards each player draws before the game starts.
+    pub opening_hand_size: usize,
+    /// First (global) turn in which players can evolve their Pokémon.
+    pub first_evolution_turn: u8,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            points_to_win: 3,
+            max_turns: 100,
+            bench_size: 3,
+            opening_hand_size: 5,
+            first_evolution_turn: 3,
+        }
+    }
+}
+
+impl RuleSet {
+    /// Checks that a game between the two decks can be played with these rules.
+    pub fn validate(&self, deck_a: &Deck, deck_b: &Deck) -> Result<(), DeckgymError> {
+        let invalid = |reason: String| Err(DeckgymError::InvalidRules(reason));
+        if self.points_to_win == 0 {
+            return invalid("Points to win must be at least 1".into());
+        }
+        if self.max_turns == 0 {
+            return invalid("Max turns must be at least 1".into());
+        }
+        if self.bench_size > MAX_BENCH_SIZE {
+            return invalid(format!(
+                "Bench size {} is larger than the maximum of {}",
+                self.bench_size, MAX_BENCH_SIZE
+            ));
+        }
+        let smallest_deck = deck_a.cards.len().min(deck_b.cards.len());
+        if self.opening_hand_size > smallest_deck {
+            return invalid(format!(
+                "Opening hand size {} is larger than a deck of {} cards",
+                self.opening_hand_size, smallest_deck
+            ));
+        }
+        Ok(())
+    }
+}
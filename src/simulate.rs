@@ -1,56 +1,195 @@
-use std::time::{Duration, Instant};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
 
 use log::{info, warn};
 use num_format::{Locale, ToFormattedString};
 
 use crate::{
+    coverage::check_deck,
     players::{create_players, fill_code_array, PlayerCode},
     state::GameOutcome,
-    Deck, Game,
+    Deck, DeckgymError, Game,
 };
 
+/// How `simulate` plays its games.
+#[derive(Debug, Clone)]
+pub struct SimulationOptions {
+    /// Players' strategies (see `fill_code_array` for the defaults).
+    pub players: Option<Vec<PlayerCode>>,
+    pub num_simulations: u32,
+    /// Base seed of the run. Game `i` (counting from 0) is played with `game_seed(seed, i)`.
+    /// A random one is picked (and logged) if `None`.
+    pub seed: Option<u64>,
+    /// Folder to write a JSON game record of each game into.
+    pub record_folder: Option<String>,
+    pub allow_partial: bool,
+    /// Number of threads to spread the games over. Results don't depend on it.
+    pub threads: usize,
+}
+
+impl Default for SimulationOptions {
+    fn default() -> Self {
+        SimulationOptions {
+            players: None,
+            num_simulations: 1,
+            seed: None,
+            record_folder: None,
+            allow_partial: false,
+            threads: 1,
+        }
+    }
+}
+
+/// Statistics of a `simulate` run, gathered in game order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulationResults {
+    /// Base seed of the run (the given one or the random one picked).
+    pub seed: u64,
+    /// Wins of deck A, wins of deck B and draws.
+    pub wins_per_deck: [u32; 3],
+    /// Games that failed (and are left out of the other statistics).
+    pub num_errors: u32,
+    pub turns_per_game: Vec<u8>,
+    pub plys_per_game: Vec<u32>,
+    pub degrees_per_ply: Vec<u32>,
+}
+
+/// Seed of the game at `index` in a run with `base_seed`, so that games differ from each
+/// other but a run can always be reproduced. The base seed is mixed before adding the index,
+/// so that runs with nearby base seeds don't share games.
+pub fn game_seed(base_seed: u64, index: u32) -> u64 {
+    splitmix64(splitmix64(base_seed).wrapping_add(index as u64))
+}
+
+// Finalizer of the SplitMix64 generator, which spreads nearby inputs all over the range.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// What a single game adds to the statistics.
+struct GameSummary {
+    outcome: Option<GameOutcome>,
+    turns: u8,
+    plys: u32,
+    degrees_per_ply: Vec<u32>,
+}
+
 pub fn simulate(
     deck_a_path: &str,
     deck_b_path: &str,
-    players: Option<Vec<PlayerCode>>,
-    num_simulations: u32,
-    seed: Option<u64>,
-) {
+    options: &SimulationOptions,
+) -> Result<SimulationResults, String> {
+    if options.threads == 0 {
+        return Err("Number of threads must be at least 1".to_string());
+    }
+
     // Read the decks files and initialize Players
-    let deck_a = Deck::from_file(deck_a_path).expect("Failed to parse deck from file");
-    let deck_b = Deck::from_file(deck_b_path).expect("Failed to parse deck from file");
-    let cli_players = fill_code_array(players);
+    let deck_a = Deck::from_legal_file(deck_a_path)?;
+    let deck_b = Deck::from_legal_file(deck_b_path)?;
+    check_deck(&deck_a, deck_a_path, options.allow_partial)?;
+    check_deck(&deck_b, deck_b_path, options.allow_partial)?;
+    let cli_players = fill_code_array(options.players.clone());
+    let base_seed = options.seed.unwrap_or_else(rand::random);
 
     // Simulate Games and accumulate statistics
     warn!(
-        "Running {} games with players {:?}",
-        num_simulations.to_formatted_string(&Locale::en),
-        cli_players
+        "Running {} games with players {:?} (seed {}, {} threads)",
+        options.num_simulations.to_formatted_string(&Locale::en),
+        cli_players,
+        base_seed,
+        options.threads
     );
     let start = Instant::now(); // Start the timer
-    let mut wins_per_deck = [0, 0, 0];
-    let mut turns_per_game = Vec::new();
-    let mut plys_per_game = Vec::new();
-    let mut total_degrees = Vec::new();
-    for i in 1..=num_simulations {
+
+    // The outer error stops the whole run (e.g. a record can't be written), the inner one
+    // only skips the game.
+    let play = |index: u32| -> Result<Result<GameSummary, DeckgymError>, String> {
         let players = create_players(deck_a.clone(), deck_b.clone(), cli_players.clone());
-        let seed = seed.unwrap_or(rand::random::<u64>());
-        let mut game = Game::new(players, seed);
-        let outcome = game.play();
-        turns_per_game.push(game.get_state_clone().turn_count);
-        plys_per_game.push(game.get_num_plys());
-        total_degrees.extend(game.get_degrees_per_ply().iter());
-        info!("Simulation {}: Winner is {:?}", i, outcome);
-        match outcome {
+        let mut game = Game::new(players, game_seed(base_seed, index));
+        game.set_allow_partial(options.allow_partial);
+        let outcome = match game.try_play() {
+            Ok(outcome) => outcome,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Some(folder) = &options.record_folder {
+            let record = game.to_record([cli_players[0].clone(), cli_players[1].clone()]);
+            let path = format!("{folder}/game-{}.json", index + 1);
+            record.to_file(&path)?;
+        }
+        Ok(Ok(GameSummary {
+            outcome,
+            turns: game.get_state_clone().turn_count,
+            plys: game.get_num_plys(),
+            degrees_per_ply: game.get_degrees_per_ply(),
+        }))
+    };
+    // Each thread takes every `threads`-th game. Players aren't `Send`, so they are created
+    // in the thread that plays them.
+    let mut games = thread::scope(|scope| {
+        let workers: Vec<_> = (0..options.threads)
+            .map(|worker| {
+                let play = &play;
+                scope.spawn(move || {
+                    (worker as u32..options.num_simulations)
+                        .step_by(options.threads)
+                        .map(|index| Ok((index, play(index)?)))
+                        .collect::<Result<Vec<_>, String>>()
+                })
+            })
+            .collect();
+        let mut games = vec![];
+        for worker in workers {
+            games.extend(worker.join().expect("Simulation thread panicked")?);
+        }
+        Ok::<_, String>(games)
+    })?;
+    games.sort_by_key(|(index, _)| *index);
+
+    let mut results = SimulationResults {
+        seed: base_seed,
+        ..Default::default()
+    };
+    for (index, game) in games {
+        let summary = match game {
+            Ok(summary) => summary,
+            Err(err) => {
+                // Skip the broken game instead of aborting the whole batch.
+                let seed = game_seed(base_seed, index);
+                warn!("Simulation {} (seed {}) failed: {}", index + 1, seed, err);
+                results.num_errors += 1;
+                continue;
+            }
+        };
+        info!("Simulation {}: Winner is {:?}", index + 1, summary.outcome);
+        results.turns_per_game.push(summary.turns);
+        results.plys_per_game.push(summary.plys);
+        results.degrees_per_ply.extend(summary.degrees_per_ply);
+        match summary.outcome {
             Some(GameOutcome::Win(winner_name)) => {
-                wins_per_deck[winner_name] += 1;
+                results.wins_per_deck[winner_name] += 1;
             }
             Some(GameOutcome::Tie) | None => {
-                wins_per_deck[2] += 1;
+                results.wins_per_deck[2] += 1;
             }
         }
     }
     let duration = start.elapsed(); // Measure elapsed time
+    if results.num_errors > 0 {
+        warn!(
+            "{} games failed and were left out of the statistics",
+            results.num_errors.to_formatted_string(&Locale::en)
+        );
+    }
+    let num_simulations = options.num_simulations - results.num_errors;
+    if num_simulations == 0 {
+        warn!("No games were played, so there are no statistics to show");
+        return Ok(results);
+    }
     let avg_time_per_game = duration.as_secs_f64() / num_simulations as f64;
     let avg_duration = Duration::from_secs_f64(avg_time_per_game);
 
@@ -63,7 +202,8 @@ pub fn simulate(
     );
     warn!(
         "Average number of turns per game: {:.2}",
-        turns_per_game
+        results
+            .turns_per_game
             .iter()
             .map(|&turns| turns as u32)
             .sum::<u32>() as f32
@@ -71,12 +211,13 @@ pub fn simulate(
     );
     warn!(
         "Average number of plys per game: {:.2}",
-        plys_per_game.iter().sum::<u32>() as f32 / num_simulations as f32
+        results.plys_per_game.iter().sum::<u32>() as f32 / num_simulations as f32
     );
     warn!(
         "Average number of degrees per ply: {:.2}",
-        total_degrees.iter().sum::<u32>() as f32 / total_degrees.len() as f32
+        results.degrees_per_ply.iter().sum::<u32>() as f32 / results.degrees_per_ply.len() as f32
     );
+    let wins_per_deck = results.wins_per_deck;
     warn!(
         "Player {:?} with Deck {} wins: {} ({:.2}%)",
         cli_players[0],
@@ -96,4 +237,5 @@ pub fn simulate(
         wins_per_deck[2].to_formatted_string(&Locale::en),
         wins_per_deck[2] as f32 / num_simulations as f32 * 100.0
     );
+    Ok(results)
 }
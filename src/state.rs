@@ -1,21 +1,52 @@
 use log::{debug, trace};
 use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::hash::Hash;
+use std::mem;
 
 use crate::{
-    actions::SimpleAction,
+    actions::{Change, PokemonFields, SimpleAction, Trail},
     deck::Deck,
-    types::{Card, EnergyType, PlayedCard},
+    effects::{migrate_turn_effects, Effect, EffectKind, EffectTarget},
+    error::DeckgymError,
+    rules::RuleSet,
+    types::{Card, CardRef, EnergyType, PlayedCard},
+    zobrist::{deck_top_features, zone_features, Feature, ZobristHashes},
 };
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameOutcome {
     Win(usize),
     Tie,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+/// The piles of cards of a player (see `State::cards`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Zone {
+    Hand,
+    Deck,
+    DiscardPile,
+    PrizeCards,
+    RevealedCards,
+}
+impl Zone {
+    pub(crate) const ALL: [Zone; 5] = [
+        Zone::Hand,
+        Zone::Deck,
+        Zone::DiscardPile,
+        Zone::PrizeCards,
+        Zone::RevealedCards,
+    ];
+}
+
+/// Version of the JSON format produced by `State::to_json`. Bump it when a change to `State`
+/// can't be loaded with `#[serde(default)]` alone, and migrate older versions in `from_json`.
+pub const STATE_SCHEMA_VERSION: u32 = 2;
+
+// Fields missing in older saved positions take their default value.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct State {
     // Turn State
     pub winner: Option<GameOutcome>,
@@ -28,38 +59,102 @@ pub struct State {
 
     // Core state
     pub(crate) current_energy: Option<EnergyType>,
-    pub hands: [Vec<Card>; 2],
+    // Energy Zone preview per player. This is the energy that the player will receive
+    // at the start of their next turn (as shown in the app).
+    pub(crate) next_energy: [Option<EnergyType>; 2],
+    pub hands: [Vec<CardRef>; 2],
     pub decks: [Deck; 2],
-    pub discard_piles: [Vec<Card>; 2],
-    pub prize_cards: [Vec<Card>; 2],
-    // 0 index is the active pokemon, 1..4 are the bench
+    pub discard_piles: [Vec<CardRef>; 2],
+    pub prize_cards: [Vec<CardRef>; 2],
+    // 0 index is the active pokemon, 1..4 are the bench (only the first rules.bench_size used)
     pub in_play_pokemon: [[Option<PlayedCard>; 4]; 2],
+    // Cards in each player's hand that the opponent knows about (e.g. searched with Poké Ball).
+    pub(crate) revealed_cards: [Vec<CardRef>; 2],
 
     // Turn Flags (remember to reset these in reset_turn_states)
     pub(crate) has_played_support: bool,
     pub(crate) has_retreated: bool,
-    // Maps turn to a vector of effects (cards) for that turn. Using BTreeMap to keep State hashable.
-    turn_effects: BTreeMap<u8, Vec<Card>>,
+    // Effects of trainers and attacks that last for some turns (e.g. Giovanni, Arbok's Corner).
+    // Expired ones are dropped at the start of each turn.
+    pub(crate) effects: Vec<Effect>,
+
+    // Points to win, turn cap, bench size, etc. Constant throughout the game.
+    pub rules: RuleSet,
+
+    // If set, unimplemented attacks deal their printed fixed damage and unimplemented
+    // trainers (and abilities) are left out of move generation, instead of failing.
+    pub(crate) allow_partial: bool,
+
+    // See zobrist_hash
+    #[serde(skip)]
+    pub(crate) hashes: ZobristHashes,
+
+    // See actions::undo
+    #[serde(skip)]
+    pub(crate) trail: Trail,
 }
 
 impl State {
     pub(crate) fn new(deck_a: &Deck, deck_b: &Deck) -> Self {
-        Self {
+        let mut state = Self {
             winner: None,
             points: [0, 0],
             turn_count: 0,
             current_player: 0,
             move_generation_stack: Vec::new(),
             current_energy: None,
+            next_energy: [None, None],
             hands: [Vec::new(), Vec::new()],
             decks: [deck_a.clone(), deck_b.clone()],
             discard_piles: [Vec::new(), Vec::new()],
             prize_cards: [Vec::new(), Vec::new()],
             in_play_pokemon: [[None, None, None, None], [None, None, None, None]],
+            revealed_cards: [Vec::new(), Vec::new()],
             has_played_support: false,
             has_retreated: false,
-            turn_effects: BTreeMap::new(),
+            effects: Vec::new(),
+            rules: RuleSet::default(),
+            allow_partial: false,
+            hashes: ZobristHashes::default(),
+            trail: Trail::default(),
+        };
+        state.rehash();
+        state
+    }
+
+    /// Serializes the full state (including private fields) into a versioned JSON snapshot.
+    pub fn to_json(&self) -> Result<String, String> {
+        let snapshot = StateSnapshot {
+            schema_version: STATE_SCHEMA_VERSION,
+            state: self.clone(),
+        };
+        serde_json::to_string(&snapshot).map_err(|err| format!("Failed to serialize: {err}"))
+    }
+
+    /// Loads a JSON snapshot produced by `to_json` (by this or an older release).
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let snapshot: StateSnapshot =
+            serde_json::from_str(json).map_err(|err| format!("Failed to parse state: {err}"))?;
+        if snapshot.schema_version > STATE_SCHEMA_VERSION {
+            return Err(format!(
+                "State schema version {} is newer than supported version {}",
+                snapshot.schema_version, STATE_SCHEMA_VERSION
+            ));
         }
+        let mut state = snapshot.state;
+
+        // Version 1 stored effects as cards keyed by turn (`turn_effects`)
+        if snapshot.schema_version < 2 {
+            let legacy: LegacyEffects = serde_json::from_str(json)
+                .map_err(|err| format!("Failed to parse state: {err}"))?;
+            state.effects = migrate_turn_effects(
+                &legacy.state.turn_effects,
+                state.turn_count,
+                state.current_player,
+            );
+        }
+        state.rehash();
+        Ok(state)
     }
 
     pub fn debug_string(&self) -> String {
@@ -76,21 +171,38 @@ impl State {
     }
 
     pub fn initialize(deck_a: &Deck, deck_b: &Deck, rng: &mut impl Rng) -> Self {
+        Self::initialize_with_rules(deck_a, deck_b, RuleSet::default(), rng)
+            .expect("Standard rules should be valid")
+    }
+
+    pub fn initialize_with_rules(
+        deck_a: &Deck,
+        deck_b: &Deck,
+        rules: RuleSet,
+        rng: &mut impl Rng,
+    ) -> Result<Self, DeckgymError> {
+        rules.validate(deck_a, deck_b)?;
         let mut state = Self::new(deck_a, deck_b);
+        state.rules = rules;
 
         // Shuffle the decks before starting the game and have players
-        //  draw 5 cards each to start
-        for deck in &mut state.decks {
-            deck.shuffle(true, rng);
+        //  draw their opening hands (5 cards each by default)
+        for player in 0..2 {
+            state.shuffle_deck(player, true, rng);
         }
-        for _ in 0..5 {
+        for _ in 0..rules.opening_hand_size {
             state.maybe_draw_card(0);
             state.maybe_draw_card(1);
         }
         // Flip a coin to determine the starting player
         state.current_player = rng.gen_range(0..2);
 
-        state
+        // Roll the first energy preview of each Energy Zone
+        for player in 0..2 {
+            state.next_energy[player] = state.roll_energy(player, rng);
+        }
+
+        Ok(state)
     }
 
     pub fn get_remaining_hp(&self, player: usize, index: usize) -> u32 {
@@ -100,22 +212,285 @@ impl State {
             .remaining_hp
     }
 
-    pub(crate) fn remove_card_from_hand(&mut self, current_player: usize, card: &Card) {
+    // The engine changes the cards, the Pokémon in play, the stack and the effects of a
+    // state through the methods below, which keep its hashes up to date and record the
+    // changes for `undo`.
+
+    pub(crate) fn cards(&self, zone: Zone, player: usize) -> &Vec<CardRef> {
+        match zone {
+            Zone::Hand => &self.hands[player],
+            Zone::Deck => &self.decks[player].cards,
+            Zone::DiscardPile => &self.discard_piles[player],
+            Zone::PrizeCards => &self.prize_cards[player],
+            Zone::RevealedCards => &self.revealed_cards[player],
+        }
+    }
+
+    // Doesn't update the hashes, nor record the change (see `undo`)
+    pub(crate) fn cards_mut(&mut self, zone: Zone, player: usize) -> &mut Vec<CardRef> {
+        match zone {
+            Zone::Hand => &mut self.hands[player],
+            Zone::Deck => &mut self.decks[player].cards,
+            Zone::DiscardPile => &mut self.discard_piles[player],
+            Zone::PrizeCards => &mut self.prize_cards[player],
+            Zone::RevealedCards => &mut self.revealed_cards[player],
+        }
+    }
+
+    /// Puts `card` at the end of the zone (i.e. at the bottom of decks).
+    pub(crate) fn push_card(&mut self, zone: Zone, player: usize, card: CardRef) {
+        self.hashes.add(Feature::Card(zone, player, &card));
+        if zone == Zone::Deck {
+            // The cards already there move one position up from the bottom
+            let len = self.decks[player].cards.len();
+            self.update_deck_keys(player, len, false);
+            self.decks[player].cards.push(card);
+            self.update_deck_keys(player, len + 1, true);
+        } else {
+            self.cards_mut(zone, player).push(card);
+        }
+        self.trail.record(Change::CardPushed(zone, player));
+    }
+
+    /// Takes the card at `index` out of the zone, keeping the order of the rest.
+    pub(crate) fn remove_card(&mut self, zone: Zone, player: usize, index: usize) -> CardRef {
+        if zone == Zone::Deck {
+            // The cards above it move one position down
+            self.update_deck_keys(player, index + 1, false);
+        }
+        let card = self.cards_mut(zone, player).remove(index);
+        if zone == Zone::Deck {
+            self.update_deck_keys(player, index, true);
+        }
+        self.hashes.remove(Feature::Card(zone, player, &card));
+        self.trail
+            .record(Change::CardRemoved(zone, player, index, card));
+        card
+    }
+
+    /// Like `remove_card`, but moves the last card into its place. For zones whose order
+    /// doesn't matter (i.e. not decks).
+    pub(crate) fn swap_remove_card(&mut self, zone: Zone, player: usize, index: usize) -> CardRef {
+        debug_assert_ne!(zone, Zone::Deck, "The order of decks matters");
+        let card = self.cards_mut(zone, player).swap_remove(index);
+        self.hashes.remove(Feature::Card(zone, player, &card));
+        self.trail
+            .record(Change::CardSwapRemoved(zone, player, index, card));
+        card
+    }
+
+    /// Replaces all the cards of the zone.
+    pub(crate) fn set_cards(&mut self, zone: Zone, player: usize, cards: Vec<CardRef>) {
+        for feature in zone_features(zone, player, &cards) {
+            self.hashes.add(feature);
+        }
+        let old_cards = mem::replace(self.cards_mut(zone, player), cards);
+        for feature in zone_features(zone, player, &old_cards) {
+            self.hashes.remove(feature);
+        }
+        self.trail
+            .record(Change::CardsReplaced(zone, player, old_cards));
+    }
+
+    pub(crate) fn shuffle_deck(
+        &mut self,
+        player: usize,
+        initial_shuffle: bool,
+        rng: &mut impl Rng,
+    ) {
+        let mut deck = Deck {
+            cards: self.decks[player].cards.clone(),
+            ..Default::default()
+        };
+        deck.shuffle(initial_shuffle, rng);
+        self.set_cards(Zone::Deck, player, deck.cards);
+    }
+
+    // Adds (or removes) the keys of the `top` cards of the deck, which depend on their position.
+    fn update_deck_keys(&mut self, player: usize, top: usize, add: bool) {
+        for feature in deck_top_features(player, &self.decks[player].cards, top) {
+            if add {
+                self.hashes.add(feature);
+            } else {
+                self.hashes.remove(feature);
+            }
+        }
+    }
+
+    /// Puts `pokemon` (or nothing) in the slot, replacing what was there.
+    pub(crate) fn set_in_play(&mut self, player: usize, index: usize, pokemon: Option<PlayedCard>) {
+        self.update_in_play_key(player, index, false);
+        let old_pokemon = mem::replace(&mut self.in_play_pokemon[player][index], pokemon);
+        self.update_in_play_key(player, index, true);
+        self.trail
+            .record(Change::InPlaySet(player, index, old_pokemon));
+    }
+
+    /// Swaps the Pokémon in two slots (e.g. the Active one with a Benched one).
+    pub(crate) fn swap_in_play(&mut self, player: usize, a: usize, b: usize) {
+        self.update_in_play_key(player, a, false);
+        self.update_in_play_key(player, b, false);
+        self.in_play_pokemon[player].swap(a, b);
+        self.update_in_play_key(player, a, true);
+        self.update_in_play_key(player, b, true);
+        self.trail.record(Change::InPlaySwapped(player, a, b));
+    }
+
+    /// Applies `change` to the Pokémon in the slot (which should be there). Its attached
+    /// energy is changed with `attach_energy` and `discard_energy` instead.
+    pub(crate) fn update_pokemon<R>(
+        &mut self,
+        player: usize,
+        index: usize,
+        change: impl FnOnce(&mut PlayedCard) -> R,
+    ) -> R {
+        self.update_in_play_key(player, index, false);
+        let pokemon = self.in_play_pokemon[player][index]
+            .as_mut()
+            .expect("Pokemon should be there if changing it");
+        let before = self
+            .trail
+            .is_recording()
+            .then(|| PokemonFields::of(pokemon));
+        let energy_before = pokemon.attached_energy.len();
+        let result = change(pokemon);
+        debug_assert_eq!(
+            pokemon.attached_energy.len(),
+            energy_before,
+            "Attached energy is changed with attach_energy and discard_energy"
+        );
+        if let Some(fields) = before.filter(|x| *x != PokemonFields::of(pokemon)) {
+            self.trail
+                .record(Change::PokemonUpdated(player, index, fields));
+        }
+        self.update_in_play_key(player, index, true);
+        result
+    }
+
+    pub(crate) fn attach_energy(
+        &mut self,
+        player: usize,
+        index: usize,
+        energy: EnergyType,
+        amount: u32,
+    ) {
+        self.update_in_play_key(player, index, false);
+        self.in_play_pokemon[player][index]
+            .as_mut()
+            .expect("Pokemon should be there if attaching energy to it")
+            .attached_energy
+            .extend(std::iter::repeat_n(energy, amount as usize));
+        self.update_in_play_key(player, index, true);
+        self.trail
+            .record(Change::EnergyAttached(player, index, amount));
+    }
+
+    /// Discards 1 of the given energy (if attached) from the Pokémon in the slot.
+    pub(crate) fn discard_energy(&mut self, player: usize, index: usize, energy: EnergyType) {
+        let attached = &self.in_play_pokemon[player][index]
+            .as_ref()
+            .expect("Pokemon should be there if discarding energy from it")
+            .attached_energy;
+        if let Some(position) = attached.iter().position(|x| *x == energy) {
+            self.update_in_play_key(player, index, false);
+            self.attached_energy_mut(player, index)
+                .swap_remove(position);
+            self.update_in_play_key(player, index, true);
+            self.trail
+                .record(Change::EnergySwapRemoved(player, index, position, energy));
+        }
+    }
+
+    /// Discards the energy attached at `position` from the Pokémon in the slot, keeping the
+    /// order of the rest.
+    pub(crate) fn remove_energy(&mut self, player: usize, index: usize, position: usize) {
+        self.update_in_play_key(player, index, false);
+        let energy = self.attached_energy_mut(player, index).remove(position);
+        self.update_in_play_key(player, index, true);
+        self.trail
+            .record(Change::EnergyRemoved(player, index, position, energy));
+    }
+
+    fn attached_energy_mut(&mut self, player: usize, index: usize) -> &mut Vec<EnergyType> {
+        &mut self.in_play_pokemon[player][index]
+            .as_mut()
+            .expect("Pokemon should be there if discarding energy from it")
+            .attached_energy
+    }
+
+    // Adds (or removes) the key of the Pokémon in the slot, if any.
+    fn update_in_play_key(&mut self, player: usize, index: usize, add: bool) {
+        if let Some(pokemon) = &self.in_play_pokemon[player][index] {
+            let feature = Feature::InPlay(player, index, pokemon);
+            if add {
+                self.hashes.add(feature);
+            } else {
+                self.hashes.remove(feature);
+            }
+        }
+    }
+
+    /// Pushes a decision of `actor` onto the move generation stack.
+    pub(crate) fn push_choices(&mut self, actor: usize, choices: Vec<SimpleAction>) {
+        let entry = (actor, choices);
+        let depth = self.move_generation_stack.len();
+        self.hashes.add(Feature::Choices(depth, &entry));
+        self.move_generation_stack.push(entry);
+        self.trail.record(Change::ChoicesPushed);
+    }
+
+    pub(crate) fn pop_choices(&mut self) {
+        if let Some(entry) = self.move_generation_stack.pop() {
+            let depth = self.move_generation_stack.len();
+            self.hashes.remove(Feature::Choices(depth, &entry));
+            self.trail.record(Change::ChoicesPopped(entry));
+        }
+    }
+
+    pub(crate) fn remove_card_from_hand(&mut self, current_player: usize, card: CardRef) {
         let index = self.hands[current_player]
             .iter()
-            .position(|x| x == card)
+            .position(|x| *x == card)
             .expect("Player hand should contain card to remove");
-        self.hands[current_player].swap_remove(index);
+        self.swap_remove_card(Zone::Hand, current_player, index);
+        self.forget_revealed_not_in_hand(current_player);
+    }
+
+    /// Marks a card that was put into the player's hand as known to the opponent.
+    pub(crate) fn reveal_card_in_hand(&mut self, player: usize, card: CardRef) {
+        self.push_card(Zone::RevealedCards, player, card);
     }
 
-    pub(crate) fn discard_card_from_hand(&mut self, current_player: usize, card: &Card) {
+    /// Drops revealed cards that are no longer in the player's hand. Call this after
+    /// removing cards from a hand without going through `remove_card_from_hand`.
+    pub(crate) fn forget_revealed_not_in_hand(&mut self, player: usize) {
+        if self.revealed_cards[player].is_empty() {
+            return;
+        }
+        let mut remaining_hand = self.hands[player].clone();
+        let mut index = 0;
+        while index < self.revealed_cards[player].len() {
+            let card = self.revealed_cards[player][index];
+            if let Some(pos) = remaining_hand.iter().position(|x| *x == card) {
+                remaining_hand.swap_remove(pos);
+                index += 1;
+            } else {
+                self.remove_card(Zone::RevealedCards, player, index);
+            }
+        }
+    }
+
+    pub(crate) fn discard_card_from_hand(&mut self, current_player: usize, card: CardRef) {
         self.remove_card_from_hand(current_player, card);
-        self.discard_piles[current_player].push(card.clone());
+        self.push_card(Zone::DiscardPile, current_player, card);
     }
 
     pub(crate) fn maybe_draw_card(&mut self, player: usize) {
-        if let Some(card) = self.decks[player].draw() {
-            self.hands[player].push(card.clone());
+        if self.decks[player].cards.is_empty() {
+            debug!("Player {} cannot draw a card, deck is empty", player + 1);
+        } else {
+            let card = self.remove_card(Zone::Deck, player, 0);
+            self.push_card(Zone::Hand, player, card);
             debug!(
                 "Player {} drew: {:?}, now hand is: {:?} and deck has {} cards",
                 player + 1,
@@ -123,69 +498,144 @@ impl State {
                 to_canonical_names(&self.hands[player]),
                 self.decks[player].cards.len()
             );
-        } else {
-            debug!("Player {} cannot draw a card, deck is empty", player + 1);
         }
     }
 
-    pub(crate) fn generate_energy(&mut self) {
-        if self.decks[self.current_player].energy_types.len() == 1 {
-            self.current_energy = Some(self.decks[self.current_player].energy_types[0]);
+    /// Moves one copy of `card` from the player's deck into their hand. Used by draw outcomes
+    /// (see `draw_outcomes`), which pick the card drawn instead of taking the top one.
+    pub(crate) fn draw_card(&mut self, player: usize, card: CardRef) {
+        let pos = self.decks[player]
+            .cards
+            .iter()
+            .position(|x| *x == card)
+            .expect("Drawn card should be in deck");
+        self.remove_card(Zone::Deck, player, pos);
+        self.push_card(Zone::Hand, player, card);
+        debug!(
+            "Player {} drew: {:?}, now hand is: {:?} and deck has {} cards",
+            player + 1,
+            canonical_name(&card),
+            to_canonical_names(&self.hands[player]),
+            self.decks[player].cards.len()
+        );
+    }
+
+    /// Energy available to the current player this turn (None if already attached).
+    pub fn get_current_energy(&self) -> Option<EnergyType> {
+        self.current_energy
+    }
+
+    /// Energy the given player will receive at the start of their next turn.
+    pub fn get_next_energy(&self, player: usize) -> Option<EnergyType> {
+        self.next_energy[player]
+    }
+
+    /// Rolls an energy from the player's Energy Zone. Decks with a single energy type
+    /// don't consume randomness.
+    pub(crate) fn roll_energy(&self, player: usize, rng: &mut impl Rng) -> Option<EnergyType> {
+        let deck_energies = &self.decks[player].energy_types;
+        if deck_energies.len() <= 1 {
+            return deck_energies.first().copied();
+        }
+        deck_energies.choose(rng).copied()
+    }
+
+    /// Possible outcomes of rolling the player's Energy Zone, with their probabilities.
+    pub(crate) fn energy_roll_outcomes(&self, player: usize) -> Vec<(f64, Option<EnergyType>)> {
+        let deck_energies = &self.decks[player].energy_types;
+        if deck_energies.is_empty() {
+            return vec![(1.0, None)];
         }
+        let probability = 1.0 / deck_energies.len() as f64;
+        deck_energies
+            .iter()
+            .map(|energy| (probability, Some(*energy)))
+            .collect()
+    }
 
-        let deck_energies = &self.decks[self.current_player].energy_types;
-        let mut rng = rand::thread_rng();
-        let generated = deck_energies
-            .choose(&mut rng)
-            .expect("Decks should have at least 1 energy");
-        self.current_energy = Some(*generated);
+    /// Moves the previewed energy of the current player into the current slot, and
+    /// sets `rolled` as the new preview. If there was no preview (e.g. hand-built states),
+    /// `rolled` becomes the current energy directly.
+    pub(crate) fn generate_energy(&mut self, rolled: Option<EnergyType>) {
+        let preview = &mut self.next_energy[self.current_player];
+        match preview.take() {
+            Some(previewed) => {
+                self.current_energy = Some(previewed);
+                *preview = rolled;
+            }
+            None => {
+                self.current_energy = rolled;
+            }
+        }
+        debug!(
+            "Player {} got energy {:?}, next will be {:?}",
+            self.current_player + 1,
+            self.current_energy,
+            self.next_energy[self.current_player]
+        );
     }
 
     pub(crate) fn reset_turn_states(&mut self) {
         // Reset .played_this_turn and .ability_used for all in-play pokemon
-        for i in 0..2 {
-            self.in_play_pokemon[i].iter_mut().for_each(|x| {
-                if let Some(pokemon) = x {
-                    pokemon.played_this_turn = false;
-                    pokemon.ability_used = false;
+        for player in 0..2 {
+            for index in 0..self.in_play_pokemon[player].len() {
+                if matches!(&self.in_play_pokemon[player][index],
+                    Some(x) if x.played_this_turn || x.ability_used)
+                {
+                    self.update_pokemon(player, index, |pokemon| {
+                        pokemon.played_this_turn = false;
+                        pokemon.ability_used = false;
+                    });
                 }
-            });
+            }
         }
 
         self.has_played_support = false;
         self.has_retreated = false;
+        let turn_count = self.turn_count;
+        self.retain_effects(|x| x.is_active(turn_count));
     }
 
-    /// Adds an effect card that will remain active for a specified number of turns.
-    ///
-    /// # Arguments
-    ///
-    /// * `card` - The card representing the effect to be applied
-    /// * `duration` - The number of turns the effect should remain active. 0 means current turn only,
-    ///   1 means current turn and the next turn, etc.
-    pub(crate) fn add_turn_effect(&mut self, card: Card, duration: u8) {
-        for turn_offset in 0..(duration + 1) {
-            let target_turn = self.turn_count + turn_offset;
-            self.turn_effects
-                .entry(target_turn)
-                .or_default()
-                .push(card.clone());
-            trace!(
-                "Adding effect {:?} for {} turns, current turn: {}, target turn: {}",
-                canonical_name(&card),
-                duration,
-                self.turn_count,
-                target_turn
-            );
+    pub(crate) fn add_effect(&mut self, effect: Effect) {
+        trace!(
+            "Adding effect {:?}, current turn: {}",
+            effect,
+            self.turn_count
+        );
+        self.hashes.add(Feature::Effect(&effect));
+        self.effects.push(effect);
+        self.trail.record(Change::EffectAdded);
+    }
+
+    fn retain_effects(&mut self, keep: impl Fn(&Effect) -> bool) {
+        let mut index = 0;
+        while index < self.effects.len() {
+            if keep(&self.effects[index]) {
+                index += 1;
+            } else {
+                let effect = self.effects.remove(index);
+                self.hashes.remove(Feature::Effect(&effect));
+                self.trail.record(Change::EffectRemoved(index, effect));
+            }
         }
     }
 
-    /// Retrieves all effects scheduled for the current turn
-    pub(crate) fn get_current_turn_effects(&self) -> Vec<Card> {
-        self.turn_effects
-            .get(&self.turn_count)
-            .cloned()
-            .unwrap_or_default()
+    /// Effects active this turn that apply to `player` (or to their Active Pokemon).
+    pub(crate) fn get_effects_on(&self, player: usize) -> impl Iterator<Item = &EffectKind> {
+        self.effects
+            .iter()
+            .filter(move |x| x.is_active(self.turn_count) && x.target.player() == player)
+            .map(|x| &x.kind)
+    }
+
+    pub(crate) fn has_effect_on(&self, player: usize, kind: EffectKind) -> bool {
+        self.get_effects_on(player).any(|x| *x == kind)
+    }
+
+    /// Drops the effects on `player`'s Active Pokemon, because it left the Active Spot
+    /// (or evolved).
+    pub(crate) fn remove_active_effects(&mut self, player: usize) {
+        self.retain_effects(|x| x.target != EffectTarget::Active(player));
     }
 
     pub fn enumerate_in_play_pokemon(
@@ -209,8 +659,7 @@ impl State {
     }
 
     pub(crate) fn queue_draw_action(&mut self, actor: usize) {
-        self.move_generation_stack
-            .push((actor, vec![SimpleAction::DrawCard]));
+        self.push_choices(actor, vec![SimpleAction::DrawCard]);
     }
 
     pub(crate) fn get_active(&self, player: usize) -> &PlayedCard {
@@ -219,14 +668,17 @@ impl State {
             .expect("Active Pokemon should be there")
     }
 
+    // The engine changes Pokémon with `update_pokemon`, which keeps the hashes up to date
+    #[cfg(test)]
     pub(crate) fn get_active_mut(&mut self, player: usize) -> &mut PlayedCard {
         self.in_play_pokemon[player][0]
             .as_mut()
             .expect("Active Pokemon should be there")
     }
 
-    // This function should be called only from turn 1 onwards
-    pub(crate) fn advance_turn(&mut self) {
+    // This function should be called only from turn 1 onwards. `rolled_energy` is the
+    // outcome of the incoming player's Energy Zone roll (see `energy_roll_outcomes`).
+    pub(crate) fn advance_turn(&mut self, rolled_energy: Option<EnergyType>) {
         debug!(
             "Ending turn moving from player {} to player {}",
             self.current_player,
@@ -236,11 +688,11 @@ impl State {
         self.turn_count += 1;
         self.reset_turn_states();
         self.queue_draw_action(self.current_player);
-        self.generate_energy();
+        self.generate_energy(rolled_energy);
     }
 
     pub(crate) fn is_game_over(&self) -> bool {
-        self.winner.is_some() || self.turn_count >= 100
+        self.winner.is_some() || self.turn_count >= self.rules.max_turns
     }
 
     pub(crate) fn num_in_play_of_type(&self, player: usize, energy: EnergyType) -> usize {
@@ -250,6 +702,23 @@ impl State {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+struct StateSnapshot {
+    schema_version: u32,
+    state: State,
+}
+
+#[derive(Deserialize)]
+struct LegacyEffects {
+    state: LegacyStateEffects,
+}
+
+#[derive(Deserialize)]
+struct LegacyStateEffects {
+    #[serde(default)]
+    turn_effects: BTreeMap<u8, Vec<Card>>,
+}
+
 fn format_cards(played_cards: &[Option<PlayedCard>]) -> Vec<String> {
     played_cards.iter().map(format_card).collect()
 }
@@ -273,13 +742,16 @@ fn canonical_name(card: &Card) -> &String {
     }
 }
 
-fn to_canonical_names(cards: &[Card]) -> Vec<&String> {
-    cards.iter().map(canonical_name).collect()
+fn to_canonical_names(cards: &[CardRef]) -> Vec<&String> {
+    cards.iter().map(|card| canonical_name(card)).collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{deck::is_basic, test_helpers::load_test_decks};
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, deck::is_basic, hooks::to_playable_card,
+        test_helpers::load_test_decks,
+    };
 
     use super::*;
 
@@ -306,7 +778,134 @@ mod tests {
         assert_eq!(state.hands[1].len(), 5);
         assert_eq!(state.decks[0].cards.len(), 15);
         assert_eq!(state.decks[1].cards.len(), 15);
-        assert!(state.hands[0].iter().any(is_basic));
-        assert!(state.hands[1].iter().any(is_basic));
+        assert!(state.hands[0].iter().any(|card| is_basic(card)));
+        assert!(state.hands[1].iter().any(|card| is_basic(card)));
+    }
+
+    #[test]
+    fn test_energy_zone_preview_becomes_current_energy() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.next_energy[0] = Some(EnergyType::Grass);
+
+        state.generate_energy(Some(EnergyType::Fire));
+
+        assert_eq!(state.get_current_energy(), Some(EnergyType::Grass));
+        assert_eq!(state.get_next_energy(0), Some(EnergyType::Fire));
+    }
+
+    #[test]
+    fn test_initialize_rolls_energy_previews() {
+        let (deck_a, deck_b) = load_test_decks();
+        let state = State::initialize(&deck_a, &deck_b, &mut rand::thread_rng());
+
+        assert_eq!(state.get_current_energy(), None);
+        assert!(deck_a
+            .energy_types
+            .contains(&state.get_next_energy(0).unwrap()));
+        assert!(deck_b
+            .energy_types
+            .contains(&state.get_next_energy(1).unwrap()));
+    }
+
+    #[test]
+    fn test_json_round_trip_mid_game() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rand::thread_rng());
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.attached_energy = vec![EnergyType::Grass];
+        active.poisoned = true;
+        state.in_play_pokemon[0][0] = Some(active);
+        state.current_energy = Some(EnergyType::Grass);
+        state.turn_count = 3;
+        state.add_effect(Effect {
+            kind: EffectKind::RetreatCostReduction(1),
+            source: CardId::PA002XSpeed,
+            owner: 0,
+            target: EffectTarget::Player(0),
+            expires_after_turn: 3,
+        });
+        state.queue_draw_action(1);
+
+        let json = state.to_json().unwrap();
+        assert_eq!(State::from_json(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_from_json_rejects_newer_schema() {
+        let json = format!(
+            "{{\"schema_version\": {}, \"state\": {{}}}}",
+            STATE_SCHEMA_VERSION + 1
+        );
+        assert!(State::from_json(&json).is_err());
+
+        // Missing fields (e.g. from older releases) take their default value.
+        let json = format!("{{\"schema_version\": {STATE_SCHEMA_VERSION}, \"state\": {{}}}}");
+        assert_eq!(State::from_json(&json).unwrap(), State::default());
+    }
+
+    #[test]
+    fn test_from_json_migrates_turn_effects() {
+        // Player 1 used Arbok's Corner in turn 3, so it is scheduled for turns 3 and 4
+        let arbok = get_card_by_enum(CardId::A1165Arbok);
+        let state = State {
+            turn_count: 3,
+            current_player: 1,
+            ..Default::default()
+        };
+        let mut json: serde_json::Value = serde_json::from_str(&state.to_json().unwrap()).unwrap();
+        json["schema_version"] = 1.into();
+        json["state"]["turn_effects"] = serde_json::json!({
+            "3": [arbok.clone()],
+            "4": [arbok],
+        });
+
+        let migrated = State::from_json(&json.to_string()).unwrap();
+        assert_eq!(
+            migrated.effects,
+            vec![Effect {
+                kind: EffectKind::CantRetreat,
+                source: CardId::A1165Arbok,
+                owner: 1,
+                target: EffectTarget::Active(0),
+                expires_after_turn: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_effects_expire_and_leave_with_active() {
+        let mut state = State {
+            turn_count: 3,
+            ..Default::default()
+        };
+        let corner = Effect {
+            kind: EffectKind::CantRetreat,
+            source: CardId::A1165Arbok,
+            owner: 0,
+            target: EffectTarget::Active(1),
+            expires_after_turn: 4,
+        };
+        let giovanni = Effect {
+            kind: EffectKind::AttackDamageBonus(10),
+            source: CardId::A1223Giovanni,
+            owner: 0,
+            target: EffectTarget::Player(0),
+            expires_after_turn: 3,
+        };
+        state.add_effect(corner);
+        state.add_effect(giovanni);
+        assert!(state.has_effect_on(0, EffectKind::AttackDamageBonus(10)));
+        assert!(!state.has_effect_on(0, EffectKind::CantRetreat));
+
+        // Giovanni only lasts for the turn it was played
+        state.advance_turn(None);
+        assert_eq!(state.effects, vec![corner]);
+        assert!(state.has_effect_on(1, EffectKind::CantRetreat));
+
+        // Corner is gone once the Pokemon leaves the Active Spot
+        state.remove_active_effects(1);
+        assert!(state.effects.is_empty());
     }
 }
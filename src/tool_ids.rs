@@ -1,25 +1,52 @@
-use std::collections::HashMap;
+// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.
 
-use crate::types::TrainerCard;
+use serde::{Deserialize, Serialize};
 
-// TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use crate::{card_ids::CardId, types::TrainerCard};
+
+/// Pokémon Tools in the database. Variants are named like their `CardId`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ToolId {
     A2147GiantCape,
     A2148RockyHelmet,
-}
-
-lazy_static::lazy_static! {
-    static ref TOOL_ID_MAP: HashMap<&'static str, ToolId> = {
-        let mut m = HashMap::new();
-        m.insert("A2 147", ToolId::A2147GiantCape);
-        m.insert("A2 148", ToolId::A2148RockyHelmet);
-        m
-    };
+    A2149LumBerry,
+    A3146PoisonBarb,
+    A3147LeafCape,
+    A3a065ElectricalCord,
+    A3a066Beastite,
+    A3b067Leftovers,
 }
 
 impl ToolId {
-    pub fn from_trainer_card(trainer_card: &TrainerCard) -> Option<&Self> {
-        TOOL_ID_MAP.get(&trainer_card.id.as_str())
+    pub fn from_trainer_card(trainer_card: &TrainerCard) -> Option<Self> {
+        Self::from_card_id(&trainer_card.id)
+    }
+
+    pub fn from_card_id(id: &str) -> Option<Self> {
+        match id {
+            "A2 147" => Some(ToolId::A2147GiantCape),
+            "A2 148" => Some(ToolId::A2148RockyHelmet),
+            "A2 149" => Some(ToolId::A2149LumBerry),
+            "A3 146" => Some(ToolId::A3146PoisonBarb),
+            "A3 147" => Some(ToolId::A3147LeafCape),
+            "A3a 065" => Some(ToolId::A3a065ElectricalCord),
+            "A3a 066" => Some(ToolId::A3a066Beastite),
+            "A3b 067" => Some(ToolId::A3b067Leftovers),
+            _ => None,
+        }
+    }
+
+    /// The trainer card of this tool (e.g. to discard it).
+    pub fn card_id(&self) -> CardId {
+        match self {
+            ToolId::A2147GiantCape => CardId::A2147GiantCape,
+            ToolId::A2148RockyHelmet => CardId::A2148RockyHelmet,
+            ToolId::A2149LumBerry => CardId::A2149LumBerry,
+            ToolId::A3146PoisonBarb => CardId::A3146PoisonBarb,
+            ToolId::A3147LeafCape => CardId::A3147LeafCape,
+            ToolId::A3a065ElectricalCord => CardId::A3a065ElectricalCord,
+            ToolId::A3a066Beastite => CardId::A3a066Beastite,
+            ToolId::A3b067Leftovers => CardId::A3b067Leftovers,
+        }
     }
 }
@@ -1,9 +1,14 @@
 use core::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::Deref;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{card_ids::CardId, tool_ids::ToolId};
+use crate::{
+    card_ids::CardId,
+    database::{get_card, intern_card},
+    tool_ids::ToolId,
+};
 
 /// Represents the type of energy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -134,6 +139,14 @@ impl Card {
         }
     }
 
+    /// Like `get_id`, without allocating.
+    pub fn get_id_str(&self) -> &str {
+        match self {
+            Card::Pokemon(pokemon_card) => &pokemon_card.id,
+            Card::Trainer(trainer_card) => &trainer_card.id,
+        }
+    }
+
     pub fn get_name(&self) -> String {
         match self {
             Card::Pokemon(pokemon_card) => pokemon_card.name.clone(),
@@ -180,7 +193,7 @@ impl Card {
     }
 
     pub fn get_card_id(&self) -> CardId {
-        CardId::from_card_id(self.get_id().as_str()).expect("Card ID should be valid")
+        CardId::from_card_id(self.get_id_str()).expect("Card ID should be valid")
     }
 
     pub fn is_basic(&self) -> bool {
@@ -191,11 +204,88 @@ impl Card {
     }
 }
 
+/// Handle to the data of a card, shared by every copy of the card. Copying it doesn't copy
+/// the strings and vectors of the card, which keeps `State::clone` cheap for search players.
+/// There is a single `&'static Card` per card id (see `database::intern_card`).
+#[derive(Clone, Copy)]
+pub struct CardRef(&'static Card);
+impl CardRef {
+    pub fn card(&self) -> &'static Card {
+        self.0
+    }
+}
+impl Deref for CardRef {
+    type Target = Card;
+
+    fn deref(&self) -> &Card {
+        self.0
+    }
+}
+impl From<&Card> for CardRef {
+    fn from(card: &Card) -> Self {
+        CardRef(intern_card(card))
+    }
+}
+impl From<Card> for CardRef {
+    fn from(card: Card) -> Self {
+        CardRef::from(&card)
+    }
+}
+impl From<CardId> for CardRef {
+    fn from(id: CardId) -> Self {
+        CardRef(get_card(id))
+    }
+}
+impl From<&CardRef> for CardRef {
+    fn from(card: &CardRef) -> Self {
+        *card
+    }
+}
+impl PartialEq for CardRef {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+impl Eq for CardRef {}
+impl PartialEq<Card> for CardRef {
+    fn eq(&self, other: &Card) -> bool {
+        self.0 == other
+    }
+}
+// Only the id is hashed: cards equal by pointer share it, and (unlike the pointer) it's the
+// same in every run.
+impl Hash for CardRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.get_id_str().hash(state);
+    }
+}
+impl fmt::Debug for CardRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl fmt::Display for CardRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+// (De)serialized as the full card, so that saved states and game records stay readable.
+impl Serialize for CardRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for CardRef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Card::deserialize(deserializer).map(CardRef::from)
+    }
+}
+
 /// This represents a card in the mat. Has a pointer to the card
 /// description, but captures the extra variable properties while in mat.
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PlayedCard {
-    pub card: Card,
+    pub card: CardRef,
     pub remaining_hp: u32,
     pub total_hp: u32,
     pub attached_energy: Vec<EnergyType>,
@@ -205,45 +295,44 @@ pub struct PlayedCard {
     pub poisoned: bool,
     pub paralyzed: bool,
     pub asleep: bool,
-    pub cards_behind: Vec<Card>,
+    pub burned: bool,
+    pub confused: bool,
+    pub cards_behind: Vec<CardRef>,
     pub damage_prevention: Option<(u32, u32)>, // (amount, expires_turn)
 }
 impl PlayedCard {
     pub(crate) fn get_id(&self) -> String {
-        match &self.card {
+        match &*self.card {
             Card::Pokemon(pokemon_card) => pokemon_card.id.clone(),
             Card::Trainer(trainer_card) => trainer_card.id.clone(),
         }
     }
 
     pub(crate) fn get_name(&self) -> String {
-        match &self.card {
+        match &*self.card {
             Card::Pokemon(pokemon_card) => pokemon_card.name.clone(),
             Card::Trainer(trainer_card) => trainer_card.name.clone(),
         }
     }
 
     pub(crate) fn get_attacks(&self) -> &Vec<Attack> {
-        match &self.card {
+        match &*self.card {
             Card::Pokemon(pokemon_card) => &pokemon_card.attacks,
             _ => panic!("Unsupported playable card type"),
         }
     }
 
-    pub(crate) fn heal(&mut self, amount: u32) {
-        self.remaining_hp = (self.remaining_hp + amount).min(self.total_hp);
+    // Asleep and Paralyzed Pokemon can't attack or retreat.
+    pub(crate) fn is_asleep_or_paralyzed(&self) -> bool {
+        self.asleep || self.paralyzed
     }
 
-    pub(crate) fn attach_energy(&mut self, energy: &EnergyType, amount: u8) {
-        self.attached_energy
-            .extend(std::iter::repeat_n(*energy, amount as usize));
+    pub(crate) fn has_status_condition(&self) -> bool {
+        self.poisoned || self.paralyzed || self.asleep || self.burned || self.confused
     }
 
-    // Discard 1 of energy type
-    pub(crate) fn discard_energy(&mut self, energy: &EnergyType) {
-        if let Some(pos) = self.attached_energy.iter().position(|x| x == energy) {
-            self.attached_energy.swap_remove(pos);
-        }
+    pub(crate) fn heal(&mut self, amount: u32) {
+        self.remaining_hp = (self.remaining_hp + amount).min(self.total_hp);
     }
 
     pub(crate) fn apply_damage(&mut self, damage: u32) {
@@ -252,7 +341,7 @@ impl PlayedCard {
 
     // Option because if playing an item card... (?)
     pub(crate) fn get_energy_type(&self) -> Option<EnergyType> {
-        match &self.card {
+        match &*self.card {
             Card::Pokemon(pokemon_card) => Some(pokemon_card.energy_type),
             _ => None,
         }
@@ -272,6 +361,8 @@ pub enum StatusCondition {
     Poisoned,
     Paralyzed,
     Asleep,
+    Burned,
+    Confused,
 }
 
 impl fmt::Display for Card {
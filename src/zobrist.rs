@@ -0,0 +1,342 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    actions::SimpleAction,
+    effects::Effect,
+    state::Zone,
+    types::{CardRef, PlayedCard},
+    State,
+};
+
+/// Zobrist hashes of the card zones, the Pokémon in play, the move generation stack and the
+/// effects of a `State`. The mutation methods of `State` keep them up to date, adding the
+/// key of each card (or Pokémon, stack entry or effect) as it comes in and subtracting it as
+/// it goes out, so that reading the hash of a state doesn't walk it. Not part of the state:
+/// states compare (and `Hash`) equal regardless of them.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ZobristHashes {
+    common: u64,
+    ordered_decks: u64,
+    unordered_decks: u64,
+}
+impl PartialEq for ZobristHashes {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for ZobristHashes {}
+impl Hash for ZobristHashes {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// A part of the state with its own key, e.g. "card X is in player 0's hand".
+pub(crate) enum Feature<'a> {
+    // Zone, player and card. Decks count here regardless of their order.
+    Card(Zone, usize, &'a CardRef),
+    // Player, position in the deck and card. Positions are counted from the bottom, so that
+    // drawing from the top doesn't move the other cards.
+    DeckCard(usize, usize, &'a CardRef),
+    // Player and slot
+    InPlay(usize, usize, &'a PlayedCard),
+    // Depth in the move generation stack
+    Choices(usize, &'a (usize, Vec<SimpleAction>)),
+    Effect(&'a Effect),
+}
+
+impl ZobristHashes {
+    /// Computes the hashes from scratch.
+    pub(crate) fn of(state: &State) -> Self {
+        let mut hashes = Self::default();
+        for player in 0..2 {
+            for zone in Zone::ALL {
+                for feature in zone_features(zone, player, state.cards(zone, player)) {
+                    hashes.add(feature);
+                }
+            }
+            for (slot, played_card) in state.enumerate_in_play_pokemon(player) {
+                hashes.add(Feature::InPlay(player, slot, played_card));
+            }
+        }
+        for (depth, choices) in state.move_generation_stack.iter().enumerate() {
+            hashes.add(Feature::Choices(depth, choices));
+        }
+        for effect in &state.effects {
+            hashes.add(Feature::Effect(effect));
+        }
+        hashes
+    }
+
+    pub(crate) fn add(&mut self, feature: Feature) {
+        let (hash, key) = self.hash_and_key(feature);
+        *hash = hash.wrapping_add(key);
+    }
+
+    pub(crate) fn remove(&mut self, feature: Feature) {
+        let (hash, key) = self.hash_and_key(feature);
+        *hash = hash.wrapping_sub(key);
+    }
+
+    /// The key of the feature, and the hash it goes into. Keys are added in and subtracted
+    /// out instead of xor-ed, so that duplicated cards count.
+    fn hash_and_key(&mut self, feature: Feature) -> (&mut u64, u64) {
+        let tag = |feature: u64, player: usize| feature | (player as u64) << 8;
+        match feature {
+            Feature::Card(zone, player, card) => {
+                let (hash, feature) = match zone {
+                    Zone::Hand => (&mut self.common, HAND),
+                    Zone::Deck => (&mut self.unordered_decks, DECK),
+                    Zone::DiscardPile => (&mut self.common, DISCARD_PILE),
+                    Zone::PrizeCards => (&mut self.common, PRIZE_CARDS),
+                    Zone::RevealedCards => (&mut self.common, REVEALED_CARDS),
+                };
+                (hash, key(tag(feature, player), card_hash(card)))
+            }
+            Feature::DeckCard(player, position, card) => {
+                let position_tag = tag(DECK, player) | (position as u64 + 1) << 16;
+                (&mut self.ordered_decks, key(position_tag, card_hash(card)))
+            }
+            Feature::InPlay(player, slot, played_card) => {
+                let slot_tag = tag(IN_PLAY, player) | (slot as u64) << 16;
+                (&mut self.common, key(slot_tag, value_hash(played_card)))
+            }
+            Feature::Choices(depth, choices) => {
+                let depth_tag = STACK | (depth as u64) << 16;
+                (&mut self.common, key(depth_tag, value_hash(choices)))
+            }
+            Feature::Effect(effect) => (&mut self.common, key(EFFECT, value_hash(effect))),
+        }
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        (self.common, self.ordered_decks, self.unordered_decks)
+            == (other.common, other.ordered_decks, other.unordered_decks)
+    }
+}
+
+/// The features of the cards of a zone (including their positions, for decks).
+pub(crate) fn zone_features(
+    zone: Zone,
+    player: usize,
+    cards: &[CardRef],
+) -> impl Iterator<Item = Feature<'_>> {
+    let positioned = if zone == Zone::Deck { cards.len() } else { 0 };
+    cards
+        .iter()
+        .map(move |card| Feature::Card(zone, player, card))
+        .chain(deck_top_features(player, cards, positioned))
+}
+
+/// The features of the positions of the `top` cards of a deck.
+pub(crate) fn deck_top_features(
+    player: usize,
+    deck: &[CardRef],
+    top: usize,
+) -> impl Iterator<Item = Feature<'_>> {
+    deck.iter()
+        .take(top)
+        .enumerate()
+        .map(move |(index, card)| Feature::DeckCard(player, deck.len() - 1 - index, card))
+}
+
+impl State {
+    /// 64-bit hash of the state, meant for transposition tables in search players. Hands,
+    /// discard piles, prize cards and effects are hashed as multisets, since their order
+    /// doesn't matter.
+    pub fn zobrist_hash(&self) -> u64 {
+        let hashes = self.checked_hashes();
+        hashes
+            .common
+            .wrapping_add(hashes.ordered_decks)
+            .wrapping_add(turn_key(self))
+    }
+
+    /// Like `zobrist_hash`, but ignores the order of the decks (which no player knows), so
+    /// that positions that are the same for the players share a hash.
+    pub fn information_set_hash(&self) -> u64 {
+        let hashes = self.checked_hashes();
+        hashes
+            .common
+            .wrapping_add(hashes.unordered_decks)
+            .wrapping_add(turn_key(self))
+    }
+
+    /// Computes the hashes again. Only needed after editing the cards, the Pokémon in play,
+    /// the stack or the effects of a state directly, as the engine keeps them up to date.
+    pub fn rehash(&mut self) {
+        self.hashes = ZobristHashes::of(self);
+    }
+
+    fn checked_hashes(&self) -> ZobristHashes {
+        debug_assert!(
+            self.hashes.same_as(&ZobristHashes::of(self)),
+            "Stale state hash, rehash the state after editing it directly"
+        );
+        self.hashes
+    }
+}
+
+// Features of the state that get their own key. Players and positions are mixed in too.
+const HAND: u64 = 1;
+const DECK: u64 = 2;
+const DISCARD_PILE: u64 = 3;
+const PRIZE_CARDS: u64 = 4;
+const REVEALED_CARDS: u64 = 5;
+const IN_PLAY: u64 = 6;
+const STACK: u64 = 7;
+const EFFECT: u64 = 8;
+const TURN: u64 = 9;
+
+/// Key of the fields that are cheap enough to hash on every read: the turn, the points, the
+/// energies, the flags and the rules.
+fn turn_key(state: &State) -> u64 {
+    let turn = (
+        state.winner,
+        state.points,
+        state.turn_count,
+        state.current_player,
+        state.current_energy,
+        state.next_energy,
+        state.has_played_support,
+        state.has_retreated,
+        [&state.decks[0].energy_types, &state.decks[1].energy_types],
+        state.rules,
+        state.allow_partial,
+    );
+    key(TURN, value_hash(&turn))
+}
+
+fn key(tag: u64, value: u64) -> u64 {
+    mix(mix(tag) ^ value)
+}
+
+fn card_hash(card: &CardRef) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(card.get_id_str().as_bytes());
+    hasher.finish()
+}
+
+fn value_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Finalizer of splitmix64: spreads every bit of the input over the output.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+/// The (fast, not DoS-resistant) hash of rustc, for hashing the parts of a feature. Unlike
+/// `DefaultHasher`, it's the same in every run.
+#[derive(Default)]
+struct FxHasher {
+    hash: u64,
+}
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(0x517cc1b727220a95);
+    }
+}
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut rest = [0; 8];
+        rest[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+        self.add(u64::from_le_bytes(rest) ^ bytes.len() as u64);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use crate::{actions::apply_action, generate_possible_actions, test_helpers::load_test_decks};
+
+    use super::*;
+
+    #[test]
+    fn test_hashes_are_updated_by_apply_action() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+        let mut seen = vec![state.zobrist_hash()];
+        while !state.is_game_over() {
+            let (_, actions) = generate_possible_actions(&state);
+            let action = actions.choose(&mut rng).unwrap().clone();
+            apply_action(&mut rng, &mut state, &action);
+            // The engine updates the hashes as it goes, and they match a full computation
+            let mut rehashed = state.clone();
+            rehashed.rehash();
+            assert_eq!(state.zobrist_hash(), rehashed.zobrist_hash());
+            assert_eq!(
+                state.information_set_hash(),
+                rehashed.information_set_hash()
+            );
+            seen.push(state.zobrist_hash());
+        }
+        // Consecutive positions are told apart
+        seen.dedup();
+        assert!(seen.len() > 10);
+    }
+
+    #[test]
+    fn test_state_is_sync() {
+        // The hashes are plain fields, so states can be shared between threads
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<State>();
+    }
+
+    #[test]
+    fn test_information_set_hash_ignores_deck_order() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut rng = StdRng::seed_from_u64(5);
+        let state = State::initialize(&deck_a, &deck_b, &mut rng);
+
+        let mut shuffled = state.clone();
+        shuffled.decks[0].cards.reverse();
+        shuffled.rehash();
+        assert_ne!(shuffled.zobrist_hash(), state.zobrist_hash());
+        assert_eq!(
+            shuffled.information_set_hash(),
+            state.information_set_hash()
+        );
+
+        // The order of the hand never matters
+        let mut reordered = state.clone();
+        reordered.hands[1].reverse();
+        reordered.rehash();
+        assert_eq!(reordered.zobrist_hash(), state.zobrist_hash());
+
+        // But which cards are where does
+        let mut moved = state.clone();
+        let card = moved.decks[0].cards.pop().unwrap();
+        moved.hands[0].push(card);
+        moved.rehash();
+        assert_ne!(moved.information_set_hash(), state.information_set_hash());
+    }
+}
@@ -124,7 +124,7 @@ fn test_play_pokeball_action() {
     let current_player = state.current_player;
     let hand = state.hands[current_player].clone();
     let pokeball = &hand[2];
-    if let Card::Trainer(trainer_card) = pokeball {
+    if let Card::Trainer(trainer_card) = &**pokeball {
         let action = SimpleAction::Play {
             trainer_card: trainer_card.clone(),
         };
@@ -155,7 +155,7 @@ fn test_place_action() {
     let current_player = state.current_player;
     let hand = state.hands[current_player].clone();
     let bulbasaur = &hand[0];
-    let action = SimpleAction::Place(bulbasaur.clone(), 2);
+    let action = SimpleAction::Place(*bulbasaur, 2);
     assert_eq!(state.enumerate_bench_pokemon(current_player).count(), 0); // no bench
     game.set_state(state);
 
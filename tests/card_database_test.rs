@@ -0,0 +1,52 @@
+use deckgym::{
+    coverage::unimplemented_cards,
+    database::{card_database, set_card_database, CardDatabase},
+    players::{Player, RandomPlayer},
+    test_helpers::load_test_decks,
+    Deck, Game,
+};
+
+// A prototype card whose attack effect has no implementation.
+const OVERLAY: &str = r#"[
+    {"Pokemon": {"id": "X1 001", "name": "Protomon", "stage": 0, "evolves_from": null,
+        "hp": 60, "energy_type": "Grass", "ability": null,
+        "attacks": [{"energy_required": ["Grass"], "title": "Proto Beam", "fixed_damage": 30,
+            "effect": "Flip a coin. If heads, this attack does 30 more damage."}],
+        "weakness": "Fire", "retreat_cost": ["Colorless"], "rarity": "◊",
+        "booster_pack": "Prototypes"}}
+]"#;
+
+#[test]
+fn test_overlay_cards_play_with_fixed_damage_fallback() {
+    // The database can only be set once per process, so this is the only test in this file.
+    let mut database = CardDatabase::default();
+    database.extend_from_json(OVERLAY).unwrap();
+    set_card_database(database).unwrap();
+    assert!(card_database().get("X1 001").is_some());
+    assert!(set_card_database(CardDatabase::default()).is_err());
+
+    let deck_a =
+        Deck::from_string("Energy: Grass\n2 Protomon X1 001\n2 Bulbasaur A1 001\n2 Weedle A1 008")
+            .unwrap();
+    let unimplemented = unimplemented_cards(&deck_a);
+    assert_eq!(unimplemented.len(), 1);
+    assert_eq!(
+        unimplemented[0].describe_missing(),
+        "X1 001 Protomon: attack Proto Beam"
+    );
+
+    let (_, deck_b) = load_test_decks();
+    for seed in 0..10 {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer {
+                deck: deck_a.clone(),
+            }),
+            Box::new(RandomPlayer {
+                deck: deck_b.clone(),
+            }),
+        ];
+        let mut game = Game::new(players, seed);
+        game.set_allow_partial(true);
+        assert!(game.try_play().is_ok());
+    }
+}
@@ -1,9 +1,19 @@
 use common::init_random_players;
 use deckgym::{
-    players::{AttachAttackPlayer, EndTurnPlayer, MctsPlayer, Player, RandomPlayer},
+    actions::{try_apply_action, Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    players::{
+        create_players, AttachAttackPlayer, EndTurnPlayer, MctsPlayer, ObservingPlayer, Player,
+        PlayerCode, RandomPlayer,
+    },
     state::GameOutcome,
     test_helpers::load_test_decks,
+    try_generate_possible_actions,
+    types::Card,
+    Deck, DeckgymError, Game, GameRecord, Observation,
 };
+use rand::{rngs::StdRng, SeedableRng};
 
 mod common;
 
@@ -29,7 +39,7 @@ fn test_mcts_player() {
     // }
     game.play();
 
-    assert_eq!(game.get_state_clone().turn_count, 30);
+    assert_eq!(game.get_state_clone().turn_count, 9);
 }
 
 #[test]
@@ -57,3 +67,149 @@ fn test_first_ko() {
     assert_eq!(game.get_state_clone().turn_count, 7);
     assert_eq!(winner, Some(GameOutcome::Win(0)));
 }
+
+#[test]
+fn test_same_seed_replays_same_game_with_multi_energy_decks() {
+    let deck = Deck::from_string(
+        "Energy: Grass
+Energy: Darkness
+2 Bulbasaur A1 001
+2 Ivysaur A1 002
+2 Ekans A1 164
+2 Arbok A1 165
+2 Koffing A1 176
+2 Weezing A1 177
+2 Professor's Research P-A 007
+2 Poké Ball P-A 005
+2 Potion P-A 001
+2 X Speed P-A 002",
+    )
+    .unwrap();
+
+    let play = |seed: u64| {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck.clone() }),
+            Box::new(RandomPlayer { deck: deck.clone() }),
+        ];
+        let mut game = deckgym::Game::new(players, seed);
+        game.play();
+        game.get_state_clone()
+    };
+    assert_eq!(play(42), play(42));
+}
+
+#[test]
+fn test_game_record_replays() {
+    let (deck_a, deck_b) = load_test_decks();
+    let codes = [PlayerCode::R, PlayerCode::V];
+    let players = create_players(deck_a, deck_b, codes.to_vec());
+    let mut game = deckgym::Game::new(players, 11);
+    game.play();
+    let record = game.to_record(codes);
+
+    let json = record.to_json().unwrap();
+    let record = GameRecord::from_json(&json).unwrap();
+    assert_eq!(Game::replay(&record), Ok(record.outcome));
+
+    // A tampered record should be reported, not silently accepted.
+    let mut tampered = record.clone();
+    tampered.seed += 1;
+    assert!(Game::replay(&tampered).is_err());
+}
+
+/// Plays the first legal action, checking it never sees the opponent's hand.
+#[derive(Debug)]
+struct FirstActionPlayer {
+    deck: Deck,
+}
+
+impl ObservingPlayer for FirstActionPlayer {
+    fn get_deck(&self) -> Deck {
+        self.deck.clone()
+    }
+
+    fn decision_fn(
+        &mut self,
+        _: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        assert_eq!(observation.player, possible_actions[0].actor);
+        assert_eq!(
+            observation.hand.len(),
+            observation.hand_sizes[observation.player]
+        );
+        possible_actions[0].clone()
+    }
+}
+
+#[test]
+fn test_observing_player_plays_full_game() {
+    let (deck_a, deck_b) = load_test_decks();
+    let players: Vec<Box<dyn Player>> = vec![
+        Box::new(FirstActionPlayer { deck: deck_a }),
+        Box::new(RandomPlayer { deck: deck_b }),
+    ];
+    let mut game = Game::new(players, 5);
+    game.play();
+    assert!(game.get_state_clone().turn_count > 0);
+}
+
+#[test]
+fn test_unsupported_trainer_is_reported_instead_of_panicking() {
+    let players = init_random_players();
+    let mut game = Game::new(players, 0);
+    while game.get_state_clone().turn_count == 0 {
+        game.play_tick();
+    }
+    game.play_tick(); // Draw card of the first turn
+    let flute = get_card_by_enum(CardId::A1a064PokemonFlute);
+    let Card::Trainer(trainer_card) = flute.clone() else {
+        panic!("Pokemon Flute should be a trainer");
+    };
+    let mut state = game.get_state_clone();
+    let actor = state.current_player;
+    state.hands[actor].push(flute.into());
+    game.set_state(state.clone());
+
+    let expected = DeckgymError::UnsupportedTrainer(trainer_card.id.clone());
+    assert_eq!(try_generate_possible_actions(&state).unwrap_err(), expected);
+    let action = Action {
+        actor,
+        action: SimpleAction::Play { trainer_card },
+        is_stack: false,
+    };
+    let mut after = state.clone();
+    let result = try_apply_action(&mut StdRng::seed_from_u64(0), &mut after, &action);
+    assert_eq!(result, Err(expected.clone()));
+    assert_eq!(after, state);
+    assert_eq!(game.try_play(), Err(expected));
+}
+
+#[test]
+fn test_allow_partial_plays_unimplemented_cards() {
+    // Caterpie's attack and Pokemon Flute are not implemented.
+    let deck_a = Deck::from_string(
+        "Energy: Grass\n2 Caterpie A1 005\n2 Bulbasaur A1 001\n2 Pokemon Flute A1a 064",
+    )
+    .unwrap();
+    let (_, deck_b) = load_test_decks();
+    for seed in 0..10 {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer {
+                deck: deck_a.clone(),
+            }),
+            Box::new(RandomPlayer {
+                deck: deck_b.clone(),
+            }),
+        ];
+        let mut game = Game::new(players, seed);
+        game.set_allow_partial(true);
+        assert!(game.try_play().is_ok());
+
+        // Records of partial games replay with the same allowance
+        let record = game.to_record([PlayerCode::R, PlayerCode::R]);
+        assert!(record.allow_partial);
+        assert_eq!(Game::replay(&record), Ok(record.outcome));
+    }
+}
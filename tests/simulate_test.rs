@@ -0,0 +1,102 @@
+use std::{collections::HashSet, thread};
+
+use deckgym::{
+    generate_possible_actions,
+    players::{ExpectiMiniMaxPlayer, Player, PlayerCode},
+    simulate,
+    simulate::game_seed,
+    Deck, SimulationOptions, State,
+};
+use log::LevelFilter;
+use rand::{rngs::StdRng, SeedableRng};
+
+const DECK_A: &str = "example_decks/venusaur-exeggutor.txt";
+const DECK_B: &str = "example_decks/weezing-arbok.txt";
+
+fn options(threads: usize) -> SimulationOptions {
+    SimulationOptions {
+        players: Some(vec![PlayerCode::R, PlayerCode::R]),
+        num_simulations: 20,
+        seed: Some(42),
+        threads,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_simulate_results_dont_depend_on_threads() {
+    let results = simulate(DECK_A, DECK_B, &options(1)).unwrap();
+    assert_eq!(results.seed, 42);
+    assert_eq!(results.plys_per_game.len(), 20);
+    for threads in [2, 3, 7] {
+        assert_eq!(
+            simulate(DECK_A, DECK_B, &options(threads)).unwrap(),
+            results
+        );
+    }
+}
+
+#[test]
+fn test_simulate_seeds_each_game_differently() {
+    let results = simulate(DECK_A, DECK_B, &options(1)).unwrap();
+    let first = results.plys_per_game[0];
+    assert!(results.plys_per_game.iter().any(|&plys| plys != first));
+}
+
+#[test]
+fn test_simulate_rejects_zero_threads() {
+    assert!(simulate(DECK_A, DECK_B, &options(0)).is_err());
+}
+
+#[test]
+fn test_runs_with_nearby_seeds_dont_share_games() {
+    let seeds: HashSet<u64> = (0..100)
+        .flat_map(|base_seed| (0..100).map(move |index| game_seed(base_seed, index)))
+        .collect();
+    assert_eq!(seeds.len(), 100 * 100);
+}
+
+#[test]
+fn test_simulate_reports_a_missing_record_folder() {
+    let options = SimulationOptions {
+        record_folder: Some("does/not/exist".to_string()),
+        ..options(2)
+    };
+    let err = simulate(DECK_A, DECK_B, &options).unwrap_err();
+    assert!(err.contains("does/not/exist"), "{err}");
+}
+
+#[test]
+fn test_simulate_without_games_has_no_statistics() {
+    let options = SimulationOptions {
+        num_simulations: 0,
+        ..options(1)
+    };
+    let results = simulate(DECK_A, DECK_B, &options).unwrap();
+    assert_eq!(results.wins_per_deck, [0, 0, 0]);
+    assert!(results.plys_per_game.is_empty());
+}
+
+#[test]
+fn test_search_players_on_several_threads_leave_the_log_level_alone() {
+    log::set_max_level(LevelFilter::Warn);
+    thread::scope(|scope| {
+        for seed in 0..8 {
+            scope.spawn(move || {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let deck_a = Deck::from_file(DECK_A).unwrap();
+                let deck_b = Deck::from_file(DECK_B).unwrap();
+                let state = State::initialize(&deck_a, &deck_b, &mut rng);
+                let mut player = ExpectiMiniMaxPlayer {
+                    deck: deck_a,
+                    max_depth: 2,
+                };
+                for _ in 0..200 {
+                    let (_, actions) = generate_possible_actions(&state);
+                    player.decision_fn(&mut rng, &state, actions);
+                }
+            });
+        }
+    });
+    assert_eq!(log::max_level(), LevelFilter::Warn);
+}
@@ -0,0 +1,65 @@
+use deckgym::{
+    actions::{apply_action_with_undo, undo},
+    generate_possible_actions,
+    test_helpers::load_test_deck,
+    State,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+// Applies random actions until the game ends, checking that undoing each one gives back
+// exactly the state from before it.
+fn check_undo_over_random_game(deck_a: &str, deck_b: &str, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut state = State::initialize(&load_test_deck(deck_a), &load_test_deck(deck_b), &mut rng);
+    while state.winner.is_none() {
+        let (_, actions) = generate_possible_actions(&state);
+        let action = actions.choose(&mut rng).unwrap().clone();
+
+        let before = state.clone();
+        let record = apply_action_with_undo(&mut rng, &mut state, &action);
+        let after = state.clone();
+        undo(&mut state, record);
+        assert_eq!(state, before, "Undo of {action:?} (seed {seed})");
+        assert_eq!(state.zobrist_hash(), before.zobrist_hash());
+
+        state = after;
+    }
+}
+
+#[test]
+fn test_apply_then_undo_is_identity() {
+    let matchups = [
+        ("venusaur-exeggutor.txt", "weezing-arbok.txt"),
+        ("mewtwoex.txt", "fire.txt"),
+        ("blastoiseex.txt", "arceusdialga.txt"),
+        ("hitmonlee.txt", "venusaur-exeggutornoex.txt"),
+    ];
+    for (deck_a, deck_b) in matchups {
+        for seed in 0..25 {
+            check_undo_over_random_game(deck_a, deck_b, seed);
+        }
+    }
+}
+
+#[test]
+fn test_undoing_a_line_of_actions_in_reverse_gives_back_its_start() {
+    for seed in 0..25 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let deck_a = load_test_deck("venusaur-exeggutor.txt");
+        let deck_b = load_test_deck("weezing-arbok.txt");
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+        let start = state.clone();
+
+        let mut records = vec![];
+        while state.winner.is_none() && records.len() < 200 {
+            let (_, actions) = generate_possible_actions(&state);
+            let action = actions.choose(&mut rng).unwrap().clone();
+            records.push(apply_action_with_undo(&mut rng, &mut state, &action));
+        }
+        for record in records.into_iter().rev() {
+            undo(&mut state, record);
+        }
+        assert_eq!(state, start, "Seed {seed}");
+        assert_eq!(state.zobrist_hash(), start.zobrist_hash());
+    }
+}